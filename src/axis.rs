@@ -0,0 +1,26 @@
+/// One of the two axes a rectangle extends along.
+///
+/// The x axis runs from left to right & the y axis runs from bottom to top.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Axis {
+    X,
+    Y,
+}
+
+impl Axis {
+    /// The other axis.
+    ///
+    /// # Example
+    /// ```
+    /// use rect_lib::Axis;
+    ///
+    /// assert_eq!(Axis::X.perpendicular(), Axis::Y);
+    /// assert_eq!(Axis::Y.perpendicular(), Axis::X);
+    /// ```
+    pub fn perpendicular(self) -> Self {
+        match self {
+            Axis::X => Axis::Y,
+            Axis::Y => Axis::X,
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use crate::Rectangle;
+use crate::{RectError, Rectangle, RectangleRead};
 
 /// A basic rectangle implementation.
 /// Edges are inclusive.
@@ -10,7 +10,7 @@ pub struct BasicRectangle {
     height: i32,
 }
 
-impl Rectangle for BasicRectangle {
+impl RectangleRead for BasicRectangle {
     type Unit = i32;
 
     fn left(&self) -> i32 {
@@ -28,7 +28,9 @@ impl Rectangle for BasicRectangle {
     fn bottom(&self) -> i32 {
         self.y - self.height + 1
     }
+}
 
+impl Rectangle for BasicRectangle {
     fn new_from_sides(left: i32, right: i32, top: i32, bottom: i32) -> Self {
         Self {
             x: left,
@@ -38,3 +40,108 @@ impl Rectangle for BasicRectangle {
         }
     }
 }
+
+/// The read-only methods `Rectangle` had before [`RectangleRead`] was split out of it, so code importing only `Rectangle` keeps compiling.
+/// Each one is the [`RectangleRead`] method of the same name.
+impl BasicRectangle {
+    /// See [`RectangleRead::left`].
+    pub fn left(&self) -> i32 {
+        RectangleRead::left(self)
+    }
+
+    /// See [`RectangleRead::right`].
+    pub fn right(&self) -> i32 {
+        RectangleRead::right(self)
+    }
+
+    /// See [`RectangleRead::top`].
+    pub fn top(&self) -> i32 {
+        RectangleRead::top(self)
+    }
+
+    /// See [`RectangleRead::bottom`].
+    pub fn bottom(&self) -> i32 {
+        RectangleRead::bottom(self)
+    }
+
+    /// See [`RectangleRead::width`].
+    pub fn width(&self) -> i32 {
+        RectangleRead::width(self)
+    }
+
+    /// See [`RectangleRead::height`].
+    pub fn height(&self) -> i32 {
+        RectangleRead::height(self)
+    }
+
+    /// See [`RectangleRead::perimeter`].
+    pub fn perimeter(&self) -> i32 {
+        RectangleRead::perimeter(self)
+    }
+
+    /// See [`RectangleRead::area`].
+    pub fn area(&self) -> i32 {
+        RectangleRead::area(self)
+    }
+
+    /// See [`RectangleRead::contains_point`].
+    pub fn contains_point(&self, x: i32, y: i32) -> bool {
+        RectangleRead::contains_point(self, x, y)
+    }
+
+    /// See [`RectangleRead::contains_rectangle`].
+    pub fn contains_rectangle(&self, other: &impl RectangleRead<Unit = i32>) -> bool {
+        RectangleRead::contains_rectangle(self, other)
+    }
+
+    /// See [`RectangleRead::overlaps`].
+    pub fn overlaps(&self, other: &impl RectangleRead<Unit = i32>) -> bool {
+        RectangleRead::overlaps(self, other)
+    }
+}
+
+impl BasicRectangle {
+    /// Creates a rectangle from its top left corner & size, validating external data.
+    ///
+    /// - `Ok(None)` means the rectangle is legitimately empty, the width or height is zero.
+    /// - `Ok(Some(rect))` means the rectangle is valid.
+    /// - `Err(RectError::NegativeSize)` means the width or height is negative.
+    /// - `Err(RectError::Overflow)` means the sides can't be represented by an `i32`.
+    ///
+    /// # Example
+    /// ```
+    /// use rect_lib::{BasicRectangle, RectError, Rectangle, RectangleRead};
+    ///
+    /// let rect = BasicRectangle::try_from_xywh(0, 3, 2, 4);
+    /// assert_eq!(rect, Ok(Some(BasicRectangle::new_from_sides(0, 1, 3, 0))));
+    ///
+    /// assert_eq!(BasicRectangle::try_from_xywh(0, 3, 0, 4), Ok(None));
+    /// assert_eq!(BasicRectangle::try_from_xywh(0, 3, -2, 4), Err(RectError::NegativeSize));
+    /// ```
+    pub fn try_from_xywh(
+        x: i32,
+        y: i32,
+        width: i32,
+        height: i32,
+    ) -> Result<Option<Self>, RectError> {
+        if width < 0 || height < 0 {
+            return Err(RectError::NegativeSize);
+        }
+
+        // the accessors compute `x + width - 1` & `y - height + 1` so both must fit
+        if x.checked_add(width).is_none() || y.checked_sub(height).is_none() {
+            return Err(RectError::Overflow);
+        }
+
+        if width == 0 || height == 0 {
+            return Ok(None);
+        }
+
+        Ok(Some(Self {
+            x,
+            y,
+            width,
+            height,
+        }))
+    }
+}
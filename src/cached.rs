@@ -0,0 +1,91 @@
+use core::ops::Deref;
+
+use crate::{Rectangle, RectangleRead};
+
+/// A rectangle with its four sides read once & kept next to it.
+///
+/// Wrap rectangles whose accessors do real work (computing bounds from glyphs, reading through a lock, & so on)
+/// so the default methods read the sides from the snapshot instead of calling into the rectangle again & again.
+/// The snapshot isn't refreshed, so the rectangle shouldn't change underneath it.
+///
+/// # Example
+/// ```
+/// use rect_lib::{BasicRectangle, Cached, Rectangle, RectangleRead};
+///
+/// let rect = Cached::new(BasicRectangle::new_from_sides(0, 4, 4, 0));
+/// assert_eq!(rect.right(), 4);
+/// assert!(rect.contains_point(2, 2));
+/// assert_eq!(rect.into_inner(), BasicRectangle::new_from_sides(0, 4, 4, 0));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Cached<R: RectangleRead> {
+    inner: R,
+    left: R::Unit,
+    right: R::Unit,
+    top: R::Unit,
+    bottom: R::Unit,
+}
+
+impl<R: RectangleRead> Cached<R> {
+    /// Reads the sides of `inner` once & keeps them.
+    pub fn new(inner: R) -> Self {
+        Self {
+            left: inner.left(),
+            right: inner.right(),
+            top: inner.top(),
+            bottom: inner.bottom(),
+            inner,
+        }
+    }
+
+    /// Returns the wrapped rectangle.
+    pub fn inner(&self) -> &R {
+        &self.inner
+    }
+
+    /// Returns the wrapped rectangle, dropping the snapshot.
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+impl<R: RectangleRead> Deref for Cached<R> {
+    type Target = R;
+
+    fn deref(&self) -> &R {
+        &self.inner
+    }
+}
+
+impl<R: RectangleRead> RectangleRead for Cached<R> {
+    type Unit = R::Unit;
+
+    fn left(&self) -> R::Unit {
+        self.left
+    }
+
+    fn right(&self) -> R::Unit {
+        self.right
+    }
+
+    fn top(&self) -> R::Unit {
+        self.top
+    }
+
+    fn bottom(&self) -> R::Unit {
+        self.bottom
+    }
+}
+
+impl<R: Rectangle> Rectangle for Cached<R> {
+    /// Builds the inner rectangle & keeps the sides it was built from, without reading them back.
+    fn new_from_sides(left: R::Unit, right: R::Unit, top: R::Unit, bottom: R::Unit) -> Self {
+        Self {
+            inner: R::new_from_sides(left, right, top, bottom),
+            left,
+            right,
+            top,
+            bottom,
+        }
+    }
+}
@@ -0,0 +1,237 @@
+use crate::{canonical_key, partial_max, partial_min, Rectangle, RectangleRead};
+
+/// The most rectangles a block holds before it is split in two.
+const MAX_BLOCK: usize = 64;
+
+/// A run of rectangles sorted by left, with the bounds needed to skip it when nothing in it can contain a query.
+#[derive(Clone, Debug)]
+struct Block<R: Rectangle> {
+    rects: Vec<R>,
+    /// The bounding box of the rectangles, only the right, top, & bottom are used.
+    reach: R,
+}
+
+impl<R: Rectangle> Block<R> {
+    /// Creates a block from sorted rectangles, there must be at least one.
+    fn new(rects: Vec<R>) -> Self {
+        let reach = rects[0];
+        let mut block = Self { rects, reach };
+        block.refresh();
+        block
+    }
+
+    /// Recomputes the bounds after the rectangles changed, the block must not be empty.
+    fn refresh(&mut self) {
+        let first = self.rects[0];
+        self.reach = self.rects[1..].iter().fold(first, grow);
+    }
+}
+
+/// The bounding box of two rectangles, for keeping a block's reach up to date.
+fn grow<R: Rectangle>(reach: R, rect: &R) -> R {
+    R::new_from_sides(
+        partial_min(reach.left(), rect.left()),
+        partial_max(reach.right(), rect.right()),
+        partial_max(reach.top(), rect.top()),
+        partial_min(reach.bottom(), rect.bottom()),
+    )
+}
+
+/// An index answering "which stored rectangles fully contain this one?", like drop zones that can accept a dragged item.
+///
+/// The rectangles are kept sorted by their left side in blocks of up to 64, each remembering the furthest its rectangles reach
+/// right, up, & down. A query only looks at blocks starting at or left of it, & skips any that can't reach around it,
+/// so it visits a fraction of the rectangles when most are nowhere near. Results are exactly what
+/// [`RectangleRead::contains_rectangle`] says over every stored rectangle.
+///
+/// # Example
+/// ```
+/// use rect_lib::{BasicRectangle, ContainmentIndex, Rectangle, RectangleRead};
+///
+/// let mut zones = ContainmentIndex::new();
+/// zones.insert(BasicRectangle::new_from_sides(0, 9, 9, 0));
+/// zones.insert(BasicRectangle::new_from_sides(5, 20, 9, 0));
+/// zones.insert(BasicRectangle::new_from_sides(30, 40, 9, 0));
+///
+/// let item = BasicRectangle::new_from_sides(6, 8, 3, 1);
+/// assert_eq!(zones.containing(&item).len(), 2);
+/// ```
+#[derive(Clone, Debug)]
+pub struct ContainmentIndex<R: Rectangle> {
+    blocks: Vec<Block<R>>,
+    len: usize,
+}
+
+impl<R: Rectangle> Default for ContainmentIndex<R> {
+    fn default() -> Self {
+        Self {
+            blocks: Vec::new(),
+            len: 0,
+        }
+    }
+}
+
+impl<R: Rectangle> ContainmentIndex<R> {
+    /// Creates an empty index.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The number of rectangles stored, counting duplicates.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Checks if no rectangles are stored.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Stores a rectangle, after any already stored with the same left side.
+    pub fn insert(&mut self, rect: R) {
+        self.len += 1;
+        let left = rect.left();
+
+        // the last block starting at or left of the rectangle, or the first block if none do
+        let block = self
+            .blocks
+            .partition_point(|block| block.rects[0].left() <= left)
+            .saturating_sub(1);
+        let Some(target) = self.blocks.get_mut(block) else {
+            self.blocks.push(Block::new(vec![rect]));
+            return;
+        };
+
+        let at = target.rects.partition_point(|other| other.left() <= left);
+        target.rects.insert(at, rect);
+        target.reach = grow(target.reach, &rect);
+
+        if target.rects.len() > MAX_BLOCK {
+            let upper = target.rects.split_off(MAX_BLOCK / 2);
+            target.refresh();
+            self.blocks.insert(block + 1, Block::new(upper));
+        }
+    }
+
+    /// Removes one stored rectangle with the same sides as `rect`, returning it if there was one.
+    pub fn remove(&mut self, rect: &impl RectangleRead<Unit = R::Unit>) -> Option<R> {
+        let sides = (rect.left(), rect.right(), rect.top(), rect.bottom());
+        let same_sides =
+            |other: &R| (other.left(), other.right(), other.top(), other.bottom()) == sides;
+
+        // rectangles with the same left can spill over into the blocks either side
+        let first = self
+            .blocks
+            .partition_point(|block| block.rects[0].left() < sides.0)
+            .saturating_sub(1);
+        for block in first..self.blocks.len() {
+            if self.blocks[block].rects[0].left() > sides.0 {
+                break;
+            }
+            let Some(at) = self.blocks[block].rects.iter().position(same_sides) else {
+                continue;
+            };
+
+            self.len -= 1;
+            let removed = self.blocks[block].rects.remove(at);
+            if self.blocks[block].rects.is_empty() {
+                self.blocks.remove(block);
+            } else {
+                self.blocks[block].refresh();
+            }
+            return Some(removed);
+        }
+        None
+    }
+
+    /// Every stored rectangle containing `query`, ordered by left side & then by when they were inserted.
+    ///
+    /// Queries can be any size, down to a single point.
+    pub fn containing(&self, query: &impl RectangleRead<Unit = R::Unit>) -> Vec<&R> {
+        let (left, right, top, bottom) = (query.left(), query.right(), query.top(), query.bottom());
+
+        self.blocks
+            .iter()
+            // everything in later blocks starts right of the query
+            .take_while(|block| block.rects[0].left() <= left)
+            .filter(|block| {
+                let reach = &block.reach;
+                reach.right() >= right && reach.top() >= top && reach.bottom() <= bottom
+            })
+            .flat_map(|block| block.rects.iter())
+            .filter(|rect| rect.contains_sides(left, right, top, bottom))
+            .collect()
+    }
+
+    /// Iterates over the stored rectangles ordered by left side & then by when they were inserted.
+    pub fn iter(&self) -> ContainmentIter<'_, R> {
+        ContainmentIter {
+            blocks: self.blocks.iter(),
+            rects: [].iter(),
+        }
+    }
+
+    /// The stored rectangles in the canonical rectangle order, see [`RectRegion::iter_sorted`](crate::RectRegion::iter_sorted).
+    pub fn iter_sorted(&self) -> std::vec::IntoIter<&R>
+    where
+        R::Unit: Ord,
+    {
+        let mut rects: Vec<&R> = self.iter().collect();
+        rects.sort_by_key(|rect| canonical_key(*rect));
+        rects.into_iter()
+    }
+}
+
+/// An iterator over the rectangles in a [`ContainmentIndex`], see [`ContainmentIndex::iter`].
+#[derive(Clone, Debug)]
+pub struct ContainmentIter<'a, R: Rectangle> {
+    blocks: core::slice::Iter<'a, Block<R>>,
+    rects: core::slice::Iter<'a, R>,
+}
+
+impl<'a, R: Rectangle> Iterator for ContainmentIter<'a, R> {
+    type Item = &'a R;
+
+    fn next(&mut self) -> Option<&'a R> {
+        loop {
+            if let Some(rect) = self.rects.next() {
+                return Some(rect);
+            }
+            self.rects = self.blocks.next()?.rects.iter();
+        }
+    }
+}
+
+impl<'a, R: Rectangle> IntoIterator for &'a ContainmentIndex<R> {
+    type Item = &'a R;
+    type IntoIter = ContainmentIter<'a, R>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<R: Rectangle> IntoIterator for ContainmentIndex<R> {
+    type Item = R;
+    type IntoIter = std::vec::IntoIter<R>;
+
+    /// Takes the rectangles ordered by left side & then by when they were inserted.
+    fn into_iter(self) -> Self::IntoIter {
+        let rects: Vec<R> = self
+            .blocks
+            .into_iter()
+            .flat_map(|block| block.rects)
+            .collect();
+        rects.into_iter()
+    }
+}
+
+impl<R: Rectangle> FromIterator<R> for ContainmentIndex<R> {
+    fn from_iter<I: IntoIterator<Item = R>>(iter: I) -> Self {
+        let mut index = Self::new();
+        for rect in iter {
+            index.insert(rect);
+        }
+        index
+    }
+}
@@ -0,0 +1,292 @@
+//! Conversions between regions & the raster & polygon worlds.
+//!
+//! - A [`RectRegion`] is exact, everything else converts through it.
+//! - A grid is a [`Heatmap`] used as a mask, a cell is set when its count isn't zero.
+//! - A polygon is a closed loop of `(x, y)` vertices, without repeating the first at the end.
+//!
+//! Polygons live on the lattice between points: the point `(x, y)` is the square from `(x, y)` to `(x + 1, y + 1)`,
+//! so a rectangle is the loop through `(left, bottom)`, `(right + 1, bottom)`, `(right + 1, top + 1)`, & `(left, top + 1)`.
+//! Region to polygons & back is lossless, the other directions quantize as documented on each function.
+//!
+//! # Example
+//! ```
+//! use rect_lib::{convert, BasicRectangle, RectRegion, Rectangle, RectangleRead};
+//!
+//! let region = RectRegion::from(BasicRectangle::new_from_sides(0, 2, 1, 0));
+//! let polygons = convert::region_to_polygons(&region);
+//! assert_eq!(polygons, vec![vec![(0, 2), (0, 0), (3, 0), (3, 2)]]);
+//! assert_eq!(convert::polygons_to_region::<BasicRectangle>(&polygons), region);
+//! ```
+
+use std::collections::BTreeMap;
+
+use num::{NumCast, One, ToPrimitive, Zero};
+
+use crate::{Heatmap, RectRegion, Rectangle};
+
+/// Rasterizes the region onto `cell_w` by `cell_h` cells of `bounds`, laid out like [`Rectangle::coverage_heatmap`].
+///
+/// A cell is set if the region covers any of its points, so the grid never loses coverage but can gain it:
+/// converting back with [`grid_to_region`] gives a superset of the region within `bounds`,
+/// growing it by less than a cell on each side. Regions aligned to the cells round trip exactly.
+///
+/// # Panics
+/// If `cell_w` or `cell_h` isn't positive.
+pub fn region_to_grid<R: Rectangle>(
+    region: &RectRegion<R>,
+    bounds: &R,
+    cell_w: R::Unit,
+    cell_h: R::Unit,
+) -> Heatmap
+where
+    R::Unit: Ord + ToPrimitive,
+{
+    let rects: Vec<&R> = region.iter().collect();
+    Heatmap::build(bounds, &rects, cell_w, cell_h).into_mask()
+}
+
+/// The region covered by the set cells of `grid`, laid out over `bounds` the same way as [`region_to_grid`].
+///
+/// This is exact, every point of a set cell is covered. Partial cells on the right & bottom are clipped to `bounds`.
+pub fn grid_to_region<R: Rectangle>(
+    grid: &Heatmap,
+    bounds: &R,
+    cell_w: R::Unit,
+    cell_h: R::Unit,
+) -> RectRegion<R>
+where
+    R::Unit: Ord + NumCast,
+{
+    let one = R::Unit::one();
+    let offset = |cells: usize, size: R::Unit| {
+        <R::Unit as NumCast>::from(cells).unwrap_or(R::Unit::zero()) * size
+    };
+
+    grid.iter()
+        .filter(|&(_, _, count)| count > 0)
+        .filter_map(|(cx, cy, _)| {
+            let left = bounds.left() + offset(cx, cell_w);
+            let top = bounds.top() - offset(cy, cell_h);
+            bounds.intersection(&R::new_from_sides(
+                left,
+                left + cell_w - one,
+                top,
+                top - cell_h + one,
+            ))
+        })
+        .collect()
+}
+
+/// The boundary loops of the region, losslessly.
+///
+/// Outer boundaries run counter clockwise & holes clockwise, so the region is always on the left of each edge.
+/// Each loop only has its corners, starts at its top left corner, & the loops are in reading order of those corners.
+/// Parts of the region only touching at a corner get separate loops.
+pub fn region_to_polygons<R: Rectangle>(region: &RectRegion<R>) -> Vec<Vec<(R::Unit, R::Unit)>>
+where
+    R::Unit: Ord,
+{
+    let one = R::Unit::one();
+
+    // every rectangle edge lies on one of these lines, so the cells between them are either covered or not
+    let mut xs: Vec<R::Unit> = region
+        .iter()
+        .flat_map(|rect| [rect.left(), rect.right() + one])
+        .collect();
+    let mut ys: Vec<R::Unit> = region
+        .iter()
+        .flat_map(|rect| [rect.bottom(), rect.top() + one])
+        .collect();
+    xs.sort_unstable();
+    xs.dedup();
+    ys.sort_unstable();
+    ys.dedup();
+    if xs.len() < 2 || ys.len() < 2 {
+        return Vec::new();
+    }
+
+    let (columns, rows) = (xs.len() - 1, ys.len() - 1);
+    let mut filled = vec![false; columns * rows];
+    let index = |lines: &[R::Unit], value: R::Unit| lines.binary_search(&value).unwrap_or(0);
+    for rect in region.iter() {
+        for row in index(&ys, rect.bottom())..index(&ys, rect.top() + one) {
+            for column in index(&xs, rect.left())..index(&xs, rect.right() + one) {
+                filled[row * columns + column] = true;
+            }
+        }
+    }
+    let is_filled = |column: usize, row: usize| {
+        column < columns && row < rows && filled[row * columns + column]
+    };
+
+    // the edges between covered & uncovered cells, directed so the covered one is on the left,
+    // found top to bottom so the first edge of each loop is on its top
+    let mut edges: Vec<((usize, usize), (usize, usize))> = Vec::new();
+    for row in (0..rows).rev() {
+        for column in 0..columns {
+            if !is_filled(column, row) {
+                continue;
+            }
+            if !is_filled(column, row + 1) {
+                edges.push(((column + 1, row + 1), (column, row + 1)));
+            }
+            if column == 0 || !is_filled(column - 1, row) {
+                edges.push(((column, row + 1), (column, row)));
+            }
+            if row == 0 || !is_filled(column, row - 1) {
+                edges.push(((column, row), (column + 1, row)));
+            }
+            if !is_filled(column + 1, row) {
+                edges.push(((column + 1, row), (column + 1, row + 1)));
+            }
+        }
+    }
+
+    let mut outgoing: BTreeMap<(usize, usize), Vec<usize>> = BTreeMap::new();
+    for (i, &(start, _)) in edges.iter().enumerate() {
+        outgoing.entry(start).or_default().push(i);
+    }
+    let direction = |edge: usize| {
+        let ((x0, y0), (x1, y1)) = edges[edge];
+        (x1 as isize - x0 as isize, y1 as isize - y0 as isize)
+    };
+
+    let mut used = vec![false; edges.len()];
+    let mut polygons = Vec::new();
+    for first in 0..edges.len() {
+        if used[first] {
+            continue;
+        }
+
+        let mut corners = Vec::new();
+        let mut edge = first;
+        loop {
+            used[edge] = true;
+            let (dx, dy) = direction(edge);
+
+            // where two parts touch at a corner turn left, keeping to the part we are walking around
+            let turn = |next: &usize| {
+                let (nx, ny) = direction(*next);
+                let cross = dx * ny - dy * nx;
+                if cross > 0 {
+                    0
+                } else if cross == 0 {
+                    1
+                } else {
+                    2
+                }
+            };
+            let next = outgoing[&edges[edge].1]
+                .iter()
+                .copied()
+                .filter(|&next| !used[next] || next == first)
+                .min_by_key(turn)
+                .unwrap_or(first);
+
+            if direction(next) != (dx, dy) {
+                corners.push(edges[edge].1);
+            }
+            if next == first {
+                break;
+            }
+            edge = next;
+        }
+
+        // start at the top left corner
+        let start = corners
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, &(x, y))| (core::cmp::Reverse(y), x))
+            .map_or(0, |(i, _)| i);
+        corners.rotate_left(start);
+        polygons.push(corners);
+    }
+
+    polygons.sort_by_key(|corners| (core::cmp::Reverse(corners[0].1), corners[0].0));
+    polygons
+        .into_iter()
+        .map(|corners| corners.into_iter().map(|(x, y)| (xs[x], ys[y])).collect())
+        .collect()
+}
+
+/// The points inside of the polygons, using the even-odd rule so holes can be separate loops going either way.
+///
+/// A point is inside when the center of its square is, which is exact for polygons with only horizontal & vertical edges
+/// on whole units, like the ones from [`region_to_polygons`]. Slanted edges are rasterized a row of points at a time,
+/// keeping exactly the points whose centers are inside.
+pub fn polygons_to_region<R: Rectangle>(polygons: &[Vec<(R::Unit, R::Unit)>]) -> RectRegion<R>
+where
+    R::Unit: Ord + NumCast + ToPrimitive,
+{
+    let one = R::Unit::one();
+    let edges: Vec<_> = polygons
+        .iter()
+        .filter(|polygon| !polygon.is_empty())
+        .flat_map(|polygon| {
+            polygon
+                .iter()
+                .zip(polygon.iter().cycle().skip(1))
+                .map(|(&a, &b)| (a, b))
+        })
+        // horizontal edges never cross a row
+        .filter(|&((_, y0), (_, y1))| y0 != y1)
+        .collect();
+
+    let mut ys: Vec<R::Unit> = edges
+        .iter()
+        .flat_map(|&((_, y0), (_, y1))| [y0, y1])
+        .collect();
+    ys.sort_unstable();
+    ys.dedup();
+
+    // the edges crossing the row of points at `y`, their low end is on or below it & their high end above it
+    let crossing = |y: R::Unit| {
+        edges
+            .iter()
+            .filter(move |&&((_, y0), (_, y1))| y0.min(y1) <= y && y < y0.max(y1))
+    };
+
+    let mut rects = Vec::new();
+    for band in ys.windows(2) {
+        let (bottom, top) = (band[0], band[1]);
+
+        // with only vertical edges every row in the band is the same
+        if crossing(bottom).all(|&((x0, _), (x1, _))| x0 == x1) {
+            let mut xs: Vec<R::Unit> = crossing(bottom).map(|&((x, _), _)| x).collect();
+            xs.sort_unstable();
+            for span in xs.chunks_exact(2) {
+                if span[0] < span[1] {
+                    rects.push(R::new_from_sides(span[0], span[1] - one, top - one, bottom));
+                }
+            }
+            continue;
+        }
+
+        let mut y = bottom;
+        while y < top {
+            let center = y.to_f64().unwrap_or(0.0) + 0.5;
+            let mut xs: Vec<f64> = crossing(y)
+                .map(|&((x0, y0), (x1, y1))| {
+                    let (x0, y0) = (x0.to_f64().unwrap_or(0.0), y0.to_f64().unwrap_or(0.0));
+                    let (x1, y1) = (x1.to_f64().unwrap_or(0.0), y1.to_f64().unwrap_or(0.0));
+                    x0 + (center - y0) * (x1 - x0) / (y1 - y0)
+                })
+                .collect();
+            xs.sort_unstable_by(f64::total_cmp);
+
+            // the points whose centers are on or right of the first crossing & left of the second
+            for span in xs.chunks_exact(2) {
+                let left = (span[0] - 0.5).ceil();
+                let right = (span[1] - 0.5).ceil() - 1.0;
+                if left <= right {
+                    if let (Some(left), Some(right)) = (NumCast::from(left), NumCast::from(right)) {
+                        rects.push(R::new_from_sides(left, right, y, y));
+                    }
+                }
+            }
+            y = y + one;
+        }
+    }
+
+    rects.into_iter().collect()
+}
@@ -0,0 +1,172 @@
+use num::One;
+
+use crate::{partial_max, partial_min, Axis, Rectangle, RectangleRead};
+
+/// Builds a rectangle from its ranges along & across the given axis.
+fn from_ranges<R: Rectangle>(
+    along: Axis,
+    (along_min, along_max): (R::Unit, R::Unit),
+    (across_min, across_max): (R::Unit, R::Unit),
+) -> R {
+    match along {
+        Axis::X => R::new_from_sides(along_min, along_max, across_max, across_min),
+        Axis::Y => R::new_from_sides(across_min, across_max, along_max, along_min),
+    }
+}
+
+/// Finds the widest unobstructed corridor running along the given axis between `a` & `b`.
+///
+/// The corridor spans from the near side of one rectangle to the near side of the other,
+/// so it overlaps each of them, & is at least `thickness` units across.
+pub(crate) fn straight<R: Rectangle>(
+    parent: &R,
+    a: &R,
+    b: &R,
+    obstructions: &[&impl RectangleRead<Unit = R::Unit>],
+    along: Axis,
+    thickness: R::Unit,
+) -> Option<R>
+where
+    R::Unit: Ord,
+{
+    let one = R::Unit::one();
+    let across = along.perpendicular();
+
+    // a straight corridor can only run where both rectangles line up
+    let band = (
+        partial_max(a.min_along(across), b.min_along(across)),
+        partial_min(a.max_along(across), b.max_along(across)),
+    );
+    if band.0 > band.1 {
+        return None;
+    }
+
+    // from the near side of one to the near side of the other,
+    // or where they overlap if they already overlap along this axis
+    let near = partial_min(a.max_along(along), b.max_along(along));
+    let far = partial_max(a.min_along(along), b.min_along(along));
+    let span = (partial_min(near, far), partial_max(near, far));
+
+    let search: R = from_ranges(along, span, band);
+    let search = parent.intersection(&search)?;
+    // the parent cut the corridor short
+    if (search.min_along(along), search.max_along(along)) != span {
+        return None;
+    }
+
+    search
+        .unobstructed_subrectangles(obstructions)
+        .into_iter()
+        .filter(|free| (free.min_along(along), free.max_along(along)) == span)
+        .filter(|free| free.extent_along(across) + one >= thickness)
+        .max_by_key(|free| free.extent_along(across))
+}
+
+/// Finds an L-shaped corridor with a horizontal leg leaving `a` & a vertical leg arriving at `b`.
+/// Returns `(horizontal, vertical)`, the legs overlap in a square at the corner.
+///
+/// The legs are placed within the rows of `a` & the columns of `b`, nearest the top left first.
+/// Whether a placement fits only changes where an obstruction, the parent, or one of the rectangles starts or stops,
+/// so only the highest row & leftmost column of each run of placements is tried, which finds the same corridor as trying every one.
+pub(crate) fn l_shaped<R: Rectangle>(
+    parent: &R,
+    a: &R,
+    b: &R,
+    obstructions: &[&impl RectangleRead<Unit = R::Unit>],
+    thickness: R::Unit,
+) -> Option<(R, R)> {
+    let one = R::Unit::one();
+    // the number of units across a leg, minus one to match the inclusive sides
+    let across = partial_max(thickness, one) - one;
+
+    let is_free = |leg: &R| {
+        parent.contains_rectangle(leg)
+            && !obstructions
+                .iter()
+                .any(|obstruction| leg.overlaps(*obstruction))
+    };
+
+    // a run of rows ends at its top below an obstruction or b, or at the top of a or the parent,
+    // & the tops are only kept if the whole leg fits in a's rows
+    let mut row_tops = vec![a.top(), parent.top()];
+    row_tops.extend(
+        obstructions
+            .iter()
+            .map(|obstruction| obstruction.bottom())
+            .chain([b.bottom()])
+            .filter(|bottom| *bottom > a.bottom())
+            .map(|bottom| bottom - one),
+    );
+    row_tops.retain(|top| *top <= a.top() && *top >= a.bottom() && *top - a.bottom() >= across);
+    row_tops.sort_by(|a, b| b.partial_cmp(a).unwrap());
+    row_tops.dedup();
+
+    // a run of columns starts right of an obstruction or a, or at the left of b or the parent,
+    // & the lefts are only kept if the whole leg fits in b's columns
+    let mut column_lefts = vec![b.left(), parent.left()];
+    column_lefts.extend(
+        obstructions
+            .iter()
+            .map(|obstruction| obstruction.right())
+            .chain([a.right()])
+            .filter(|right| *right < b.right())
+            .map(|right| right + one),
+    );
+    column_lefts
+        .retain(|left| *left >= b.left() && *left <= b.right() && b.right() - *left >= across);
+    column_lefts.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    column_lefts.dedup();
+
+    for &rows_top in &row_tops {
+        let rows_bottom = rows_top - across;
+
+        for &columns_left in &column_lefts {
+            let columns_right = columns_left + across;
+
+            // the legs have to leave through the sides, not run back through the rectangles
+            let horizontal = if columns_left > a.right() {
+                Some(R::new_from_sides(
+                    a.right(),
+                    columns_right,
+                    rows_top,
+                    rows_bottom,
+                ))
+            } else if columns_right < a.left() {
+                Some(R::new_from_sides(
+                    columns_left,
+                    a.left(),
+                    rows_top,
+                    rows_bottom,
+                ))
+            } else {
+                None
+            };
+            let vertical = if rows_top < b.bottom() {
+                Some(R::new_from_sides(
+                    columns_left,
+                    columns_right,
+                    b.bottom(),
+                    rows_bottom,
+                ))
+            } else if rows_bottom > b.top() {
+                Some(R::new_from_sides(
+                    columns_left,
+                    columns_right,
+                    rows_top,
+                    b.top(),
+                ))
+            } else {
+                None
+            };
+
+            if let (Some(horizontal), Some(vertical)) = (horizontal, vertical) {
+                // the corner is part of both legs, so this checks it too
+                if is_free(&horizontal) && is_free(&vertical) {
+                    return Some((horizontal, vertical));
+                }
+            }
+        }
+    }
+
+    None
+}
@@ -0,0 +1,41 @@
+/// How [`Rectangle::ease_to`](crate::Rectangle::ease_to) moves over time, the usual quadratic easing curves.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Easing {
+    /// A constant speed, the same as [`Rectangle::lerp`](crate::Rectangle::lerp).
+    Linear,
+    /// Starts slow & speeds up.
+    EaseIn,
+    /// Starts fast & slows down.
+    EaseOut,
+    /// Starts & ends slow, passing the halfway point at `t = 0.5`.
+    EaseInOut,
+}
+
+impl Easing {
+    /// How far along the motion is at time `t`, both from `0.0` to `1.0`. `t` is clamped first.
+    ///
+    /// # Example
+    /// ```
+    /// use rect_lib::Easing;
+    ///
+    /// assert_eq!(Easing::EaseIn.apply(0.5), 0.25);
+    /// assert_eq!(Easing::EaseOut.apply(0.5), 0.75);
+    /// assert_eq!(Easing::EaseInOut.apply(0.5), 0.5);
+    /// ```
+    pub fn apply(self, t: f64) -> f64 {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            Easing::Linear => t,
+            Easing::EaseIn => t * t,
+            Easing::EaseOut => 1.0 - (1.0 - t) * (1.0 - t),
+            Easing::EaseInOut => {
+                // an ease in squeezed into the first half & an ease out into the second
+                if t < 0.5 {
+                    2.0 * t * t
+                } else {
+                    1.0 - 2.0 * (1.0 - t) * (1.0 - t)
+                }
+            }
+        }
+    }
+}
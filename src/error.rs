@@ -0,0 +1,140 @@
+use core::fmt;
+
+/// Any error from the crate, for callers that want a single error type to propagate with `?`.
+///
+/// Each variant wraps the more specific error a function returns, which converts into this with `From`.
+/// Rectangles with inverted sides are treated as empty rather than as errors. With signed & float units they don't panic,
+/// but an unsigned unit can't hold the negative width of an inverted rectangle, so reading it panics in debug builds.
+/// The other panics are listed under `# Panics` on the methods that have them: contract violations like a grid
+/// with a cell size of zero, & in debug builds results too large for the unit, like the area of a huge rectangle.
+///
+/// # Example
+/// ```
+/// use rect_lib::{BasicRectangle, Error, RectError};
+///
+/// fn load(width: i32) -> Result<Option<BasicRectangle>, Error> {
+///     Ok(BasicRectangle::try_from_xywh(0, 0, width, 1)?)
+/// }
+///
+/// assert!(matches!(load(-1), Err(Error::Rect(RectError::NegativeSize))));
+/// assert_eq!(load(-1).unwrap_err().to_string(), "invalid rectangle: rectangle has a negative width or height");
+/// ```
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum Error {
+    /// Constructing a rectangle failed.
+    Rect(RectError),
+    /// Reading a region back from bytes failed.
+    RegionDecode(RegionDecodeError),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Rect(error) => write!(f, "invalid rectangle: {error}"),
+            Error::RegionDecode(error) => write!(f, "invalid region data: {error}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Rect(error) => Some(error),
+            Error::RegionDecode(error) => Some(error),
+        }
+    }
+}
+
+impl From<RectError> for Error {
+    fn from(error: RectError) -> Self {
+        Error::Rect(error)
+    }
+}
+
+impl From<RegionDecodeError> for Error {
+    fn from(error: RegionDecodeError) -> Self {
+        Error::RegionDecode(error)
+    }
+}
+
+/// The ways constructing a rectangle can fail.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RectError {
+    /// A width or height was negative.
+    NegativeSize,
+    /// The sides of the rectangle can't be represented by its unit type.
+    Overflow,
+    /// A side was NaN or infinite.
+    NonFinite,
+    /// A side was never given.
+    MissingSide,
+}
+
+impl fmt::Display for RectError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RectError::NegativeSize => write!(f, "rectangle has a negative width or height"),
+            RectError::Overflow => write!(f, "rectangle sides overflow the unit type"),
+            RectError::NonFinite => write!(f, "rectangle side is NaN or infinite"),
+            RectError::MissingSide => write!(f, "rectangle side is missing"),
+        }
+    }
+}
+
+impl std::error::Error for RectError {}
+
+/// The ways reading a [`RectRegion`](crate::RectRegion) back from bytes can fail.
+#[derive(Debug)]
+pub enum RegionDecodeError {
+    /// The reader failed, including running out of bytes part way through.
+    Io(std::io::Error),
+    /// The data doesn't start with the region header.
+    BadMagic,
+    /// The data was written by a newer version of the format.
+    UnsupportedVersion(u8),
+    /// A number was encoded with more bytes than it could need.
+    BadVarint,
+    /// A side doesn't fit in the unit type of the region.
+    Overflow,
+    /// The rectangles are not in the canonical order.
+    Unordered,
+    /// Two rectangles overlap, only checked when asked for.
+    Overlapping,
+    /// The rectangles don't overlap, but aren't split & merged the canonical way, only checked when asked for.
+    NotCanonical,
+}
+
+impl fmt::Display for RegionDecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RegionDecodeError::Io(error) => write!(f, "failed to read region: {error}"),
+            RegionDecodeError::BadMagic => write!(f, "data is not an encoded region"),
+            RegionDecodeError::UnsupportedVersion(version) => {
+                write!(f, "unsupported region format version {version}")
+            }
+            RegionDecodeError::BadVarint => write!(f, "region contains an overlong number"),
+            RegionDecodeError::Overflow => write!(f, "region sides overflow the unit type"),
+            RegionDecodeError::Unordered => write!(f, "region rectangles are out of order"),
+            RegionDecodeError::Overlapping => write!(f, "region rectangles overlap"),
+            RegionDecodeError::NotCanonical => {
+                write!(f, "region rectangles are not in canonical form")
+            }
+        }
+    }
+}
+
+impl std::error::Error for RegionDecodeError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            RegionDecodeError::Io(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for RegionDecodeError {
+    fn from(error: std::io::Error) -> Self {
+        RegionDecodeError::Io(error)
+    }
+}
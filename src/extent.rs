@@ -0,0 +1,15 @@
+/// The size of a rectangle without a position.
+///
+/// Like `Rectangle::width` & `Rectangle::height`, the sizes are the distance between the inclusive sides.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Extent<U> {
+    pub width: U,
+    pub height: U,
+}
+
+impl<U> Extent<U> {
+    /// Creates a new extent from a width & height.
+    pub fn new(width: U, height: U) -> Self {
+        Self { width, height }
+    }
+}
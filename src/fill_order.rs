@@ -0,0 +1,10 @@
+/// The order [`Rectangle::cells_in_order`](crate::Rectangle::cells_in_order) visits the cells of a grid in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum FillOrder {
+    /// Rows from top to bottom, each from left to right, like reading text.
+    RowMajor,
+    /// Columns from left to right, each from top to bottom.
+    ColumnMajor,
+    /// Rows from top to bottom, alternating between left to right & right to left so each row starts where the last ended.
+    Boustrophedon,
+}
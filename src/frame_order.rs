@@ -0,0 +1,10 @@
+/// Which bands [`Rectangle::split_around`](crate::Rectangle::split_around) puts in which slot.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum FrameOrder {
+    /// `[top, bottom, left, right]`, the top & bottom bands span the full width
+    /// & the left & right pieces only span the height of the hole.
+    HorizontalFirst,
+    /// `[left, right, top, bottom]`, the left & right bands span the full height
+    /// & the top & bottom pieces only span the width of the hole.
+    VerticalFirst,
+}
@@ -0,0 +1,225 @@
+//! Structured obstruction layouts for tests, benchmarks, & demos.
+//!
+//! Uniformly random rectangles mostly pile up into one blob, these generators build layouts that look more like real inputs.
+//! Each one is deterministic for a given seed & documents the properties its output always has.
+//! Sizes count units, since the sides are inclusive a wall one unit thick has its left equal to its right.
+//!
+//! # Example
+//! ```
+//! use rect_lib::{generate, BasicRectangle, Rectangle, RectangleRead};
+//!
+//! let parent = BasicRectangle::new_from_sides(0, 40, 30, 0);
+//! let walls = generate::corridor_maze(7, &parent, 2);
+//! let walls: Vec<&BasicRectangle> = walls.iter().collect();
+//! assert_eq!(parent.free_components(&walls).len(), 1);
+//! ```
+
+use crate::{BasicRectangle, FillOrder, RectRegion, Rectangle};
+
+/// A tiny xorshift generator, good enough for layouts & free of dependencies.
+struct Rng(u64);
+
+impl Rng {
+    fn new(seed: u64) -> Self {
+        // xorshift never leaves zero, so swap it for an arbitrary odd constant
+        Self(if seed == 0 {
+            0x9e37_79b9_7f4a_7c15
+        } else {
+            seed
+        })
+    }
+
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    /// A number in `0..max`, or zero if `max` is zero.
+    fn below(&mut self, max: usize) -> usize {
+        if max == 0 {
+            0
+        } else {
+            (self.next() % max as u64) as usize
+        }
+    }
+
+    /// A number in `min..=max`.
+    fn between(&mut self, min: i32, max: i32) -> i32 {
+        min + self.below((max - min + 1) as usize) as i32
+    }
+}
+
+/// Walls of a maze filling `parent`, with corridors `corridor_width` units wide between walls one unit thick.
+///
+/// - The walls are disjoint & inside of `parent`.
+/// - The free space is connected, & the corridors form a tree so there is exactly one way between any two cells.
+/// - Any space past the last whole cell on the right & bottom is walled off.
+///
+/// A `corridor_width` below one is treated as one. If not even one cell fits, the whole parent is returned as a wall.
+pub fn corridor_maze(
+    seed: u64,
+    parent: &BasicRectangle,
+    corridor_width: i32,
+) -> Vec<BasicRectangle> {
+    let mut rng = Rng::new(seed);
+    let corridor_width = corridor_width.max(1);
+    // a cell & the wall before it
+    let pitch = corridor_width + 1;
+    // a wall on the left & top, then a cell & the wall after it per pitch
+    let columns = (parent.width() / pitch).max(0) as usize;
+    let rows = (parent.height() / pitch).max(0) as usize;
+    if columns == 0 || rows == 0 {
+        return vec![*parent];
+    }
+
+    let cell = |column: usize, row: usize| {
+        let left = parent.left() + 1 + column as i32 * pitch;
+        let top = parent.top() - 1 - row as i32 * pitch;
+        BasicRectangle::new_from_sides(
+            left,
+            left + corridor_width - 1,
+            top,
+            top - corridor_width + 1,
+        )
+    };
+
+    // carve a spanning tree with a depth first search, knocking out the wall between each cell & the one it was reached from
+    let mut free: Vec<BasicRectangle> = Vec::new();
+    let mut visited = vec![false; columns * rows];
+    let mut stack = vec![(0, 0)];
+    visited[0] = true;
+    free.push(cell(0, 0));
+    while let Some(&(column, row)) = stack.last() {
+        let mut neighbours: Vec<(usize, usize)> = Vec::new();
+        if column > 0 {
+            neighbours.push((column - 1, row));
+        }
+        if column + 1 < columns {
+            neighbours.push((column + 1, row));
+        }
+        if row > 0 {
+            neighbours.push((column, row - 1));
+        }
+        if row + 1 < rows {
+            neighbours.push((column, row + 1));
+        }
+        neighbours.retain(|&(column, row)| !visited[row * columns + column]);
+        if neighbours.is_empty() {
+            stack.pop();
+            continue;
+        }
+
+        let (next_column, next_row) = neighbours[rng.below(neighbours.len())];
+        visited[next_row * columns + next_column] = true;
+        stack.push((next_column, next_row));
+
+        // the bounding box of the two cells includes the wall between them, opening a passage
+        let (a, b) = (cell(column, row), cell(next_column, next_row));
+        free.push(b);
+        free.push(BasicRectangle::new_from_sides(
+            a.left().min(b.left()),
+            a.right().max(b.right()),
+            a.top().max(b.top()),
+            a.bottom().min(b.bottom()),
+        ));
+    }
+
+    let free: RectRegion<BasicRectangle> = free.into_iter().collect();
+    RectRegion::from(*parent)
+        .subtract(&free)
+        .iter()
+        .copied()
+        .collect()
+}
+
+/// A grid of 4 by 4 unit boxes filling `parent`, each nudged up to `jitter` units from the center of its slot.
+///
+/// - The boxes are disjoint & inside of `parent`, each slot is `4 + 2 * jitter` units so nudges can't collide.
+/// - There is one box per whole slot, in reading order of the slots.
+///
+/// A negative `jitter` is treated as zero, which gives an even grid of boxes touching their neighbours.
+pub fn packed_grid(seed: u64, parent: &BasicRectangle, jitter: i32) -> Vec<BasicRectangle> {
+    let mut rng = Rng::new(seed);
+    let jitter = jitter.max(0);
+    let slot = 4 + 2 * jitter;
+
+    let slots = parent.fit_to_cell_grid(slot, slot);
+    slots
+        .cells_in_order(slot, slot, FillOrder::RowMajor)
+        .into_iter()
+        .map(|slot| {
+            let left = slot.left() + jitter + rng.between(-jitter, jitter);
+            let top = slot.top() - jitter + rng.between(-jitter, jitter);
+            BasicRectangle::new_from_sides(left, left + 3, top, top - 3)
+        })
+        .collect()
+}
+
+/// Up to `depth` frames nested inside `parent`, each a one unit thick ring of walls with a one unit door in a random side.
+///
+/// - The walls are disjoint & inside of `parent`.
+/// - Every frame has at least one unit of free space on each side of it, & the doors join it all into one connected space.
+/// - The frames are listed from the outside in, each as the up to five walls left around its door.
+///
+/// Fewer than `depth` frames are made if they stop fitting.
+pub fn nested_frames(seed: u64, parent: &BasicRectangle, depth: usize) -> Vec<BasicRectangle> {
+    let mut rng = Rng::new(seed);
+    let mut walls = Vec::new();
+
+    let mut bounds = *parent;
+    for _ in 0..depth {
+        // leave a gap of one or two units outside of the frame
+        let gap = rng.between(1, 2);
+        let frame = BasicRectangle::new_from_sides(
+            bounds.left() + gap,
+            bounds.right() - gap,
+            bounds.top() - gap,
+            bounds.bottom() + gap,
+        );
+        // the frame needs at least one point inside of it
+        if frame.width() < 2 || frame.height() < 2 {
+            break;
+        }
+
+        let (left, right, top, bottom) = (frame.left(), frame.right(), frame.top(), frame.bottom());
+        let mut sides = [
+            BasicRectangle::new_from_sides(left, right, top, top),
+            BasicRectangle::new_from_sides(left, right, bottom, bottom),
+            BasicRectangle::new_from_sides(left, left, top - 1, bottom + 1),
+            BasicRectangle::new_from_sides(right, right, top - 1, bottom + 1),
+        ];
+
+        // the door goes anywhere along a side except the corners, splitting that side in two
+        let side = rng.below(sides.len());
+        let wall = sides[side];
+        let [before, after] = if side < 2 {
+            let x = rng.between(left + 1, right - 1);
+            [
+                BasicRectangle::new_from_sides(wall.left(), x - 1, wall.top(), wall.bottom()),
+                BasicRectangle::new_from_sides(x + 1, wall.right(), wall.top(), wall.bottom()),
+            ]
+        } else {
+            let y = rng.between(bottom + 1, top - 1);
+            [
+                BasicRectangle::new_from_sides(wall.left(), wall.right(), wall.top(), y + 1),
+                BasicRectangle::new_from_sides(wall.left(), wall.right(), y - 1, wall.bottom()),
+            ]
+        };
+        sides[side] = before;
+
+        // a door next to a corner leaves nothing on that side of it
+        walls.extend(
+            sides
+                .into_iter()
+                .chain([after])
+                .filter(|wall| wall.left() <= wall.right() && wall.bottom() <= wall.top()),
+        );
+
+        // the next frame goes inside this one
+        bounds = BasicRectangle::new_from_sides(left + 1, right - 1, top - 1, bottom + 1);
+    }
+
+    walls
+}
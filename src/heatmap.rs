@@ -0,0 +1,126 @@
+use num::ToPrimitive;
+
+use crate::{integer_unit, partial_max, partial_min, RectangleRead};
+
+/// How many rectangles cover each cell of a grid, from [`Rectangle::coverage_heatmap`](crate::Rectangle::coverage_heatmap).
+///
+/// Cells are indexed by column from the left & row from the top, & the counts are stored row-major.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Heatmap {
+    columns: usize,
+    rows: usize,
+    counts: Vec<u32>,
+}
+
+impl Heatmap {
+    /// Counts the rectangles touching each `cell_w` by `cell_h` cell of `bounds`, starting at its top left corner.
+    ///
+    /// Integer units count cells in `i128` so nothing is lost, float units in `f64` so fractional cells work.
+    pub(crate) fn build<B, R>(bounds: &B, rects: &[&R], cell_w: B::Unit, cell_h: B::Unit) -> Self
+    where
+        B: RectangleRead,
+        B::Unit: ToPrimitive,
+        R: RectangleRead<Unit = B::Unit>,
+    {
+        let positive = |cell: B::Unit| cell.to_f64().is_some_and(|cell| cell > 0.0);
+        assert!(
+            positive(cell_w) && positive(cell_h),
+            "cells must have a positive size"
+        );
+
+        // how many whole cells fit between `from` & `to`, which is never before `from`
+        let integer = integer_unit::<B::Unit>();
+        let steps = |from: B::Unit, to: B::Unit, cell: B::Unit| -> usize {
+            if integer {
+                let wide = |unit: B::Unit| unit.to_i128().unwrap_or(0);
+                usize::try_from((wide(to) - wide(from)) / wide(cell)).unwrap_or(usize::MAX)
+            } else {
+                let wide = |unit: B::Unit| unit.to_f64().unwrap_or(0.0);
+                // `as` saturates, so a huge float count can't wrap
+                ((wide(to) - wide(from)) / wide(cell)).floor() as usize
+            }
+        };
+
+        let (left, right, top, bottom) =
+            (bounds.left(), bounds.right(), bounds.top(), bounds.bottom());
+        // a partial cell on the right or bottom still counts, so the last side starts a cell
+        let (columns, rows) = if left <= right && bottom <= top {
+            (
+                steps(left, right, cell_w).saturating_add(1),
+                steps(bottom, top, cell_h).saturating_add(1),
+            )
+        } else {
+            (0, 0)
+        };
+
+        let mut counts = vec![0; columns * rows];
+        for rect in rects {
+            // clip to the bounds, everything is then a positive offset from the top left
+            let (rect_left, rect_right) = (
+                partial_max(left, rect.left()),
+                partial_min(right, rect.right()),
+            );
+            let (rect_top, rect_bottom) = (
+                partial_min(top, rect.top()),
+                partial_max(bottom, rect.bottom()),
+            );
+            if !(rect_left <= rect_right && rect_bottom <= rect_top) {
+                continue;
+            }
+
+            let column = |x: B::Unit| steps(left, x, cell_w).min(columns - 1);
+            let row = |y: B::Unit| steps(y, top, cell_h).min(rows - 1);
+            for cy in row(rect_top)..=row(rect_bottom) {
+                for cx in column(rect_left)..=column(rect_right) {
+                    counts[cy * columns + cx] += 1;
+                }
+            }
+        }
+
+        Self {
+            columns,
+            rows,
+            counts,
+        }
+    }
+
+    /// Caps every count at one, leaving a mask of which cells are touched at all.
+    pub(crate) fn into_mask(mut self) -> Self {
+        for count in &mut self.counts {
+            *count = (*count).min(1);
+        }
+        self
+    }
+
+    /// The number of columns & rows, as `(columns, rows)`.
+    pub fn dimensions(&self) -> (usize, usize) {
+        (self.columns, self.rows)
+    }
+
+    /// The count for the cell in column `cx` & row `cy`, or `None` if it is outside the grid.
+    pub fn get(&self, cx: usize, cy: usize) -> Option<u32> {
+        if cx < self.columns && cy < self.rows {
+            Some(self.counts[cy * self.columns + cx])
+        } else {
+            None
+        }
+    }
+
+    /// The highest count of any cell, zero for an empty grid.
+    pub fn max(&self) -> u32 {
+        self.counts.iter().copied().max().unwrap_or(0)
+    }
+
+    /// The counts in row-major order, from the top left.
+    pub fn counts(&self) -> &[u32] {
+        &self.counts
+    }
+
+    /// Iterates over every cell as `(cx, cy, count)`, in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = (usize, usize, u32)> + '_ {
+        self.counts
+            .iter()
+            .enumerate()
+            .map(|(i, &count)| (i % self.columns, i / self.columns, count))
+    }
+}
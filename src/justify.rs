@@ -0,0 +1,84 @@
+use num::{NumCast, One, ToPrimitive, Zero};
+
+use crate::{Axis, Rectangle};
+
+/// How [`distribute`] spreads the free space of a container between its items, like `justify-content` in CSS.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Justify {
+    /// Packed at the start, with the free space after the last item.
+    Start,
+    /// Packed at the end, with the free space before the first item.
+    End,
+    /// Packed in the middle, with the free space split evenly before & after.
+    Center,
+    /// The first & last items at the ends, with the free space split evenly between the items.
+    SpaceBetween,
+    /// The free space split evenly around each item, so the ends get half as much as the gaps between items.
+    SpaceAround,
+}
+
+/// Moves already sized items along `axis` within `container`, spreading the free space between them according to `mode`.
+///
+/// Items keep their size & their position across the axis, & are laid out in slice order
+/// from left to right along [`Axis::X`] or top to bottom along [`Axis::Y`].
+/// Lengths count points, so items touching each other have no space between them.
+/// Integer units round each item down towards the start, & items that don't fit are packed at the start.
+///
+/// # Example
+/// ```
+/// use rect_lib::{distribute, Axis, BasicRectangle, Justify, Rectangle, RectangleRead};
+///
+/// let container = BasicRectangle::new_from_sides(0, 9, 1, 0);
+/// let mut items = [
+///     BasicRectangle::new_from_sides(0, 1, 1, 0),
+///     BasicRectangle::new_from_sides(0, 1, 1, 0),
+/// ];
+/// distribute(&container, &mut items, Axis::X, Justify::SpaceBetween);
+/// assert_eq!(items[0].left(), 0);
+/// assert_eq!(items[1].right(), 9);
+/// ```
+pub fn distribute<R: Rectangle>(container: &R, items: &mut [R], axis: Axis, mode: Justify)
+where
+    R::Unit: NumCast + ToPrimitive,
+{
+    let one = R::Unit::one();
+    let zero = R::Unit::zero();
+    let length = |rect: &R| match axis {
+        Axis::X => rect.width() + one,
+        Axis::Y => rect.height() + one,
+    };
+
+    let total = items.iter().fold(zero, |total, item| total + length(item));
+    let space = length(container);
+    let free = if total < space { space - total } else { zero };
+
+    // item `i` gets `(first + step * i) / parts` of the free space before it
+    let count = items.len();
+    let (first, step, parts) = match mode {
+        Justify::Start => (0, 0, 1),
+        Justify::End => (1, 0, 1),
+        Justify::Center => (1, 0, 2),
+        Justify::SpaceBetween => (0, 1, count.saturating_sub(1).max(1)),
+        Justify::SpaceAround => (1, 2, 2 * count.max(1)),
+    };
+    let free = free.to_f64().unwrap_or(0.0);
+
+    let mut before = zero;
+    for (i, item) in items.iter_mut().enumerate() {
+        let share = free * (first + step * i) as f64 / parts as f64;
+        let offset = before + NumCast::from(share).unwrap_or(zero);
+        before = before + length(item);
+
+        let (width, height) = (item.width(), item.height());
+        *item = match axis {
+            Axis::X => {
+                let left = container.left() + offset;
+                R::new_from_sides(left, left + width, item.top(), item.bottom())
+            }
+            Axis::Y => {
+                let top = container.top() - offset;
+                R::new_from_sides(item.left(), item.right(), top, top - height)
+            }
+        };
+    }
+}
@@ -1,72 +1,368 @@
 use core::cmp::Reverse;
-use num::{Num, One};
+use core::hash::Hasher;
+use core::ops::ControlFlow;
+use num::{Num, NumCast, One, ToPrimitive, Zero};
 
 // re-export the num crate
 pub use num;
 
+// axis
+mod axis;
+pub use axis::Axis;
+
+// sides of a rectangle
+mod side;
+pub use side::Side;
+
+// corridors between rectangles
+mod corridor;
+
+// errors
+mod error;
+pub use error::{Error, RectError, RegionDecodeError};
+
+// building rectangles by named sides
+mod rect_builder;
+pub use rect_builder::RectBuilder;
+
+// caching expensive sides
+mod cached;
+pub use cached::Cached;
+
 // basic rectangle
 mod basic_rectangle;
 pub use basic_rectangle::BasicRectangle;
 
-/// A trait containing methods for rectangle like data structures which implement `Sized` & `Copy`.
+// sizes
+mod extent;
+pub use extent::Extent;
+
+// relationships between rectangles
+mod overlap_kind;
+pub use overlap_kind::OverlapKind;
+
+// finding the rectangles around another
+mod containment_index;
+pub use containment_index::{ContainmentIndex, ContainmentIter};
+
+// multi-resolution occupancy
+mod rect_pyramid;
+pub use rect_pyramid::{Occupancy, RectPyramid};
+
+// easing curves
+mod easing;
+pub use easing::Easing;
+
+// coordinate conventions
+mod oriented;
+pub use oriented::Oriented;
+
+// paths
+mod path;
+pub use path::sweep_along_path;
+
+// common imports
+pub mod prelude;
+
+// structured layouts for testing
+pub mod generate;
+
+// moving between regions, grids, & polygons
+pub mod convert;
+
+// regions
+mod rect_region;
+pub use rect_region::{
+    boolean_op, coverage_fraction, drop_slivers, greedy_cover, union_area, BoolOp, RectRegion,
+};
+
+// reading & writing regions
+mod region_io;
+
+// rectangles over time
+mod rect_track;
+pub use rect_track::RectTrack;
+
+// deduplicating by fingerprint
+mod rect_fingerprint;
+use rect_fingerprint::Fnv;
+pub use rect_fingerprint::{HashBytes, RectFingerprintSet};
+
+// rectangle sets
+mod rect_set;
+pub use rect_set::{
+    clip_all, clip_all_into, clip_all_sorted, clip_all_sorted_into, cluster_overlapping,
+    merge_overlapping, merge_overlapping_into, merge_plan, merge_rects, merge_rects_into,
+    overlap_adjacency, remove_contained, remove_contained_indices, retain_clipped, separate_rects,
+    MergeStep,
+};
+
+// line segments
+mod segment;
+pub use segment::Segment;
+
+// coverage counts per grid cell
+mod heatmap;
+pub use heatmap::Heatmap;
+
+// frame decompositions around a hole
+mod frame_order;
+pub use frame_order::FrameOrder;
+
+// grid traversal orders
+mod fill_order;
+pub use fill_order::FillOrder;
+
+// typed units
+mod typed;
+pub use typed::{Area, Height, Typed, TypedRectangle, Width, XCoord, YCoord};
+
+// exact tiling
+mod tiling;
+
+// stacking layouts
+mod stack;
+pub use stack::stack;
+
+// justifying items in a container
+mod justify;
+pub use justify::{distribute, Justify};
+
+// watching the sweep
+mod trace;
+#[cfg(feature = "trace")]
+pub use trace::{RecordingObserver, SweepEvent, SweepObserver};
+
+// scrolling
+mod scroll;
+pub use scroll::{scroll_region, BlitPlan};
+
+// ascii art for debugging
+mod render;
+pub use render::{render_ascii, MAX_ASCII_POINTS};
+
+// scanline utilities
+mod scanline;
+pub use scanline::{
+    occupancy_grid, projection_profile_x, projection_profile_y, scanline_spans, vertical_gaps_at,
+    x_event_lines,
+};
+
+// mixing unit types
+mod unit_cast;
+pub use unit_cast::{intersection_cast, union_hull_cast};
+
+// options for unobstructed_subrectangles_with
+mod unobstructed_options;
+pub use unobstructed_options::{Decomposition, UnobstructedOptions};
+
+// the sweep behind unobstructed_subrectangles
+mod unobstructed_sweep_line;
+pub use unobstructed_sweep_line::{normalize_obstructions, BudgetStrategy, SweepState};
+
+/// The aspect ratios [`Rectangle::snap_to_common_aspect`] snaps to, as `(width, height)`.
+/// Portrait rectangles snap to the same ratios turned on their side.
+pub const COMMON_ASPECT_RATIOS: [(u32, u32); 7] =
+    [(1, 1), (5, 4), (4, 3), (3, 2), (16, 10), (16, 9), (21, 9)];
+
+/// Checks if the unit divides like an integer, rounding a half down to zero, rather than like a float.
+pub(crate) fn integer_unit<U: Num + One + Copy>() -> bool {
+    U::one() / (U::one() + U::one()) == U::zero()
+}
+
+/// The smaller of two units, only needing `PartialOrd` unlike `Ord::min`.
+/// If they can't be compared the first is returned.
+pub(crate) fn partial_min<U: PartialOrd>(a: U, b: U) -> U {
+    if b < a {
+        b
+    } else {
+        a
+    }
+}
+
+/// The larger of two units, only needing `PartialOrd` unlike `Ord::max`.
+/// If they can't be compared the first is returned.
+pub(crate) fn partial_max<U: PartialOrd>(a: U, b: U) -> U {
+    if b > a {
+        b
+    } else {
+        a
+    }
+}
+
+/// The key of the canonical rectangle order: reading order (top to bottom, then left to right), with the bottom & right breaking ties.
+/// Containers sort by this for [`RectRegion::iter_sorted`] & the like, so the order never depends on how they are built.
+pub(crate) fn canonical_key<R: RectangleRead>(
+    rect: &R,
+) -> (Reverse<R::Unit>, R::Unit, R::Unit, R::Unit) {
+    (
+        Reverse(rect.top()),
+        rect.left(),
+        rect.bottom(),
+        rect.right(),
+    )
+}
+
+/// The number of points covered by a rectangle.
+/// The sides are inclusive, so this is `(width + 1) * (height + 1)`.
+pub(crate) fn points_in<R: RectangleRead>(rect: &R) -> R::Unit {
+    (rect.width() + R::Unit::one()) * (rect.height() + R::Unit::one())
+}
+
+/// The number of points covered by a rectangle, widened to an `i128` so it can't overflow.
+pub(crate) fn points_in_wide<R: RectangleRead>(rect: &R) -> i128
+where
+    R::Unit: ToPrimitive,
+{
+    let side = |unit: R::Unit| unit.to_i128().unwrap_or(0);
+    (side(rect.right()) - side(rect.left()) + 1) * (side(rect.top()) - side(rect.bottom()) + 1)
+}
+
+/// Splits `units` into shares proportional to `weights`, returning the offsets between them starting at zero.
+///
+/// For integer units the running total is rounded rather than each share, so the rounding never adds up & the last offset is `units`.
+/// Float units aren't rounded at all.
+/// Negative weights count as zero, & if every weight is zero the shares are even.
+fn weighted_offsets<U: Num + Copy + NumCast>(units: U, weights: &[f64]) -> Vec<U> {
+    let mut weights: Vec<f64> = weights.iter().map(|&weight| weight.max(0.0)).collect();
+    if weights.iter().sum::<f64>() <= 0.0 {
+        weights.fill(1.0);
+    }
+    let total_weight: f64 = weights.iter().sum();
+    let units_f64 = units.to_f64().unwrap_or(0.0);
+
+    let integer = integer_unit::<U>();
+
+    let mut offsets = vec![U::zero()];
+    let mut cumulative = 0.0;
+    for weight in &weights {
+        cumulative += weight;
+        let offset = units_f64 * cumulative / total_weight;
+        let offset = if integer { offset.round() } else { offset };
+        offsets.push(NumCast::from(offset).unwrap_or(U::zero()));
+    }
+    offsets
+}
+
+/// Wraps the inclusive range `min..=max` into a toroidal world along one axis,
+/// returning the part already inside, the part past the end wrapped to the start, & the part before the start wrapped to the end.
+/// A range as long as the world covers all of it, so it is only the first part.
+///
+/// This is the one toroidal split, [`Rectangle::wrap_pieces`] & [`Rectangle::translate_wrapping`] both cut with it.
+fn wrap_range<U: Num + Copy + PartialOrd>(
+    min: U,
+    max: U,
+    world_min: U,
+    world_max: U,
+) -> [Option<(U, U)>; 3] {
+    let one = U::one();
+    let size = world_max - world_min + one;
+    if max - min + one >= size {
+        return [Some((world_min, world_max)), None, None];
+    }
+
+    [
+        (min <= world_max && world_min <= max)
+            .then(|| (partial_max(min, world_min), partial_min(max, world_max))),
+        (max > world_max).then(|| (partial_max(min, world_max + one) - size, max - size)),
+        (min < world_min).then(|| (min + size, partial_min(max, world_min - one) + size)),
+    ]
+}
+
+/// Linearly interpolates between two units with `t` clamped to `0.0..=1.0`, rounding to the nearest unit for integer units.
+/// If the result doesn't fit in the unit, whichever end `t` is closer to is returned.
+fn lerp_unit<U: Num + Copy + NumCast>(from: U, to: U, t: f64) -> U {
+    let t = t.clamp(0.0, 1.0);
+    from.to_f64()
+        .zip(to.to_f64())
+        .map(|(a, b)| a + (b - a) * t)
+        .and_then(|value| {
+            NumCast::from(if integer_unit::<U>() {
+                value.round()
+            } else {
+                value
+            })
+        })
+        .unwrap_or(if t < 0.5 { from } else { to })
+}
+
+/// Resizes a rectangle to `width` by `height` around its center.
 ///
+/// Half the change goes to each side, rounding towards zero so the extra unit of an odd change stays on the right & bottom.
+/// Growing & shrinking are worked out separately, so unsigned units never go below zero in between.
+fn resize_centered<R: Rectangle>(rect: &R, width: R::Unit, height: R::Unit) -> R {
+    let two = R::Unit::one() + R::Unit::one();
+    let left = if width <= rect.width() {
+        rect.left() + (rect.width() - width) / two
+    } else {
+        rect.left() - (width - rect.width()) / two
+    };
+    let top = if height <= rect.height() {
+        rect.top() - (rect.height() - height) / two
+    } else {
+        rect.top() + (height - rect.height()) / two
+    };
+    R::new_from_sides(left, left + width, top, top - height)
+}
+
+/// The read-only half of [`Rectangle`], for rectangle like data structures that can't be constructed.
+///
+/// Everything taking another rectangle (obstructions, targets, & so on) only needs this,
+/// so foreign types can be passed in by implementing the four sides.
 /// This trait treats all edges (left, right, top, & bottom) as inclusive.
 ///
+/// The unit only has to be `PartialOrd`, so floats work for the geometry basics.
+/// Methods that sort or sweep (like [`Rectangle::unobstructed_subrectangles`]) also ask for `Unit: Ord`.
+///
+/// The defaults call the accessors freely, so if they are expensive wrap the rectangle in [`Cached`] first.
+/// The sweeps ([`Rectangle::unobstructed_subrectangles`] & the [`RectRegion`] operations) & [`Rectangle::intersections_with`]
+/// already read each rectangle's sides once up front, everything else reads them as it goes.
+///
 /// # Example
 /// ```
-/// use rect_lib::Rectangle;
+/// use rect_lib::{BasicRectangle, Rectangle, RectangleRead};
 ///
-/// #[derive(Clone, Copy)]
-/// pub struct BasicRectangle {
-///     x: i32,
-///     y: i32,
-///     width: i32,
-///     height: i32,
+/// /// A rectangle owned by someone else, we can only look at it.
+/// pub struct ForeignRect {
+///     sides: [i32; 4],
 /// }
 ///
-/// impl Rectangle for BasicRectangle {
+/// impl RectangleRead for ForeignRect {
 ///     type Unit = i32;
 ///
 ///     fn left(&self) -> i32 {
-///         self.x
+///         self.sides[0]
 ///     }
 ///
 ///     fn right(&self) -> i32 {
-///         self.x + self.width - 1
+///         self.sides[1]
 ///     }
 ///
 ///     fn top(&self) -> i32 {
-///         self.y
+///         self.sides[2]
 ///     }
 ///
 ///     fn bottom(&self) -> i32 {
-///         self.y - self.height + 1
-///     }
-///
-///     fn new_from_sides(left: i32, right: i32, top: i32, bottom: i32) -> Self {
-///         Self {
-///             x: left,
-///             y: top,
-///             width: right - left + 1,
-///             height: top - bottom + 1,
-///         }
+///         self.sides[3]
 ///     }
 /// }
+///
+/// let foreign = ForeignRect { sides: [0, 2, 5, 1] };
+/// let rect = BasicRectangle::new_from_sides(0, 5, 5, 0);
+/// assert!(rect.overlaps(&foreign));
+/// assert_eq!(rect.unobstructed_subrectangles(&[&foreign]).len(), 2);
 /// ```
-pub trait Rectangle
-where
-    Self: Sized + Copy,
-{
+pub trait RectangleRead {
     // - Required implementations.
 
     /// The unit type used for the rectangle.
-    type Unit: Num + One + Copy + PartialEq + PartialOrd + Ord;
+    type Unit: Num + One + Copy + PartialEq + PartialOrd;
 
     /// The left most point of the rectangle.
     ///
     /// # Example
     /// ```
-    /// use rect_lib::{BasicRectangle, Rectangle};
+    /// use rect_lib::{BasicRectangle, Rectangle, RectangleRead};
     ///
     /// let rect = BasicRectangle::new_from_sides(0, 1, 2, 3);
     /// assert_eq!(rect.left(), 0);
@@ -77,7 +373,7 @@ where
     ///
     /// # Example
     /// ```
-    /// use rect_lib::{BasicRectangle, Rectangle};
+    /// use rect_lib::{BasicRectangle, Rectangle, RectangleRead};
     ///
     /// let rect = BasicRectangle::new_from_sides(0, 1, 2, 3);
     /// assert_eq!(rect.right(), 1);
@@ -88,7 +384,7 @@ where
     ///
     /// # Example
     /// ```
-    /// use rect_lib::{BasicRectangle, Rectangle};
+    /// use rect_lib::{BasicRectangle, Rectangle, RectangleRead};
     ///
     /// let rect = BasicRectangle::new_from_sides(0, 1, 2, 3);
     /// assert_eq!(rect.top(), 2);
@@ -99,29 +395,13 @@ where
     ///
     /// # Example
     /// ```
-    /// use rect_lib::{BasicRectangle, Rectangle};
+    /// use rect_lib::{BasicRectangle, Rectangle, RectangleRead};
     ///
     /// let rect = BasicRectangle::new_from_sides(0, 1, 2, 3);
     /// assert_eq!(rect.bottom(), 3);
     /// ```
     fn bottom(&self) -> Self::Unit;
 
-    /// Creates a new rectangle from the given sides.
-    /// The sides are inclusive.
-    ///
-    /// # Example
-    /// ```
-    /// use rect_lib::{BasicRectangle, Rectangle};
-    ///
-    /// let rect = BasicRectangle::new_from_sides(0, 1, 2, 3);
-    /// ```
-    fn new_from_sides(
-        left: Self::Unit,
-        right: Self::Unit,
-        top: Self::Unit,
-        bottom: Self::Unit,
-    ) -> Self;
-
     // - Default implementations.
 
     /// The width of the rectangle.
@@ -129,7 +409,7 @@ where
     ///
     /// # Example
     /// ```
-    /// use rect_lib::{BasicRectangle, Rectangle};
+    /// use rect_lib::{BasicRectangle, Rectangle, RectangleRead};
     ///
     /// let rect = BasicRectangle::new_from_sides(0, 1, 1, 0);
     /// assert_eq!(rect.width(), 1);
@@ -143,7 +423,7 @@ where
     ///
     /// # Example
     /// ```
-    /// use rect_lib::{BasicRectangle, Rectangle};
+    /// use rect_lib::{BasicRectangle, Rectangle, RectangleRead};
     ///
     /// let rect = BasicRectangle::new_from_sides(0, 1, 1, 0);
     /// assert_eq!(rect.height(), 1);
@@ -152,60 +432,132 @@ where
         self.top() - self.bottom()
     }
 
-    /// Translates the rectangle by the given amount.
-    /// This is done by adding the given amount to the x and y coordinates.
+    /// The lowest point of the rectangle along the given axis.
+    /// This is `left` for the x axis & `bottom` for the y axis.
     ///
     /// # Example
     /// ```
-    /// use rect_lib::{BasicRectangle, Rectangle};
+    /// use rect_lib::{Axis, BasicRectangle, Rectangle, RectangleRead};
     ///
-    /// let rect = BasicRectangle::new_from_sides(0, 1, 1, 0);
-    /// let translated = rect.translate(1, 1);
-    /// assert_eq!(translated, BasicRectangle::new_from_sides(1, 2, 2, 1));
+    /// let rect = BasicRectangle::new_from_sides(0, 1, 3, 2);
+    /// assert_eq!(rect.min_along(Axis::X), 0);
+    /// assert_eq!(rect.min_along(Axis::Y), 2);
     /// ```
-    fn translate(&self, x: Self::Unit, y: Self::Unit) -> Self {
-        Self::new_from_sides(
-            self.left() + x,
-            self.right() + x,
-            self.top() + y,
-            self.bottom() + y,
-        )
+    fn min_along(&self, axis: Axis) -> Self::Unit {
+        match axis {
+            Axis::X => self.left(),
+            Axis::Y => self.bottom(),
+        }
+    }
+
+    /// The highest point of the rectangle along the given axis.
+    /// This is `right` for the x axis & `top` for the y axis.
+    ///
+    /// # Example
+    /// ```
+    /// use rect_lib::{Axis, BasicRectangle, Rectangle, RectangleRead};
+    ///
+    /// let rect = BasicRectangle::new_from_sides(0, 1, 3, 2);
+    /// assert_eq!(rect.max_along(Axis::X), 1);
+    /// assert_eq!(rect.max_along(Axis::Y), 3);
+    /// ```
+    fn max_along(&self, axis: Axis) -> Self::Unit {
+        match axis {
+            Axis::X => self.right(),
+            Axis::Y => self.top(),
+        }
+    }
+
+    /// The size of the rectangle along the given axis.
+    /// This is the `width` for the x axis & the `height` for the y axis.
+    ///
+    /// # Example
+    /// ```
+    /// use rect_lib::{Axis, BasicRectangle, Rectangle, RectangleRead};
+    ///
+    /// let rect = BasicRectangle::new_from_sides(0, 1, 3, 0);
+    /// assert_eq!(rect.extent_along(Axis::X), 1);
+    /// assert_eq!(rect.extent_along(Axis::Y), 3);
+    /// ```
+    fn extent_along(&self, axis: Axis) -> Self::Unit {
+        match axis {
+            Axis::X => self.width(),
+            Axis::Y => self.height(),
+        }
     }
 
     /// The perimeter of the rectangle.
     /// This is calculated as `(width + height) * 2`.
     ///
+    /// Inverted rectangles give a negative or zero result rather than panicking.
+    ///
+    /// # Panics
+    /// In debug builds, if the result overflows an integer unit type, even one that wraps silently.
+    ///
     /// # Example
     /// ```
-    /// use rect_lib::{BasicRectangle, Rectangle};
+    /// use rect_lib::{BasicRectangle, Rectangle, RectangleRead};
     ///
     /// let rect = BasicRectangle::new_from_sides(0, 1, 1, 0);
     /// assert_eq!(rect.perimeter(), 4);
     /// ```
     fn perimeter(&self) -> Self::Unit {
-        (self.width() + self.height()) * (Self::Unit::one() + Self::Unit::one())
+        let two = Self::Unit::one() + Self::Unit::one();
+        let (width, height) = (self.width(), self.height());
+        let half = width + height;
+        let perimeter = half * two;
+
+        // catch wrapping integer units in debug builds, adding a positive height can't go down & a negative one can't go up,
+        // & the doubling has to halve back exactly. Floats don't wrap, so they are left alone
+        debug_assert!(
+            !integer_unit::<Self::Unit>()
+                || (if height >= Self::Unit::zero() {
+                    half >= width
+                } else {
+                    half <= width
+                } && perimeter / two == half),
+            "rectangle perimeter overflowed its unit type"
+        );
+
+        perimeter
     }
 
     /// The area of the rectangle.
     /// This is calculated as `width * height`.
     ///
+    /// Inverted rectangles give a negative or zero result rather than panicking.
+    ///
+    /// # Panics
+    /// In debug builds, if the result overflows an integer unit type, even one that wraps silently.
+    ///
     /// # Example
     /// ```
-    /// use rect_lib::{BasicRectangle, Rectangle};
+    /// use rect_lib::{BasicRectangle, Rectangle, RectangleRead};
     ///
     /// let rect = BasicRectangle::new_from_sides(0, 1, 1, 0);
     /// assert_eq!(rect.area(), 1);
     /// ```
     fn area(&self) -> Self::Unit {
         // This function is so cute for some reason
-        self.width() * self.height() // :3
+        let area = self.width() * self.height(); // :3
+
+        // catch wrapping integer units in debug builds, integer division only undoes the product exactly if it didn't wrap.
+        // Float division rounds, so floats are left alone
+        debug_assert!(
+            !integer_unit::<Self::Unit>()
+                || self.width() == Self::Unit::zero()
+                || area / self.width() == self.height(),
+            "rectangle area overflowed its unit type"
+        );
+
+        area
     }
 
     /// Checks if the rectangle contains the given point.
     ///
     /// # Example
     /// ```
-    /// use rect_lib::{BasicRectangle, Rectangle};
+    /// use rect_lib::{BasicRectangle, Rectangle, RectangleRead};
     ///
     /// let rect = BasicRectangle::new_from_sides(0, 1, 1, 0);
     /// assert!(rect.contains_point(0, 1));
@@ -219,271 +571,3038 @@ where
     ///
     /// # Example
     /// ```
-    /// use rect_lib::{BasicRectangle, Rectangle};
+    /// use rect_lib::{BasicRectangle, Rectangle, RectangleRead};
     ///
     /// let rect = BasicRectangle::new_from_sides(0, 2, 2, 0);
     /// let other = BasicRectangle::new_from_sides(0, 1, 1, 0);
     /// assert!(rect.contains_rectangle(&other));
     /// assert!(!other.contains_rectangle(&rect));
     /// ```
-    fn contains_rectangle(&self, other: &impl Rectangle<Unit = Self::Unit>) -> bool {
-        self.left() <= other.left()
-            && self.right() >= other.right()
-            && self.top() >= other.top()
-            && self.bottom() <= other.bottom()
+    fn contains_rectangle(&self, other: &impl RectangleRead<Unit = Self::Unit>) -> bool {
+        self.contains_sides(other.left(), other.right(), other.top(), other.bottom())
+    }
+
+    /// Checks if the rectangle contains a rectangle given as raw sides.
+    ///
+    /// # Example
+    /// ```
+    /// use rect_lib::{BasicRectangle, Rectangle, RectangleRead};
+    ///
+    /// let rect = BasicRectangle::new_from_sides(0, 2, 2, 0);
+    /// assert!(rect.contains_sides(0, 1, 1, 0));
+    /// assert!(!rect.contains_sides(0, 3, 3, 0));
+    /// ```
+    fn contains_sides(
+        &self,
+        left: Self::Unit,
+        right: Self::Unit,
+        top: Self::Unit,
+        bottom: Self::Unit,
+    ) -> bool {
+        self.left() <= left && self.right() >= right && self.top() >= top && self.bottom() <= bottom
     }
 
     /// Checks if one rectangle overlaps with another.
     ///
     /// # Example
     /// ```
-    /// use rect_lib::{BasicRectangle, Rectangle};
+    /// use rect_lib::{BasicRectangle, Rectangle, RectangleRead};
     ///
     /// let rect = BasicRectangle::new_from_sides(0, 2, 2, 0);
     /// assert!(rect.overlaps(&BasicRectangle::new_from_sides(1, 3, 3, 1)));
     /// assert!(!rect.overlaps(&BasicRectangle::new_from_sides(3, 4, 4, 3)));
     /// ```
-    fn overlaps(&self, other: &impl Rectangle<Unit = Self::Unit>) -> bool {
-        self.left() <= other.right()
-            && self.right() >= other.left()
-            && self.top() >= other.bottom()
-            && self.bottom() <= other.top()
+    fn overlaps(&self, other: &impl RectangleRead<Unit = Self::Unit>) -> bool {
+        self.overlaps_sides(other.left(), other.right(), other.top(), other.bottom())
     }
 
-    /// Returns the intersection of two rectangles.
-    /// If the rectangles do not intersect, `None` is returned.
+    /// Checks if the rectangle overlaps any of the others, stopping at the first overlap.
     ///
     /// # Example
     /// ```
-    /// use rect_lib::{BasicRectangle, Rectangle};
+    /// use rect_lib::{BasicRectangle, Rectangle, RectangleRead};
     ///
     /// let rect = BasicRectangle::new_from_sides(0, 2, 2, 0);
-    /// let intersection = rect.intersection(&BasicRectangle::new_from_sides(1, 3, 3, 1));
-    /// assert_eq!(intersection, Some(BasicRectangle::new_from_sides(1, 2, 2, 1)));
+    /// let others = [
+    ///     BasicRectangle::new_from_sides(5, 6, 6, 5),
+    ///     BasicRectangle::new_from_sides(2, 3, 3, 2),
+    /// ];
+    /// assert!(rect.overlaps_any(&others));
+    /// assert!(!rect.overlaps_any(&others[..1]));
+    /// ```
+    #[inline]
+    fn overlaps_any(&self, others: &[impl RectangleRead<Unit = Self::Unit>]) -> bool {
+        for other in others {
+            if self.overlaps(other) {
+                return true;
+            }
+        }
+        false
+    }
+
+    /// Returns the first of the others overlapping the rectangle, along with its index.
+    /// The others are checked in order, so this is the earliest overlapping index & nothing after it is looked at.
     ///
-    /// let no_intersection = rect.intersection(&BasicRectangle::new_from_sides(3, 4, 4, 3));
-    /// assert_eq!(no_intersection, None);
+    /// # Example
     /// ```
-    fn intersection(&self, other: &impl Rectangle<Unit = Self::Unit>) -> Option<Self> {
-        let left = self.left().max(other.left());
-        let right = self.right().min(other.right());
-        let top = self.top().min(other.top());
-        let bottom = self.bottom().max(other.bottom());
+    /// use rect_lib::{BasicRectangle, Rectangle, RectangleRead};
+    ///
+    /// let rect = BasicRectangle::new_from_sides(0, 2, 2, 0);
+    /// let others = [
+    ///     BasicRectangle::new_from_sides(5, 6, 6, 5),
+    ///     BasicRectangle::new_from_sides(2, 3, 3, 2),
+    /// ];
+    /// assert_eq!(rect.first_overlapping(&others), Some((1, &others[1])));
+    /// ```
+    #[inline]
+    fn first_overlapping<'a, R: RectangleRead<Unit = Self::Unit>>(
+        &self,
+        others: &'a [R],
+    ) -> Option<(usize, &'a R)> {
+        for (i, other) in others.iter().enumerate() {
+            if self.overlaps(other) {
+                return Some((i, other));
+            }
+        }
+        None
+    }
 
-        if left <= right && bottom <= top {
-            Some(Self::new_from_sides(left, right, top, bottom))
-        } else {
-            None
+    /// Counts how many of the others overlap the rectangle.
+    ///
+    /// # Example
+    /// ```
+    /// use rect_lib::{BasicRectangle, Rectangle, RectangleRead};
+    ///
+    /// let rect = BasicRectangle::new_from_sides(0, 2, 2, 0);
+    /// let others = [
+    ///     BasicRectangle::new_from_sides(1, 3, 3, 1),
+    ///     BasicRectangle::new_from_sides(5, 6, 6, 5),
+    ///     BasicRectangle::new_from_sides(2, 3, 3, 2),
+    /// ];
+    /// assert_eq!(rect.count_overlapping(&others), 2);
+    /// ```
+    #[inline]
+    fn count_overlapping(&self, others: &[impl RectangleRead<Unit = Self::Unit>]) -> usize {
+        let mut count = 0;
+        for other in others {
+            if self.overlaps(other) {
+                count += 1;
+            }
         }
+        count
     }
 
-    /// This algorithm identifies all unique unobstructed sub-rectangles within a given rectangle by comparing it against a list of obstructions.
+    /// Narrows rectangles sorted by `left` down to the ones that could overlap this rectangle.
+    ///
+    /// A binary search drops every rectangle starting to the right of this one,
+    /// the window can then be passed to `overlaps_any`, `first_overlapping`, or `count_overlapping`.
+    /// Indices into the window are indices into `sorted_by_left`, since it always starts at zero.
     ///
     /// # Example
     /// ```
-    /// use rect_lib::{BasicRectangle, Rectangle};
+    /// use rect_lib::{BasicRectangle, Rectangle, RectangleRead};
     ///
-    /// let rect = BasicRectangle::new_from_sides(0, 5, 5, 0);
-    /// let obstruction = BasicRectangle::new_from_sides(0, 2, 5, 1);
-    /// let subrects = rect.unobstructed_subrectangles(&vec![&obstruction]);
+    /// let rect = BasicRectangle::new_from_sides(0, 2, 2, 0);
+    /// let sorted_by_left = [
+    ///     BasicRectangle::new_from_sides(-3, -1, 1, 0),
+    ///     BasicRectangle::new_from_sides(2, 3, 3, 2),
+    ///     BasicRectangle::new_from_sides(5, 6, 6, 5),
+    /// ];
+    /// let window = rect.overlap_window(&sorted_by_left);
+    /// assert_eq!(window.len(), 2);
+    /// assert_eq!(rect.first_overlapping(window), Some((1, &sorted_by_left[1])));
+    /// ```
+    #[inline]
+    fn overlap_window<'a, R: RectangleRead<Unit = Self::Unit>>(
+        &self,
+        sorted_by_left: &'a [R],
+    ) -> &'a [R] {
+        let end = sorted_by_left.partition_point(|other| other.left() <= self.right());
+        &sorted_by_left[..end]
+    }
+
+    /// Checks if the rectangle overlaps a rectangle given as raw sides.
+    /// Inverted rectangles hold no points, so they never overlap anything, like `intersection` returning `None`.
     ///
-    /// assert_eq!(subrects.len(), 2);
-    /// assert!(subrects.iter().all(|r| [
-    ///     BasicRectangle::new_from_sides(0, 5, 0, 0),
-    ///     BasicRectangle::new_from_sides(3, 5, 5, 0)
-    /// ].contains(r)));
+    /// # Example
     /// ```
-    fn unobstructed_subrectangles(
+    /// use rect_lib::{BasicRectangle, Rectangle, RectangleRead};
+    ///
+    /// let rect = BasicRectangle::new_from_sides(0, 2, 2, 0);
+    /// assert!(rect.overlaps_sides(1, 3, 3, 1));
+    /// assert!(!rect.overlaps_sides(3, 4, 4, 3));
+    /// ```
+    fn overlaps_sides(
         &self,
-        obstructions: &[&impl Rectangle<Unit = Self::Unit>],
-    ) -> Vec<Self> {
-        /// A rectangle that has not been obstructed yet
-        #[derive(Clone)]
-        struct UnfinishedRect<T: Rectangle> {
-            left: T::Unit,
-            top: T::Unit,
-            bottom: T::Unit,
-        }
-        /// A gap between two obstructions
-        struct Gap<T: Rectangle> {
-            top: T::Unit,
-            bottom: T::Unit,
-        }
-        /// A line we need to check for gaps
-        struct Line<T: Rectangle> {
-            x: T::Unit,
-            opens: bool,
-        }
-
-        let mut obstructions = obstructions.to_vec();
-        // sort the obstructions by top position
-        obstructions.sort_unstable_by(
-            // descending order
-            |rect_a, rect_b| {
-                rect_b.top().cmp(&rect_a.top()) // by the first point on each
-            },
-        );
+        left: Self::Unit,
+        right: Self::Unit,
+        top: Self::Unit,
+        bottom: Self::Unit,
+    ) -> bool {
+        left <= right
+            && bottom <= top
+            && self.left() <= self.right()
+            && self.bottom() <= self.top()
+            && self.left() <= right
+            && self.right() >= left
+            && self.top() >= bottom
+            && self.bottom() <= top
+    }
 
-        // Section 1: collect all lines that need to be checked for gaps
-        let mut lines: Vec<Line<Self>> = vec![Line {
-            x: self.left(),
-            opens: true,
-        }];
+    /// Classifies how the rectangles relate in one call, the most specific kind is returned.
+    ///
+    /// Since the sides are inclusive, touching means the sides are one unit apart along one axis & overlap along the other.
+    /// Rectangles that only meet diagonally at a corner are disjoint.
+    ///
+    /// # Example
+    /// ```
+    /// use rect_lib::{BasicRectangle, OverlapKind, Rectangle, RectangleRead};
+    ///
+    /// let rect = BasicRectangle::new_from_sides(0, 3, 3, 0);
+    /// assert_eq!(rect.overlap_kind(&BasicRectangle::new_from_sides(4, 6, 2, 1)), OverlapKind::Touching);
+    /// assert_eq!(rect.overlap_kind(&BasicRectangle::new_from_sides(1, 2, 2, 1)), OverlapKind::Contains);
+    /// ```
+    fn overlap_kind(&self, other: &impl RectangleRead<Unit = Self::Unit>) -> OverlapKind {
+        let contains = self.contains_rectangle(other);
+        let contained = other.contains_sides(self.left(), self.right(), self.top(), self.bottom());
+        if contains && contained {
+            return OverlapKind::Equal;
+        } else if contains {
+            return OverlapKind::Contains;
+        } else if contained {
+            return OverlapKind::ContainedBy;
+        } else if self.overlaps(other) {
+            return OverlapKind::Overlapping;
+        }
 
-        for rect in &obstructions {
-            // gaps might close on the left of each obstruction
-            lines.push(Line {
-                x: rect.left(),
-                opens: false,
-            });
+        let one = Self::Unit::one();
+        let ranges_overlap = |a_min, a_max, b_min, b_max| a_min <= b_max && b_min <= a_max;
+        let ranges_adjacent = |a_min: Self::Unit, a_max: Self::Unit, b_min, b_max| {
+            a_max + one == b_min || b_max + one == a_min
+        };
+        let (left, right, top, bottom) = (other.left(), other.right(), other.top(), other.bottom());
 
-            // gaps might open just after the right of each obstruction
-            lines.push(Line {
-                x: rect.right() + Self::Unit::one(),
-                opens: true,
-            });
+        let side_by_side = ranges_adjacent(self.left(), self.right(), left, right)
+            && ranges_overlap(self.bottom(), self.top(), bottom, top);
+        let stacked = ranges_adjacent(self.bottom(), self.top(), bottom, top)
+            && ranges_overlap(self.left(), self.right(), left, right);
+        if side_by_side || stacked {
+            OverlapKind::Touching
+        } else {
+            OverlapKind::Disjoint
         }
+    }
 
-        // order from left to right
-        lines.sort_unstable_by_key(|line| line.x);
-        lines.dedup_by_key(|line| line.x);
+    /// Checks if every side is within `epsilon` of the other rectangle's, for comparing computed float rectangles.
+    ///
+    /// # Example
+    /// ```
+    /// use rect_lib::{BasicRectangle, Rectangle, RectangleRead};
+    ///
+    /// let rect = BasicRectangle::new_from_sides(0, 10, 10, 0);
+    /// assert!(rect.approx_eq(&BasicRectangle::new_from_sides(1, 9, 10, -1), 1));
+    /// assert!(!rect.approx_eq(&BasicRectangle::new_from_sides(2, 10, 10, 0), 1));
+    /// ```
+    fn approx_eq(
+        &self,
+        other: &impl RectangleRead<Unit = Self::Unit>,
+        epsilon: Self::Unit,
+    ) -> bool {
+        // subtracting the smaller from the larger keeps unsigned units from underflowing
+        let close = |a: Self::Unit, b: Self::Unit| {
+            let difference = if a > b { a - b } else { b - a };
+            difference <= epsilon
+        };
+        close(self.left(), other.left())
+            && close(self.right(), other.right())
+            && close(self.top(), other.top())
+            && close(self.bottom(), other.bottom())
+    }
 
-        // filter out lines that are outside the rectangle
-        let lines = lines
-            .into_iter()
-            .filter(|line| self.left() <= line.x && line.x <= self.right());
+    /// The fraction of `cell` covered by the rectangle, from `0.0` to `1.0`, for coverage based anti-aliasing.
+    ///
+    /// This is the area of the overlap over the area of the cell, measured as `width * height` like [`RectangleRead::area`],
+    /// so float rectangles partly over a cell give a partial fraction. A cell with no area is never covered.
+    ///
+    /// # Example
+    /// ```
+    /// use rect_lib::{BasicRectangle, Rectangle, RectangleRead};
+    ///
+    /// let rect = BasicRectangle::new_from_sides(0, 10, 10, 0);
+    /// assert_eq!(rect.cell_coverage_f64(&BasicRectangle::new_from_sides(8, 12, 4, 0)), 0.5);
+    /// ```
+    fn cell_coverage_f64(&self, cell: &impl RectangleRead<Unit = Self::Unit>) -> f64
+    where
+        Self::Unit: ToPrimitive,
+    {
+        let area = |width: Self::Unit, height: Self::Unit| {
+            width.to_f64().unwrap_or(0.0) * height.to_f64().unwrap_or(0.0)
+        };
+        let cell_area = area(cell.width(), cell.height());
+        if cell.left() > cell.right() || cell.bottom() > cell.top() || cell_area <= 0.0 {
+            return 0.0;
+        }
 
-        // this is the list we will return
-        let mut unique_rectangles: Vec<Self> = Vec::new();
+        let left = partial_max(self.left(), cell.left());
+        let right = partial_min(self.right(), cell.right());
+        let top = partial_min(self.top(), cell.top());
+        let bottom = partial_max(self.bottom(), cell.bottom());
+        if left >= right || bottom >= top {
+            return 0.0;
+        }
 
-        // this will store active rectangles as we sweep from line to line
-        let mut active_rectangles: Vec<UnfinishedRect<Self>> = Vec::new();
+        (area(right - left, top - bottom) / cell_area).clamp(0.0, 1.0)
+    }
 
-        for line in lines {
-            // Section 2: collect all gaps between obstructions
-            let mut gaps: Vec<Gap<Self>> = Vec::new();
+    /// The side of the rectangle a point moving in a straight line from `from` to `to` leaves through,
+    /// or `None` if it starts outside or never leaves.
+    ///
+    /// The math is exact, there is no division so integer units don't round,
+    /// & integer units are widened before they are multiplied so they can't overflow.
+    /// A path leaving exactly through a corner goes out the side whose axis it overshoots more,
+    /// & the left or right side if it overshoots both the same.
+    ///
+    /// # Example
+    /// ```
+    /// use rect_lib::{BasicRectangle, Rectangle, RectangleRead, Side};
+    ///
+    /// let rect = BasicRectangle::new_from_sides(0, 10, 10, 0);
+    /// assert_eq!(rect.exit_side((5, 5), (15, 7)), Some(Side::Right));
+    /// assert_eq!(rect.exit_side((5, 5), (6, 30)), Some(Side::Top));
+    /// assert_eq!(rect.exit_side((5, 5), (6, 6)), None);
+    /// ```
+    fn exit_side(
+        &self,
+        from: (Self::Unit, Self::Unit),
+        to: (Self::Unit, Self::Unit),
+    ) -> Option<Side>
+    where
+        Self::Unit: ToPrimitive,
+    {
+        side::exit_side(
+            (self.left(), self.right(), self.top(), self.bottom()),
+            from,
+            to,
+        )
+    }
 
-            // think of each obstruction as a shingle on a roof
-            // if the bottom of one shingle is above the top of the next there is a gap between them
-            let mut last_rectange_bottom: Self::Unit = self.top();
+    /// The side of the rectangle a dragged rectangle first sticks out of, when moved in a straight line from `from` to `to`,
+    /// or `None` if it doesn't start inside or never sticks out.
+    ///
+    /// `to` is `from` moved, only its position is used. Corners are broken the same way as [`RectangleRead::exit_side`].
+    ///
+    /// # Example
+    /// ```
+    /// use rect_lib::{BasicRectangle, Rectangle, RectangleRead, Side};
+    ///
+    /// let rect = BasicRectangle::new_from_sides(0, 10, 10, 0);
+    /// let dragged = BasicRectangle::new_from_sides(2, 4, 4, 2);
+    /// assert_eq!(rect.exit_side_rect(&dragged, &dragged.translate(0, -3)), Some(Side::Bottom));
+    /// assert_eq!(rect.exit_side_rect(&dragged, &dragged.translate(6, 6)), None);
+    /// ```
+    fn exit_side_rect(
+        &self,
+        from: &impl RectangleRead<Unit = Self::Unit>,
+        to: &impl RectangleRead<Unit = Self::Unit>,
+    ) -> Option<Side>
+    where
+        Self::Unit: ToPrimitive,
+    {
+        if from.width() > self.width() || from.height() > self.height() {
+            return None;
+        }
+
+        // the bottom left corner of the dragged rectangle can go anywhere in here without it sticking out
+        side::exit_side(
+            (
+                self.left(),
+                self.right() - from.width(),
+                self.top() - from.height(),
+                self.bottom(),
+            ),
+            (from.left(), from.bottom()),
+            (to.left(), to.bottom()),
+        )
+    }
+
+    /// A hash of the sides that is the same on every platform, run, & version of the crate, for cache keys.
+    ///
+    /// Each side in the order left, right, top, bottom is fed as its 16 [`HashBytes`] into 64 bit FNV-1a,
+    /// an integer widened to an `i128` & a float by its exact bit pattern.
+    ///
+    /// # Example
+    /// ```
+    /// use rect_lib::{BasicRectangle, Rectangle, RectangleRead};
+    ///
+    /// let rect = BasicRectangle::new_from_sides(0, 1, 1, 0);
+    /// assert_eq!(rect.content_hash(), BasicRectangle::new_from_sides(0, 1, 1, 0).content_hash());
+    /// assert_ne!(rect.content_hash(), BasicRectangle::new_from_sides(0, 1, 2, 0).content_hash());
+    /// ```
+    fn content_hash(&self) -> u64
+    where
+        Self::Unit: HashBytes,
+    {
+        let mut hasher = Fnv(Fnv::OFFSET_BASIS);
+        for side in [self.left(), self.right(), self.top(), self.bottom()] {
+            hasher.write(&side.hash_bytes());
+        }
+        hasher.finish()
+    }
+
+    /// Checks if one rectangle overlaps with another in a toroidal world.
+    /// The `world` wraps around, so a rectangle spilling off the right edge can overlap one near the left edge.
+    ///
+    /// Both rectangles should be within one world width & height of the world.
+    ///
+    /// # Example
+    /// ```
+    /// use rect_lib::{BasicRectangle, Rectangle, RectangleRead};
+    ///
+    /// let world = BasicRectangle::new_from_sides(0, 9, 9, 0);
+    /// let rect = BasicRectangle::new_from_sides(8, 11, 5, 4);
+    /// assert!(rect.overlaps_wrapped(&BasicRectangle::new_from_sides(0, 1, 5, 4), &world));
+    /// assert!(!rect.overlaps_wrapped(&BasicRectangle::new_from_sides(2, 3, 5, 4), &world));
+    /// ```
+    fn overlaps_wrapped(
+        &self,
+        other: &impl RectangleRead<Unit = Self::Unit>,
+        world: &impl RectangleRead<Unit = Self::Unit>,
+    ) -> bool {
+        let zero = Self::Unit::zero();
+        // the sides are inclusive so +1
+        let world_width = world.width() + Self::Unit::one();
+        let world_height = world.height() + Self::Unit::one();
 
-            // filter out obstructions that don't intersect the current line
-            for obstruction in obstructions
+        // wrapping one rectangle backwards is the same as wrapping the other forwards
+        let ranges_overlap = |(a_min, a_max): (Self::Unit, Self::Unit),
+                              (b_min, b_max): (Self::Unit, Self::Unit),
+                              size: Self::Unit| {
+            [(zero, zero), (size, zero), (zero, size)]
                 .iter()
-                .filter(|rect| rect.left() <= line.x && line.x <= rect.right())
-            {
-                if last_rectange_bottom > obstruction.top() {
-                    gaps.push(Gap {
-                        top: last_rectange_bottom,
-                        bottom: obstruction.top() + Self::Unit::one(), // the top is inclusive so +1
-                    });
-                }
+                .any(|&(a_shift, b_shift)| {
+                    a_min + a_shift <= b_max + b_shift && b_min + b_shift <= a_max + a_shift
+                })
+        };
+
+        ranges_overlap(
+            (self.left(), self.right()),
+            (other.left(), other.right()),
+            world_width,
+        ) && ranges_overlap(
+            (self.bottom(), self.top()),
+            (other.bottom(), other.top()),
+            world_height,
+        )
+    }
+
+    /// Wraps a point into the rectangle as a toroidal world, however far outside of it the point is.
+    /// If the rectangle is inverted along an axis there is nothing to wrap into, & the point is left as it is along that axis.
+    ///
+    /// # Example
+    /// ```
+    /// use rect_lib::{BasicRectangle, Rectangle, RectangleRead};
+    ///
+    /// let world = BasicRectangle::new_from_sides(0, 9, 9, 0);
+    /// assert_eq!(world.wrap_point(12, -1), (2, 9));
+    /// assert_eq!(world.wrap_point(-25, 35), (5, 5));
+    /// ```
+    fn wrap_point(&self, x: Self::Unit, y: Self::Unit) -> (Self::Unit, Self::Unit) {
+        // `%` keeps the sign of the left hand side, so adding the size once more makes it positive
+        let wrap = |value: Self::Unit, min: Self::Unit, max: Self::Unit| {
+            let size = max - min + Self::Unit::one();
+            if size <= Self::Unit::zero() {
+                return value;
+            }
+            ((value - min) % size + size) % size + min
+        };
+        (
+            wrap(x, self.left(), self.right()),
+            wrap(y, self.bottom(), self.top()),
+        )
+    }
+
+    /// Returns the closest pair of points, one on the boundary of each rectangle, as `(on_self, on_other)`.
+    /// Where the rectangles line up on an axis, the points sit in the middle of the shared range,
+    /// which gives natural endpoints for connector lines.
+    ///
+    /// If the rectangles overlap, the points are on the closest pair of parallel sides, one side from each,
+    /// in the middle of the range the rectangles share along those sides.
+    /// Sides the rectangles have in common are zero apart, so then both points are the same point on both boundaries.
+    ///
+    /// # Example
+    /// ```
+    /// use rect_lib::{BasicRectangle, Rectangle, RectangleRead};
+    ///
+    /// let rect = BasicRectangle::new_from_sides(0, 2, 4, 0);
+    /// let other = BasicRectangle::new_from_sides(6, 8, 6, 2);
+    /// assert_eq!(rect.connection_points(&other), ((2, 3), (6, 3)));
+    ///
+    /// // overlapping, the bottom sides are the closest
+    /// let inner = BasicRectangle::new_from_sides(3, 5, 6, 1);
+    /// assert_eq!(BasicRectangle::new_from_sides(0, 9, 9, 0).connection_points(&inner), ((4, 0), (4, 1)));
+    /// ```
+    #[allow(clippy::type_complexity)]
+    fn connection_points(
+        &self,
+        other: &impl RectangleRead<Unit = Self::Unit>,
+    ) -> ((Self::Unit, Self::Unit), (Self::Unit, Self::Unit)) {
+        let two = Self::Unit::one() + Self::Unit::one();
+        let middle = |low: Self::Unit, high: Self::Unit| low + (high - low) / two;
 
-                // if a later shingle starts in the same place we could get a fake gap
-                // so we avoid that by getting the lowest point
-                last_rectange_bottom =
-                    last_rectange_bottom.min(obstruction.bottom() - Self::Unit::one());
+        let (x_range, other_x_range) = ((self.left(), self.right()), (other.left(), other.right()));
+        let (y_range, other_y_range) = ((self.bottom(), self.top()), (other.bottom(), other.top()));
+
+        // the closest coordinates on each rectangle along one axis, `None` if they overlap along it
+        let closest = |(min, max): (Self::Unit, Self::Unit),
+                       (other_min, other_max): (Self::Unit, Self::Unit)| {
+            if max < other_min {
+                Some((max, other_min))
+            } else if other_max < min {
+                Some((min, other_max))
+            } else {
+                None
             }
+        };
+        // the middle of the range shared along one axis
+        let shared = |(min, max): (Self::Unit, Self::Unit),
+                      (other_min, other_max): (Self::Unit, Self::Unit)| {
+            middle(partial_max(min, other_min), partial_min(max, other_max))
+        };
 
-            // check if there is a gap between the bottom of the last shingle and the end of the roof
-            // the bottom is inclusive so >=
-            if last_rectange_bottom >= self.bottom() {
-                gaps.push(Gap {
-                    top: last_rectange_bottom,
-                    bottom: self.bottom(),
+        match (
+            closest(x_range, other_x_range),
+            closest(y_range, other_y_range),
+        ) {
+            (None, None) => {}
+            (x, y) => {
+                let (x, other_x) = x.unwrap_or_else(|| {
+                    let middle = shared(x_range, other_x_range);
+                    (middle, middle)
+                });
+                let (y, other_y) = y.unwrap_or_else(|| {
+                    let middle = shared(y_range, other_y_range);
+                    (middle, middle)
                 });
+                return ((x, y), (other_x, other_y));
             }
-            // alright, we have all the gaps
-
-            active_rectangles.sort_unstable_by_key(|rect| Reverse(rect.left));
-
-            // Section 3: if the current line opens we create new rectangles
-            if line.opens {
-                // try to create a new rect for each gap
-                for gap in gaps {
-                    // make sure its unique
-                    if !active_rectangles
-                        .iter()
-                        .any(|rect| gap.top == rect.top && gap.bottom == rect.bottom)
-                    {
-                        active_rectangles.push(UnfinishedRect {
-                            left: line.x,
-                            top: gap.top,
-                            bottom: gap.bottom,
-                        });
-                    }
-                }
+        }
+
+        // overlapping, so find the closest pair of parallel sides
+        // subtracting the smaller from the larger so unsigned units can't underflow
+        let distance = |a: Self::Unit, b: Self::Unit| if a < b { b - a } else { a - b };
+        let (middle_x, middle_y) = (
+            shared(x_range, other_x_range),
+            shared(y_range, other_y_range),
+        );
+        let vertical = [self.left(), self.right()]
+            .into_iter()
+            .flat_map(|x| [other.left(), other.right()].map(|other_x| (x, other_x)))
+            .map(|(x, other_x)| (distance(x, other_x), ((x, middle_y), (other_x, middle_y))));
+        let horizontal = [self.bottom(), self.top()]
+            .into_iter()
+            .flat_map(|y| [other.bottom(), other.top()].map(|other_y| (y, other_y)))
+            .map(|(y, other_y)| (distance(y, other_y), ((middle_x, y), (middle_x, other_y))));
+
+        vertical
+            .chain(horizontal)
+            .reduce(|closest, pair| if pair.0 < closest.0 { pair } else { closest })
+            .map(|(_, points)| points)
+            .expect("there are always sides to pick from")
+    }
+
+    /// Projects the four corners of the rectangle onto the given axis.
+    /// Returns the `(min, max)` of the projection, for use in separating axis collision tests.
+    ///
+    /// The axis should be normalized.
+    ///
+    /// # Example
+    /// ```
+    /// use rect_lib::{BasicRectangle, Rectangle, RectangleRead};
+    ///
+    /// let rect = BasicRectangle::new_from_sides(1, 3, 2, 0);
+    /// assert_eq!(rect.project_onto_axis_f64((1.0, 0.0)), (1.0, 3.0));
+    /// ```
+    fn project_onto_axis_f64(&self, axis: (f64, f64)) -> (f64, f64)
+    where
+        Self::Unit: ToPrimitive,
+    {
+        let to_f64 = |unit: Self::Unit| unit.to_f64().unwrap_or(f64::NAN);
+        let (left, right) = (to_f64(self.left()), to_f64(self.right()));
+        let (top, bottom) = (to_f64(self.top()), to_f64(self.bottom()));
+
+        [(left, top), (right, top), (left, bottom), (right, bottom)]
+            .iter()
+            .map(|(x, y)| x * axis.0 + y * axis.1)
+            .fold(
+                (f64::INFINITY, f64::NEG_INFINITY),
+                |(min, max), projection| (min.min(projection), max.max(projection)),
+            )
+    }
+
+    /// Checks if a circle overlaps the rectangle, touching counts as overlapping.
+    /// The nearest point of the rectangle to the center is found by clamping, then compared against the radius.
+    ///
+    /// An inverted rectangle, or one with a side `f64` can't hold, covers nothing & never intersects.
+    ///
+    /// # Example
+    /// ```
+    /// use rect_lib::{BasicRectangle, Rectangle, RectangleRead};
+    ///
+    /// let rect = BasicRectangle::new_from_sides(0, 4, 4, 0);
+    /// assert!(rect.intersects_circle_f64((6.0, 2.0), 2.0));
+    /// assert!(!rect.intersects_circle_f64((6.0, 6.0), 2.0));
+    /// ```
+    fn intersects_circle_f64(&self, center: (f64, f64), radius: f64) -> bool
+    where
+        Self::Unit: ToPrimitive,
+    {
+        let to_f64 = |unit: Self::Unit| unit.to_f64().unwrap_or(f64::NAN);
+        let (left, right) = (to_f64(self.left()), to_f64(self.right()));
+        let (top, bottom) = (to_f64(self.top()), to_f64(self.bottom()));
+        // `clamp` panics on these, & NaN sides fail both comparisons
+        if !(left <= right && bottom <= top) {
+            return false;
+        }
+        let nearest_x = center.0.clamp(left, right);
+        let nearest_y = center.1.clamp(bottom, top);
+
+        let (dx, dy) = (center.0 - nearest_x, center.1 - nearest_y);
+        dx * dx + dy * dy <= radius * radius
+    }
+
+    /// Checks if the longer side is more than `max_aspect` times the shorter one, for filtering out unusably thin rectangles.
+    /// A rectangle with no width or height is a line & always counts as a sliver.
+    ///
+    /// # Example
+    /// ```
+    /// use rect_lib::{BasicRectangle, Rectangle, RectangleRead};
+    ///
+    /// assert!(BasicRectangle::new_from_sides(0, 20, 2, 0).is_sliver(4.0));
+    /// assert!(!BasicRectangle::new_from_sides(0, 6, 2, 0).is_sliver(4.0));
+    /// ```
+    fn is_sliver(&self, max_aspect: f64) -> bool
+    where
+        Self::Unit: ToPrimitive,
+    {
+        let to_f64 = |unit: Self::Unit| unit.to_f64().unwrap_or(f64::NAN);
+        let (width, height) = (to_f64(self.width()), to_f64(self.height()));
+        let (short, long) = (width.min(height), width.max(height));
+        short <= 0.0 || long > short * max_aspect
+    }
+
+    /// Clips a segment to the part inside the rectangle, or `None` if it misses the rectangle.
+    /// Ends moved onto the edges are rounded to the nearest unit.
+    ///
+    /// # Example
+    /// ```
+    /// use rect_lib::{BasicRectangle, Rectangle, RectangleRead, Segment};
+    ///
+    /// let rect = BasicRectangle::new_from_sides(0, 9, 9, 0);
+    /// let segment = Segment::new((-5, 5), (5, 5));
+    /// assert_eq!(rect.clip_segment(&segment), Some(Segment::new((0, 5), (5, 5))));
+    /// ```
+    fn clip_segment(&self, segment: &Segment<Self::Unit>) -> Option<Segment<Self::Unit>>
+    where
+        Self: Sized,
+        Self::Unit: NumCast,
+    {
+        segment::clip(self, segment).map(|clipped| clipped.segment)
+    }
 
-                // on to the next line
+    /// Clips a polyline to the rectangle, returning the runs of points inside it.
+    /// Each time the line leaves & comes back a new run starts, so routes leaving the viewport are split up.
+    ///
+    /// Points where the line crosses the edges are rounded to the nearest unit.
+    ///
+    /// # Example
+    /// ```
+    /// use rect_lib::{BasicRectangle, Rectangle, RectangleRead};
+    ///
+    /// let rect = BasicRectangle::new_from_sides(0, 9, 9, 0);
+    /// let runs = rect.clip_polyline(&[(2, 2), (2, 20), (6, 20), (6, 2)]);
+    /// assert_eq!(runs, vec![vec![(2, 2), (2, 9)], vec![(6, 9), (6, 2)]]);
+    /// ```
+    fn clip_polyline(
+        &self,
+        points: &[(Self::Unit, Self::Unit)],
+    ) -> Vec<Vec<(Self::Unit, Self::Unit)>>
+    where
+        Self: Sized,
+        Self::Unit: NumCast,
+    {
+        let mut runs: Vec<Vec<(Self::Unit, Self::Unit)>> = Vec::new();
+        if let [point] = points {
+            if self.contains_point(point.0, point.1) {
+                runs.push(vec![*point]);
+            }
+            return runs;
+        }
+
+        // whether the last run ended inside, so the next segment carries it on
+        let mut continuing = false;
+        for pair in points.windows(2) {
+            let Some(clipped) = segment::clip(self, &Segment::new(pair[0], pair[1])) else {
+                continuing = false;
                 continue;
+            };
+
+            match runs.last_mut() {
+                Some(run) if continuing && !clipped.start_moved => run.push(clipped.segment.end),
+                _ => runs.push(vec![clipped.segment.start, clipped.segment.end]),
             }
+            continuing = !clipped.end_moved;
+        }
 
-            // Section 3 & 1/2: if the current line closes we finish rectangles
-            let mut new_active_rectangles: Vec<UnfinishedRect<Self>> = Vec::new();
+        runs
+    }
 
-            active_rectangles = active_rectangles
-                .iter()
-                .filter(|rect| {
-                    // if the current rect fits within a gap we can keep it
-                    if gaps
-                        .iter()
-                        .any(|gap| gap.top >= rect.top && rect.bottom >= gap.bottom)
-                    {
-                        // on to the next active rect
-                        return true;
-                    }
+    /// Returns every grid cell a line from `p0` to `p1` passes through, clipped to the rectangle.
+    ///
+    /// This is a supercover walk, each point is treated as a unit cell centered on it.
+    /// Where the line passes exactly through a corner both cells beside it are included, so nothing can be seen through a diagonal gap.
+    /// Cells are ordered from `p0` to `p1`.
+    ///
+    /// The walk skips straight to where the line reaches the rectangle & stops once it leaves, so it only costs the cells inside.
+    /// The decisions are made in `i128`, exact for any line whose ends are within `2^62` of each other.
+    ///
+    /// # Example
+    /// ```
+    /// use rect_lib::{BasicRectangle, Rectangle, RectangleRead};
+    ///
+    /// let rect = BasicRectangle::new_from_sides(0, 9, 9, 0);
+    /// assert_eq!(rect.cells_along_line((0, 0), (2, 1)), vec![(0, 0), (1, 0), (1, 1), (2, 1)]);
+    /// ```
+    fn cells_along_line(
+        &self,
+        p0: (Self::Unit, Self::Unit),
+        p1: (Self::Unit, Self::Unit),
+    ) -> Vec<(Self::Unit, Self::Unit)>
+    where
+        Self::Unit: NumCast,
+    {
+        let wide = |unit: Self::Unit| unit.to_i128();
+        let (Some(x0), Some(y0), Some(x1), Some(y1)) =
+            (wide(p0.0), wide(p0.1), wide(p1.0), wide(p1.1))
+        else {
+            return Vec::new();
+        };
+        let (Some(left), Some(right), Some(top), Some(bottom)) = (
+            wide(self.left()),
+            wide(self.right()),
+            wide(self.top()),
+            wide(self.bottom()),
+        ) else {
+            return Vec::new();
+        };
+
+        // the walk counts steps along each axis, so directions never need a negative unit
+        let (nx, ny) = ((x1 - x0).abs(), (y1 - y0).abs());
+        let (step_x, step_y) = ((x1 - x0).signum(), (y1 - y0).signum());
 
-                    // if it is obstructed we can close it
-                    unique_rectangles.push(Self::new_from_sides(
-                        rect.left,                  // left
-                        line.x - Self::Unit::one(), // right
-                        rect.top,                   // top
-                        rect.bottom,                // bottom
-                    ));
-
-                    // check if there are any gaps within the current rect
-                    for gap in gaps
-                        .iter()
-                        .filter(|gap| gap.top <= rect.top || rect.bottom <= gap.bottom)
-                    {
-                        let top_limit = rect.top.min(gap.top);
-                        let bottom_limit = rect.bottom.max(gap.bottom);
-
-                        // make sure its unique
-                        if !active_rectangles
-                            .iter()
-                            .chain(new_active_rectangles.iter())
-                            .any(|rect| top_limit == rect.top && bottom_limit == rect.bottom)
-                        {
-                            new_active_rectangles.push(UnfinishedRect {
-                                left: rect.left,
-                                top: top_limit,
-                                bottom: bottom_limit,
-                            });
-                        }
+        // the steps along an axis that land inside the rectangle
+        let steps_inside = |from: i128, step: i128, steps: i128, low: i128, high: i128| {
+            let (first, last) = if step < 0 {
+                (from - high, from - low)
+            } else {
+                (low - from, high - from)
+            };
+            (first.max(0), last.min(steps))
+        };
+        let (first_x, last_x) = steps_inside(x0, step_x, nx, left, right);
+        let (first_y, last_y) = steps_inside(y0, step_y, ny, bottom, top);
+        if first_x > last_x || first_y > last_y {
+            return Vec::new();
+        }
+
+        // the line crosses into column `ix + 1` at `(1 + 2 * ix) * ny` & into row `iy + 1` at `(1 + 2 * iy) * nx`,
+        // so skip every crossing before it has reached both ranges, just like the walk below would
+        let (mut ix, mut iy) = if nx == 0 || ny == 0 {
+            (first_x, first_y)
+        } else {
+            let reached = |first: i128, other: i128| {
+                if first == 0 {
+                    0
+                } else {
+                    (2 * first - 1) * other
+                }
+            };
+            let start = reached(first_x, ny).max(reached(first_y, nx));
+            let crossed = |steps: i128, other: i128| {
+                if start <= other {
+                    0
+                } else {
+                    ((start - other + 2 * other - 1) / (2 * other)).min(steps)
+                }
+            };
+            (crossed(nx, ny), crossed(ny, nx))
+        };
+
+        let inside =
+            |ix: i128, iy: i128| first_x <= ix && ix <= last_x && first_y <= iy && iy <= last_y;
+        // cells inside the rectangle lie between its sides, so they fit in the unit
+        let cell = |ix: i128, iy: i128| -> (Self::Unit, Self::Unit) {
+            (
+                NumCast::from(x0 + step_x * ix).expect("the cell is inside the rectangle"),
+                NumCast::from(y0 + step_y * iy).expect("the cell is inside the rectangle"),
+            )
+        };
+
+        let mut cells = Vec::new();
+        if inside(ix, iy) {
+            cells.push(cell(ix, iy));
+        }
+        while (ix < nx || iy < ny) && ix <= last_x && iy <= last_y {
+            // compare where the line crosses the next column & the next row
+            let ahead = (1 + 2 * ix) * ny;
+            let above = (1 + 2 * iy) * nx;
+            if ahead == above {
+                // straight through a corner, both sides are touched
+                for (side_x, side_y) in [(ix + 1, iy), (ix, iy + 1)] {
+                    if inside(side_x, side_y) {
+                        cells.push(cell(side_x, side_y));
                     }
+                }
+                ix += 1;
+                iy += 1;
+            } else if ahead < above {
+                ix += 1;
+            } else {
+                iy += 1;
+            }
+            if inside(ix, iy) {
+                cells.push(cell(ix, iy));
+            }
+        }
 
-                    // make sure to remove it from active
-                    false
-                })
-                .cloned()
-                .collect();
+        cells
+    }
+}
+
+/// A trait containing methods for rectangle like data structures which implement `Sized` & `Copy`.
+/// The accessors & read-only methods live in the [`RectangleRead`] supertrait, this adds the constructing ones.
+///
+/// This trait treats all edges (left, right, top, & bottom) as inclusive.
+///
+/// # Example
+/// ```
+/// use rect_lib::{Rectangle, RectangleRead};
+///
+/// #[derive(Clone, Copy)]
+/// pub struct BasicRectangle {
+///     x: i32,
+///     y: i32,
+///     width: i32,
+///     height: i32,
+/// }
+///
+/// impl RectangleRead for BasicRectangle {
+///     type Unit = i32;
+///
+///     fn left(&self) -> i32 {
+///         self.x
+///     }
+///
+///     fn right(&self) -> i32 {
+///         self.x + self.width - 1
+///     }
+///
+///     fn top(&self) -> i32 {
+///         self.y
+///     }
+///
+///     fn bottom(&self) -> i32 {
+///         self.y - self.height + 1
+///     }
+/// }
+///
+/// impl Rectangle for BasicRectangle {
+///     fn new_from_sides(left: i32, right: i32, top: i32, bottom: i32) -> Self {
+///         Self {
+///             x: left,
+///             y: top,
+///             width: right - left + 1,
+///             height: top - bottom + 1,
+///         }
+///     }
+/// }
+/// ```
+pub trait Rectangle: RectangleRead
+where
+    Self: Sized + Copy,
+{
+    // - Required implementations.
+
+    /// Creates a new rectangle from the given sides.
+    /// The sides are inclusive.
+    ///
+    /// # Example
+    /// ```
+    /// use rect_lib::{BasicRectangle, Rectangle, RectangleRead};
+    ///
+    /// let rect = BasicRectangle::new_from_sides(0, 1, 2, 3);
+    /// ```
+    fn new_from_sides(
+        left: Self::Unit,
+        right: Self::Unit,
+        top: Self::Unit,
+        bottom: Self::Unit,
+    ) -> Self;
 
-            // add any new sub rectangles
-            active_rectangles.append(&mut new_active_rectangles);
+    // - Default implementations.
+
+    /// Creates a new rectangle from computed `f64` sides, rejecting anything that isn't a real rectangle.
+    ///
+    /// - `Err(RectError::NonFinite)` means a side is NaN or infinite.
+    /// - `Err(RectError::NegativeSize)` means the right is left of the left or the top is below the bottom.
+    /// - `Err(RectError::Overflow)` means a side can't be represented by the unit type.
+    ///
+    /// Sides are converted with [`NumCast`], so fractions are truncated for integer units.
+    ///
+    /// # Example
+    /// ```
+    /// use rect_lib::{BasicRectangle, RectError, Rectangle, RectangleRead};
+    ///
+    /// let rect = BasicRectangle::try_from_f64_sides(0.0, 3.0, 2.0, 0.0);
+    /// assert_eq!(rect, Ok(BasicRectangle::new_from_sides(0, 3, 2, 0)));
+    ///
+    /// let rect = BasicRectangle::try_from_f64_sides(f64::NAN, 3.0, 2.0, 0.0);
+    /// assert_eq!(rect, Err(RectError::NonFinite));
+    /// ```
+    fn try_from_f64_sides(left: f64, right: f64, top: f64, bottom: f64) -> Result<Self, RectError>
+    where
+        Self::Unit: NumCast,
+    {
+        if [left, right, top, bottom]
+            .iter()
+            .any(|side| !side.is_finite())
+        {
+            return Err(RectError::NonFinite);
+        }
+        if right < left || top < bottom {
+            return Err(RectError::NegativeSize);
         }
 
-        // Section 4: now that we have checked all lines we can close any remaining rectangles
-        for rect in active_rectangles {
-            unique_rectangles.push(Self::new_from_sides(
-                rect.left,
-                self.right(),
-                rect.top,
-                rect.bottom,
-            ));
+        let unit = |side: f64| -> Result<Self::Unit, RectError> {
+            <Self::Unit as NumCast>::from(side).ok_or(RectError::Overflow)
+        };
+        Ok(Self::new_from_sides(
+            unit(left)?,
+            unit(right)?,
+            unit(top)?,
+            unit(bottom)?,
+        ))
+    }
+
+    /// Creates a new rectangle from any two opposite corners.
+    /// The coordinates are sorted, so the corners may be given in any order.
+    ///
+    /// # Example
+    /// ```
+    /// use rect_lib::{BasicRectangle, Rectangle, RectangleRead};
+    ///
+    /// let rect = BasicRectangle::from_corners((2, 0), (0, 3));
+    /// assert_eq!(rect, BasicRectangle::new_from_sides(0, 2, 3, 0));
+    /// ```
+    fn from_corners(p0: (Self::Unit, Self::Unit), p1: (Self::Unit, Self::Unit)) -> Self {
+        Self::new_from_sides(
+            partial_min(p0.0, p1.0), // left
+            partial_max(p0.0, p1.0), // right
+            partial_max(p0.1, p1.1), // top
+            partial_min(p0.1, p1.1), // bottom
+        )
+    }
+
+    /// Creates a 1x1 rectangle covering the given point.
+    /// Because the sides are inclusive its `width` & `height` are zero, and so is its `area`.
+    ///
+    /// # Example
+    /// ```
+    /// use rect_lib::{BasicRectangle, Rectangle, RectangleRead};
+    ///
+    /// let rect = BasicRectangle::unit_at(2, 3);
+    /// assert_eq!(rect, BasicRectangle::new_from_sides(2, 2, 3, 3));
+    /// assert_eq!(rect.area(), 0);
+    /// ```
+    fn unit_at(x: Self::Unit, y: Self::Unit) -> Self {
+        Self::new_from_sides(x, x, y, y)
+    }
+
+    /// Creates the smallest rectangle containing all the given points.
+    /// If there are no points, `None` is returned.
+    ///
+    /// # Example
+    /// ```
+    /// use rect_lib::{BasicRectangle, Rectangle, RectangleRead};
+    ///
+    /// let rect = BasicRectangle::spanning_points([(1, 4), (3, 0), (0, 2)]);
+    /// assert_eq!(rect, Some(BasicRectangle::new_from_sides(0, 3, 4, 0)));
+    ///
+    /// let empty = BasicRectangle::spanning_points([]);
+    /// assert_eq!(empty, None);
+    /// ```
+    fn spanning_points(points: impl IntoIterator<Item = (Self::Unit, Self::Unit)>) -> Option<Self> {
+        let mut points = points.into_iter();
+        let (x, y) = points.next()?;
+
+        let (mut left, mut right, mut top, mut bottom) = (x, x, y, y);
+        for (x, y) in points {
+            left = partial_min(left, x);
+            right = partial_max(right, x);
+            top = partial_max(top, y);
+            bottom = partial_min(bottom, y);
         }
 
-        // Quod Erat Demonstrandum
-        unique_rectangles
+        Some(Self::new_from_sides(left, right, top, bottom))
+    }
+
+    /// Frames every target with `padding` on each side, then widens or heightens the frame to the `aspect_w:aspect_h` ratio.
+    /// Returns `None` if there are no targets, or if either side of the aspect isn't positive.
+    ///
+    /// Like [`RectangleRead::width`] the ratio is between the distances across the frame, which is only grown,
+    /// so for integer units it is rounded up & can be slightly off. Float units fit the ratio exactly.
+    /// The growth is split evenly with any odd unit going to the right or bottom.
+    ///
+    /// # Example
+    /// ```
+    /// use rect_lib::{BasicRectangle, Rectangle, RectangleRead};
+    ///
+    /// let targets = [BasicRectangle::new_from_sides(0, 4, 4, 0)];
+    /// let frame = BasicRectangle::camera_fit(&targets, 2, 1, 1).unwrap();
+    /// assert_eq!(frame, BasicRectangle::new_from_sides(-4, 8, 5, -1));
+    /// ```
+    fn camera_fit(
+        targets: &[impl RectangleRead<Unit = Self::Unit>],
+        aspect_w: Self::Unit,
+        aspect_h: Self::Unit,
+        padding: Self::Unit,
+    ) -> Option<Self> {
+        let (zero, one) = (Self::Unit::zero(), Self::Unit::one());
+        let two = one + one;
+        if !(aspect_w > zero && aspect_h > zero) {
+            return None;
+        }
+        let hull = Self::spanning_points(targets.iter().flat_map(|target| {
+            [
+                (target.left(), target.top()),
+                (target.right(), target.bottom()),
+            ]
+        }))?;
+        let (left, right) = (hull.left() - padding, hull.right() + padding);
+        let (top, bottom) = (hull.top() + padding, hull.bottom() - padding);
+        let (width, height) = (right - left, top - bottom);
+
+        // the division rounding up, so the frame never ends up smaller than its contents
+        let integer = integer_unit::<Self::Unit>();
+        let div_ceil = |a: Self::Unit, b: Self::Unit| {
+            if integer {
+                (a + b - one) / b
+            } else {
+                a / b
+            }
+        };
+        let (grow_x, grow_y) = if width * aspect_h < height * aspect_w {
+            (div_ceil(height * aspect_w, aspect_h) - width, zero)
+        } else {
+            (zero, div_ceil(width * aspect_h, aspect_w) - height)
+        };
+
+        Some(Self::new_from_sides(
+            left - grow_x / two,
+            right + (grow_x - grow_x / two),
+            top + grow_y / two,
+            bottom - (grow_y - grow_y / two),
+        ))
+    }
+
+    /// Creates a rectangle one unit tall spanning from `left` to `right` on row `y`.
+    ///
+    /// # Example
+    /// ```
+    /// use rect_lib::{BasicRectangle, Rectangle, RectangleRead};
+    ///
+    /// let rect = BasicRectangle::from_row(2, 0, 5);
+    /// assert_eq!(rect, BasicRectangle::new_from_sides(0, 5, 2, 2));
+    /// ```
+    fn from_row(y: Self::Unit, left: Self::Unit, right: Self::Unit) -> Self {
+        Self::new_from_sides(left, right, y, y)
+    }
+
+    /// Creates a rectangle one unit wide spanning from `top` to `bottom` on column `x`.
+    ///
+    /// # Example
+    /// ```
+    /// use rect_lib::{BasicRectangle, Rectangle, RectangleRead};
+    ///
+    /// let rect = BasicRectangle::from_column(2, 5, 0);
+    /// assert_eq!(rect, BasicRectangle::new_from_sides(2, 2, 5, 0));
+    /// ```
+    fn from_column(x: Self::Unit, top: Self::Unit, bottom: Self::Unit) -> Self {
+        Self::new_from_sides(x, x, top, bottom)
+    }
+
+    /// Grows the rectangle just enough to contain every target, like zooming a camera out to fit them all.
+    /// Sides only ever move outwards, so any side already past every target stays where it is.
+    ///
+    /// # Example
+    /// ```
+    /// use rect_lib::{BasicRectangle, Rectangle, RectangleRead};
+    ///
+    /// let view = BasicRectangle::new_from_sides(0, 9, 9, 0);
+    /// let targets = [BasicRectangle::new_from_sides(5, 14, 3, 2)];
+    /// assert_eq!(view.frame_all(&targets), BasicRectangle::new_from_sides(0, 14, 9, 0));
+    /// ```
+    fn frame_all(&self, targets: &[impl RectangleRead<Unit = Self::Unit>]) -> Self {
+        targets.iter().fold(*self, |frame, target| {
+            Self::new_from_sides(
+                partial_min(frame.left(), target.left()),
+                partial_max(frame.right(), target.right()),
+                partial_max(frame.top(), target.top()),
+                partial_min(frame.bottom(), target.bottom()),
+            )
+        })
+    }
+
+    /// Translates the rectangle by the given amount.
+    /// This is done by adding the given amount to the x and y coordinates.
+    ///
+    /// # Example
+    /// ```
+    /// use rect_lib::{BasicRectangle, Rectangle, RectangleRead};
+    ///
+    /// let rect = BasicRectangle::new_from_sides(0, 1, 1, 0);
+    /// let translated = rect.translate(1, 1);
+    /// assert_eq!(translated, BasicRectangle::new_from_sides(1, 2, 2, 1));
+    /// ```
+    fn translate(&self, x: Self::Unit, y: Self::Unit) -> Self {
+        Self::new_from_sides(
+            self.left() + x,
+            self.right() + x,
+            self.top() + y,
+            self.bottom() + y,
+        )
+    }
+
+    /// Moves the rectangle so its top left corner lands on the nearest grid point, keeping its size.
+    /// Grid points are at multiples of `grid_x` & `grid_y`, halfway points round up.
+    ///
+    /// # Panics
+    /// If `grid_x` or `grid_y` is zero.
+    ///
+    /// # Example
+    /// ```
+    /// use rect_lib::{BasicRectangle, Rectangle, RectangleRead};
+    ///
+    /// let rect = BasicRectangle::new_from_sides(3, 6, 9, 7);
+    /// let snapped = rect.snap_position_to_grid(4, 4);
+    /// assert_eq!(snapped, BasicRectangle::new_from_sides(4, 7, 8, 6));
+    /// ```
+    fn snap_position_to_grid(&self, grid_x: Self::Unit, grid_y: Self::Unit) -> Self {
+        let zero = Self::Unit::zero();
+
+        // the distance to the nearest grid point, rem can be negative so wrap it first
+        let offset = |value: Self::Unit, grid: Self::Unit| {
+            let grid = if grid < zero { zero - grid } else { grid };
+            let mut below = value % grid;
+            if below < zero {
+                below = below + grid;
+            }
+            if below + below >= grid {
+                grid - below
+            } else {
+                zero - below
+            }
+        };
+
+        self.translate(offset(self.left(), grid_x), offset(self.top(), grid_y))
+    }
+
+    /// Shrinks the rectangle so it holds a whole number of `cell_w` by `cell_h` cells, keeping the top left corner.
+    /// The partial column on the right & the partial row on the bottom are dropped.
+    ///
+    /// Sizes count units, since the sides are inclusive a rectangle `width + 1` units wide holds `(width + 1) / cell_w` columns.
+    /// If not even one cell fits, the right side ends up one less than the left or the bottom one more than the top.
+    ///
+    /// # Panics
+    /// If `cell_w` or `cell_h` isn't positive.
+    ///
+    /// # Example
+    /// ```
+    /// use rect_lib::{BasicRectangle, Rectangle, RectangleRead};
+    ///
+    /// let rect = BasicRectangle::new_from_sides(0, 9, 9, 0);
+    /// assert_eq!(rect.fit_to_cell_grid(3, 4), BasicRectangle::new_from_sides(0, 8, 9, 2));
+    /// ```
+    fn fit_to_cell_grid(&self, cell_w: Self::Unit, cell_h: Self::Unit) -> Self {
+        let (zero, one) = (Self::Unit::zero(), Self::Unit::one());
+        assert!(
+            cell_w > zero && cell_h > zero,
+            "cells must have a positive size"
+        );
+        // the largest multiple of the cell size that fits in the units
+        let fit = |units: Self::Unit, cell: Self::Unit| units - units % cell;
+
+        let width = fit(self.width() + one, cell_w);
+        let height = fit(self.height() + one, cell_h);
+        Self::new_from_sides(
+            self.left(),
+            self.left() + width - one,
+            self.top(),
+            self.top() - height + one,
+        )
+    }
+
+    /// Splits the rectangle into a grid of `cell_w` by `cell_h` cells, visited in the given order.
+    ///
+    /// The grid starts at the top left corner & only whole cells are included, like [`Rectangle::fit_to_cell_grid`].
+    ///
+    /// # Panics
+    /// If `cell_w` or `cell_h` isn't positive, the columns or rows would never end.
+    ///
+    /// # Example
+    /// ```
+    /// use rect_lib::{BasicRectangle, FillOrder, Rectangle, RectangleRead};
+    ///
+    /// let rect = BasicRectangle::new_from_sides(0, 5, 1, 0);
+    /// let cells = rect.cells_in_order(2, 2, FillOrder::RowMajor);
+    /// assert_eq!(
+    ///     cells,
+    ///     vec![
+    ///         BasicRectangle::new_from_sides(0, 1, 1, 0),
+    ///         BasicRectangle::new_from_sides(2, 3, 1, 0),
+    ///         BasicRectangle::new_from_sides(4, 5, 1, 0),
+    ///     ]
+    /// );
+    /// ```
+    fn cells_in_order(
+        &self,
+        cell_w: Self::Unit,
+        cell_h: Self::Unit,
+        order: FillOrder,
+    ) -> Vec<Self> {
+        let (zero, one) = (Self::Unit::zero(), Self::Unit::one());
+        assert!(
+            cell_w > zero && cell_h > zero,
+            "cells must have a positive size"
+        );
+        let grid = self.fit_to_cell_grid(cell_w, cell_h);
+
+        // the left side of each column & the top side of each row
+        let mut columns = Vec::new();
+        let mut left = grid.left();
+        while left <= grid.right() {
+            columns.push(left);
+            left = left + cell_w;
+        }
+        let mut rows = Vec::new();
+        let mut top = grid.top();
+        while top >= grid.bottom() {
+            rows.push(top);
+            top = top - cell_h;
+        }
+
+        let cell = |left: Self::Unit, top: Self::Unit| {
+            Self::new_from_sides(left, left + cell_w - one, top, top - cell_h + one)
+        };
+        match order {
+            FillOrder::RowMajor => rows
+                .iter()
+                .flat_map(|&top| columns.iter().map(move |&left| cell(left, top)))
+                .collect(),
+            FillOrder::ColumnMajor => columns
+                .iter()
+                .flat_map(|&left| rows.iter().map(move |&top| cell(left, top)))
+                .collect(),
+            FillOrder::Boustrophedon => rows
+                .iter()
+                .enumerate()
+                .flat_map(|(i, &top)| {
+                    let mut row: Vec<Self> = columns.iter().map(|&left| cell(left, top)).collect();
+                    // every other row runs back the other way
+                    if i % 2 == 1 {
+                        row.reverse();
+                    }
+                    row
+                })
+                .collect(),
+        }
+    }
+
+    /// Counts how many of the rectangles touch each `cell_w` by `cell_h` cell of this rectangle, for spotting where they bunch up.
+    ///
+    /// The grid starts at the top left corner like [`Rectangle::cells_in_order`], but partial cells on the right & bottom are kept.
+    /// A rectangle counts once in every cell it touches, however little of it, & the parts outside of this rectangle are ignored.
+    ///
+    /// # Panics
+    /// If `cell_w` or `cell_h` isn't positive.
+    ///
+    /// # Example
+    /// ```
+    /// use rect_lib::{BasicRectangle, Rectangle, RectangleRead};
+    ///
+    /// let bounds = BasicRectangle::new_from_sides(0, 3, 3, 0);
+    /// let panels = [
+    ///     BasicRectangle::new_from_sides(0, 1, 3, 2),
+    ///     BasicRectangle::new_from_sides(0, 3, 3, 3),
+    /// ];
+    /// let heatmap = bounds.coverage_heatmap(&[&panels[0], &panels[1]], 2, 2);
+    /// assert_eq!(heatmap.dimensions(), (2, 2));
+    /// assert_eq!(heatmap.counts(), &[2, 1, 0, 0]);
+    /// ```
+    fn coverage_heatmap(
+        &self,
+        rects: &[&impl RectangleRead<Unit = Self::Unit>],
+        cell_w: Self::Unit,
+        cell_h: Self::Unit,
+    ) -> Heatmap
+    where
+        Self::Unit: ToPrimitive,
+    {
+        Heatmap::build(self, rects, cell_w, cell_h)
+    }
+
+    /// Splits the rectangle into the frame left around a hole, with each band always in the same slot.
+    ///
+    /// The `order` picks whether the horizontal or vertical bands span the full rectangle, see [`FrameOrder`] for the slots.
+    /// Slots are `None` where the hole reaches that edge. The hole is clipped to the rectangle first,
+    /// if it misses entirely there is no frame to split & this returns `None`.
+    ///
+    /// # Example
+    /// ```
+    /// use rect_lib::{BasicRectangle, FrameOrder, Rectangle, RectangleRead};
+    ///
+    /// let rect = BasicRectangle::new_from_sides(0, 9, 9, 0);
+    /// let hole = BasicRectangle::new_from_sides(3, 6, 9, 3);
+    /// assert_eq!(
+    ///     rect.split_around(&hole, FrameOrder::HorizontalFirst),
+    ///     Some([
+    ///         None,
+    ///         Some(BasicRectangle::new_from_sides(0, 9, 2, 0)),
+    ///         Some(BasicRectangle::new_from_sides(0, 2, 9, 3)),
+    ///         Some(BasicRectangle::new_from_sides(7, 9, 9, 3)),
+    ///     ])
+    /// );
+    ///
+    /// let outside = BasicRectangle::new_from_sides(20, 25, 5, 0);
+    /// assert_eq!(rect.split_around(&outside, FrameOrder::HorizontalFirst), None);
+    /// ```
+    fn split_around(
+        &self,
+        hole: &impl RectangleRead<Unit = Self::Unit>,
+        order: FrameOrder,
+    ) -> Option<[Option<Self>; 4]> {
+        let one = Self::Unit::one();
+        let hole = self.intersection(hole)?;
+        let (left, right, top, bottom) = (self.left(), self.right(), self.top(), self.bottom());
+
+        // the clipped hole is inside, so there is only room for a piece on a side where it stops short,
+        // & checking first keeps the sides one past the hole from going past the limits of the unit
+        let above = (hole.top() < top).then(|| hole.top() + one);
+        let below = (hole.bottom() > bottom).then(|| hole.bottom() - one);
+        let left_of = (hole.left() > left).then(|| hole.left() - one);
+        let right_of = (hole.right() < right).then(|| hole.right() + one);
+
+        Some(match order {
+            FrameOrder::HorizontalFirst => [
+                above.map(|above| Self::new_from_sides(left, right, top, above)),
+                below.map(|below| Self::new_from_sides(left, right, below, bottom)),
+                left_of
+                    .map(|left_of| Self::new_from_sides(left, left_of, hole.top(), hole.bottom())),
+                right_of.map(|right_of| {
+                    Self::new_from_sides(right_of, right, hole.top(), hole.bottom())
+                }),
+            ],
+            FrameOrder::VerticalFirst => [
+                left_of.map(|left_of| Self::new_from_sides(left, left_of, top, bottom)),
+                right_of.map(|right_of| Self::new_from_sides(right_of, right, top, bottom)),
+                above.map(|above| Self::new_from_sides(hole.left(), hole.right(), top, above)),
+                below.map(|below| Self::new_from_sides(hole.left(), hole.right(), below, bottom)),
+            ],
+        })
+    }
+
+    /// Removes the part of the rectangle covered by `hole`, returning what's left as at most four disjoint rectangles.
+    ///
+    /// This is [`Rectangle::split_around`] with [`FrameOrder::HorizontalFirst`] minus the empty slots:
+    /// the full width bands above & below the hole, then the pieces left & right of it.
+    /// A hole missing the rectangle leaves it whole.
+    ///
+    /// # Example
+    /// ```
+    /// use rect_lib::{BasicRectangle, Rectangle, RectangleRead};
+    ///
+    /// let screen = BasicRectangle::new_from_sides(0, 9, 9, 0);
+    /// let taskbar = BasicRectangle::new_from_sides(0, 9, 0, 0);
+    /// assert_eq!(screen.carve_out(&taskbar), vec![BasicRectangle::new_from_sides(0, 9, 9, 1)]);
+    /// ```
+    fn carve_out(&self, hole: &impl RectangleRead<Unit = Self::Unit>) -> Vec<Self> {
+        let mut out = Vec::new();
+        self.carve_out_into(hole, &mut out);
+        out
+    }
+
+    /// The same as `carve_out`, but clears `out` & fills it instead of allocating a new `Vec`.
+    ///
+    /// # Example
+    /// ```
+    /// use rect_lib::{BasicRectangle, Rectangle, RectangleRead};
+    ///
+    /// let rect = BasicRectangle::new_from_sides(0, 9, 9, 0);
+    /// let hole = BasicRectangle::new_from_sides(3, 5, 6, 4);
+    ///
+    /// let mut out = Vec::new();
+    /// rect.carve_out_into(&hole, &mut out);
+    /// assert_eq!(out, rect.carve_out(&hole));
+    /// ```
+    fn carve_out_into(&self, hole: &impl RectangleRead<Unit = Self::Unit>, out: &mut Vec<Self>) {
+        out.clear();
+        match self.split_around(hole, FrameOrder::HorizontalFirst) {
+            Some(pieces) => out.extend(pieces.into_iter().flatten()),
+            None => out.push(*self),
+        }
+    }
+
+    /// Removes every hole from the rectangle, returning what's left as disjoint rectangles in reading order.
+    ///
+    /// This covers exactly the points [`Rectangle::unobstructed_subrectangles`] does with the holes as obstructions,
+    /// but as a partition instead of the overlapping maximal rectangles. It is [`carve_out`](Rectangle::carve_out) for many holes,
+    /// & the same as subtracting a [`RectRegion`] of the holes from one of the rectangle.
+    /// It runs the same sweep as the region operations, so many holes cost about `n log n` rather than a pass per hole.
+    ///
+    /// # Example
+    /// ```
+    /// use rect_lib::{BasicRectangle, Rectangle, RectangleRead};
+    ///
+    /// let rect = BasicRectangle::new_from_sides(0, 9, 9, 0);
+    /// let left = BasicRectangle::new_from_sides(0, 4, 9, 5);
+    /// let right = BasicRectangle::new_from_sides(5, 9, 4, 0);
+    /// assert_eq!(
+    ///     rect.subtract_all(&[&left, &right]),
+    ///     vec![
+    ///         BasicRectangle::new_from_sides(5, 9, 9, 5),
+    ///         BasicRectangle::new_from_sides(0, 4, 4, 0),
+    ///     ]
+    /// );
+    /// ```
+    fn subtract_all(&self, holes: &[&impl RectangleRead<Unit = Self::Unit>]) -> Vec<Self>
+    where
+        Self::Unit: Ord,
+    {
+        let mut out = Vec::new();
+        self.subtract_all_into(holes, &mut out);
+        out
+    }
+
+    /// The same as `subtract_all`, but clears `out` & fills it instead of allocating a new `Vec` for the result.
+    ///
+    /// # Example
+    /// ```
+    /// use rect_lib::{BasicRectangle, Rectangle, RectangleRead};
+    ///
+    /// let rect = BasicRectangle::new_from_sides(0, 9, 9, 0);
+    /// let hole = BasicRectangle::new_from_sides(3, 5, 6, 4);
+    ///
+    /// let mut out = Vec::new();
+    /// rect.subtract_all_into(&[&hole], &mut out);
+    /// assert_eq!(out, rect.subtract_all(&[&hole]));
+    /// ```
+    fn subtract_all_into(
+        &self,
+        holes: &[&impl RectangleRead<Unit = Self::Unit>],
+        out: &mut Vec<Self>,
+    ) where
+        Self::Unit: Ord,
+    {
+        let holes: Vec<Self> = holes
+            .iter()
+            .map(|hole| Self::new_from_sides(hole.left(), hole.right(), hole.top(), hole.bottom()))
+            .collect();
+        RectRegion::combine_into(&[*self], &holes, |a, b| a && !b, out);
+    }
+
+    /// Splits the rectangle into two at the given x coordinate.
+    /// Returns `(left, right)` where the right piece starts at `x`.
+    /// If `x` would leave either piece empty, `None` is returned.
+    ///
+    /// # Example
+    /// ```
+    /// use rect_lib::{BasicRectangle, Rectangle, RectangleRead};
+    ///
+    /// let rect = BasicRectangle::new_from_sides(0, 4, 1, 0);
+    /// let (left, right) = rect.split_x(2).unwrap();
+    /// assert_eq!(left, BasicRectangle::new_from_sides(0, 1, 1, 0));
+    /// assert_eq!(right, BasicRectangle::new_from_sides(2, 4, 1, 0));
+    ///
+    /// assert_eq!(rect.split_x(0), None);
+    /// ```
+    fn split_x(&self, x: Self::Unit) -> Option<(Self, Self)> {
+        if x <= self.left() || self.right() < x {
+            return None;
+        }
+
+        Some((
+            Self::new_from_sides(
+                self.left(),
+                x - Self::Unit::one(),
+                self.top(),
+                self.bottom(),
+            ),
+            Self::new_from_sides(x, self.right(), self.top(), self.bottom()),
+        ))
+    }
+
+    /// Splits the rectangle into two at the given y coordinate.
+    /// Returns `(bottom, top)` where the top piece starts at `y`.
+    /// If `y` would leave either piece empty, `None` is returned.
+    ///
+    /// # Example
+    /// ```
+    /// use rect_lib::{BasicRectangle, Rectangle, RectangleRead};
+    ///
+    /// let rect = BasicRectangle::new_from_sides(0, 1, 4, 0);
+    /// let (bottom, top) = rect.split_y(2).unwrap();
+    /// assert_eq!(bottom, BasicRectangle::new_from_sides(0, 1, 1, 0));
+    /// assert_eq!(top, BasicRectangle::new_from_sides(0, 1, 4, 2));
+    ///
+    /// assert_eq!(rect.split_y(5), None);
+    /// ```
+    fn split_y(&self, y: Self::Unit) -> Option<(Self, Self)> {
+        if y <= self.bottom() || self.top() < y {
+            return None;
+        }
+
+        Some((
+            Self::new_from_sides(
+                self.left(),
+                self.right(),
+                y - Self::Unit::one(),
+                self.bottom(),
+            ),
+            Self::new_from_sides(self.left(), self.right(), self.top(), y),
+        ))
+    }
+
+    /// Translates the rectangle by the given amount along the given axis.
+    ///
+    /// # Example
+    /// ```
+    /// use rect_lib::{Axis, BasicRectangle, Rectangle, RectangleRead};
+    ///
+    /// let rect = BasicRectangle::new_from_sides(0, 1, 1, 0);
+    /// assert_eq!(rect.translate_along(Axis::Y, 2), rect.translate(0, 2));
+    /// ```
+    fn translate_along(&self, axis: Axis, distance: Self::Unit) -> Self {
+        match axis {
+            Axis::X => self.translate(distance, Self::Unit::zero()),
+            Axis::Y => self.translate(Self::Unit::zero(), distance),
+        }
+    }
+
+    /// Splits the rectangle into two at the given point along the given axis.
+    /// Returns the lower piece first, see `split_x` & `split_y`.
+    ///
+    /// # Example
+    /// ```
+    /// use rect_lib::{Axis, BasicRectangle, Rectangle, RectangleRead};
+    ///
+    /// let rect = BasicRectangle::new_from_sides(0, 4, 4, 0);
+    /// assert_eq!(rect.split_along(Axis::X, 2), rect.split_x(2));
+    /// ```
+    fn split_along(&self, axis: Axis, at: Self::Unit) -> Option<(Self, Self)> {
+        match axis {
+            Axis::X => self.split_x(at),
+            Axis::Y => self.split_y(at),
+        }
+    }
+
+    /// Splits the rectangle into columns with widths proportional to the given weights, from left to right.
+    /// The columns tile the rectangle exactly, one per weight.
+    ///
+    /// The edges between columns are rounded to the nearest unit, so each column is within a unit of its exact share.
+    /// A column whose share rounds to nothing has its right one less than its left.
+    /// Negative weights count as zero, & if every weight is zero the columns are even.
+    ///
+    /// # Example
+    /// ```
+    /// use rect_lib::{BasicRectangle, Rectangle, RectangleRead};
+    ///
+    /// let row = BasicRectangle::new_from_sides(0, 7, 1, 0);
+    /// assert_eq!(
+    ///     row.columns_weighted(&[1.0, 2.0, 1.0]),
+    ///     vec![
+    ///         BasicRectangle::new_from_sides(0, 1, 1, 0),
+    ///         BasicRectangle::new_from_sides(2, 5, 1, 0),
+    ///         BasicRectangle::new_from_sides(6, 7, 1, 0),
+    ///     ]
+    /// );
+    /// ```
+    fn columns_weighted(&self, weights: &[f64]) -> Vec<Self>
+    where
+        Self::Unit: NumCast,
+    {
+        let mut columns = Vec::new();
+        self.columns_weighted_into(weights, &mut columns);
+        columns
+    }
+
+    /// The same as `columns_weighted`, but clears `out` & fills it instead of allocating a new `Vec` for the result.
+    ///
+    /// # Example
+    /// ```
+    /// use rect_lib::{BasicRectangle, Rectangle, RectangleRead};
+    ///
+    /// let row = BasicRectangle::new_from_sides(0, 7, 1, 0);
+    /// let mut out = Vec::new();
+    /// row.columns_weighted_into(&[1.0, 2.0, 1.0], &mut out);
+    /// assert_eq!(out, row.columns_weighted(&[1.0, 2.0, 1.0]));
+    /// ```
+    fn columns_weighted_into(&self, weights: &[f64], out: &mut Vec<Self>)
+    where
+        Self::Unit: NumCast,
+    {
+        let one = Self::Unit::one();
+        out.clear();
+        // the sides are inclusive so +1
+        out.extend(
+            weighted_offsets(self.width() + one, weights)
+                .windows(2)
+                .map(|offset| {
+                    Self::new_from_sides(
+                        self.left() + offset[0],
+                        self.left() + offset[1] - one,
+                        self.top(),
+                        self.bottom(),
+                    )
+                }),
+        );
+    }
+
+    /// Splits the rectangle into rows with heights proportional to the given weights, from top to bottom.
+    /// The rows tile the rectangle exactly, one per weight.
+    ///
+    /// The edges between rows are rounded like `columns_weighted`, so each row is within a unit of its exact share.
+    /// A row whose share rounds to nothing has its bottom one more than its top.
+    /// Negative weights count as zero, & if every weight is zero the rows are even.
+    ///
+    /// # Example
+    /// ```
+    /// use rect_lib::{BasicRectangle, Rectangle, RectangleRead};
+    ///
+    /// let column = BasicRectangle::new_from_sides(0, 1, 7, 0);
+    /// assert_eq!(
+    ///     column.rows_weighted(&[1.0, 3.0]),
+    ///     vec![
+    ///         BasicRectangle::new_from_sides(0, 1, 7, 6),
+    ///         BasicRectangle::new_from_sides(0, 1, 5, 0),
+    ///     ]
+    /// );
+    /// ```
+    fn rows_weighted(&self, weights: &[f64]) -> Vec<Self>
+    where
+        Self::Unit: NumCast,
+    {
+        let mut rows = Vec::new();
+        self.rows_weighted_into(weights, &mut rows);
+        rows
+    }
+
+    /// The same as `rows_weighted`, but clears `out` & fills it instead of allocating a new `Vec` for the result.
+    ///
+    /// # Example
+    /// ```
+    /// use rect_lib::{BasicRectangle, Rectangle, RectangleRead};
+    ///
+    /// let column = BasicRectangle::new_from_sides(0, 1, 7, 0);
+    /// let mut out = Vec::new();
+    /// column.rows_weighted_into(&[1.0, 3.0], &mut out);
+    /// assert_eq!(out, column.rows_weighted(&[1.0, 3.0]));
+    /// ```
+    fn rows_weighted_into(&self, weights: &[f64], out: &mut Vec<Self>)
+    where
+        Self::Unit: NumCast,
+    {
+        let one = Self::Unit::one();
+        out.clear();
+        // the sides are inclusive so +1
+        out.extend(
+            weighted_offsets(self.height() + one, weights)
+                .windows(2)
+                .map(|offset| {
+                    Self::new_from_sides(
+                        self.left(),
+                        self.right(),
+                        self.top() - offset[0],
+                        self.top() - offset[1] + one,
+                    )
+                }),
+        );
+    }
+
+    /// Splits the rectangle into at most `k` pieces of roughly equal area, for spreading work across `k` threads.
+    /// The pieces tile the rectangle exactly & are returned in reading order (top to bottom, then left to right).
+    ///
+    /// The pieces form a near-square grid of `rows = floor(sqrt(k))` by `k / rows`, with the longer count along the longer side,
+    /// so a prime `k` gives fewer than `k` pieces rather than thin strips.
+    /// There are never more columns or rows than the rectangle has points across, & a `k` of 0 is treated as 1.
+    ///
+    /// # Example
+    /// ```
+    /// use rect_lib::{BasicRectangle, Rectangle, RectangleRead};
+    ///
+    /// let rect = BasicRectangle::new_from_sides(0, 9, 9, 0);
+    /// let pieces = rect.partition_into_at_most(4);
+    /// assert_eq!(pieces[0], BasicRectangle::new_from_sides(0, 4, 9, 5));
+    /// assert_eq!(pieces.len(), 4);
+    /// ```
+    fn partition_into_at_most(&self, k: usize) -> Vec<Self>
+    where
+        Self::Unit: NumCast,
+    {
+        let mut pieces = Vec::new();
+        self.partition_into_at_most_into(k, &mut pieces);
+        pieces
+    }
+
+    /// The same as `partition_into_at_most`, but clears `out` & fills it instead of allocating a new `Vec` for the result.
+    ///
+    /// # Example
+    /// ```
+    /// use rect_lib::{BasicRectangle, Rectangle, RectangleRead};
+    ///
+    /// let rect = BasicRectangle::new_from_sides(0, 9, 9, 0);
+    /// let mut out = Vec::new();
+    /// rect.partition_into_at_most_into(4, &mut out);
+    /// assert_eq!(out, rect.partition_into_at_most(4));
+    /// ```
+    fn partition_into_at_most_into(&self, k: usize, out: &mut Vec<Self>)
+    where
+        Self::Unit: NumCast,
+    {
+        let k = k.max(1);
+        let short = k.isqrt();
+        let long = k / short;
+
+        // more pieces along the longer side, but never more than there are points
+        let (columns, rows) = if self.width() >= self.height() {
+            (long, short)
+        } else {
+            (short, long)
+        };
+        let points = |units: Self::Unit| {
+            units
+                .to_usize()
+                .map_or(usize::MAX, |units| units.saturating_add(1))
+        };
+        let columns = columns.min(points(self.width()));
+        let rows = rows.min(points(self.height()));
+
+        out.clear();
+        out.extend(
+            self.rows_weighted(&vec![1.0; rows])
+                .iter()
+                .flat_map(|row| row.columns_weighted(&vec![1.0; columns])),
+        );
+    }
+
+    /// Splits the rectangle into a grid of `cols` by `rows` cells, keeping only the ones not overlapping any obstruction.
+    /// The cells are returned in reading order (top to bottom, then left to right), for placing items on a grid around obstacles.
+    ///
+    /// The grid is cut with `rows_weighted` & `columns_weighted` using even weights, so the cells differ in size by at most a unit.
+    /// If there are more columns or rows than points across, the cells left empty are dropped too.
+    ///
+    /// # Example
+    /// ```
+    /// use rect_lib::{BasicRectangle, Rectangle, RectangleRead};
+    ///
+    /// let rect = BasicRectangle::new_from_sides(0, 5, 5, 0);
+    /// let obstruction = BasicRectangle::new_from_sides(0, 1, 5, 4);
+    /// let cells = rect.grid_cells_unobstructed(3, 3, &[&obstruction]);
+    /// assert_eq!(cells.len(), 8);
+    /// assert_eq!(cells[0], BasicRectangle::new_from_sides(2, 3, 5, 4));
+    /// ```
+    fn grid_cells_unobstructed(
+        &self,
+        cols: usize,
+        rows: usize,
+        obstructions: &[&impl RectangleRead<Unit = Self::Unit>],
+    ) -> Vec<Self>
+    where
+        Self::Unit: NumCast,
+    {
+        self.rows_weighted(&vec![1.0; rows])
+            .iter()
+            .flat_map(|row| row.columns_weighted(&vec![1.0; cols]))
+            .filter(|cell| cell.left() <= cell.right() && cell.bottom() <= cell.top())
+            .filter(|cell| {
+                !obstructions
+                    .iter()
+                    .any(|obstruction| cell.overlaps(*obstruction))
+            })
+            .collect()
+    }
+
+    /// Lays out a squarified treemap, one piece per weight with areas proportional to the weights.
+    /// The pieces tile the rectangle exactly & are returned in the same order as the weights.
+    ///
+    /// This follows Bruls, Huizing & van Wijk's squarified algorithm:
+    /// the largest weights are laid in strips along the shorter side, growing each strip while that keeps its pieces closer to square.
+    /// The strips & the pieces in them are cut with `columns_weighted` & `rows_weighted`, so their edges are rounded the same way.
+    /// Negative weights count as zero, & if every weight is zero the pieces are even.
+    ///
+    /// # Example
+    /// ```
+    /// use rect_lib::{BasicRectangle, Rectangle, RectangleRead};
+    ///
+    /// let rect = BasicRectangle::new_from_sides(0, 5, 3, 0);
+    /// let pieces = rect.squarified_treemap(&[6.0, 6.0, 4.0, 3.0, 2.0, 2.0, 1.0]);
+    /// assert_eq!(pieces[0], BasicRectangle::new_from_sides(0, 2, 3, 2));
+    /// assert_eq!(pieces[1], BasicRectangle::new_from_sides(0, 2, 1, 0));
+    /// ```
+    fn squarified_treemap(&self, weights: &[f64]) -> Vec<Self>
+    where
+        Self::Unit: NumCast,
+    {
+        let mut pieces = Vec::new();
+        self.squarified_treemap_into(weights, &mut pieces);
+        pieces
+    }
+
+    /// The same as `squarified_treemap`, but clears `out` & fills it instead of allocating a new `Vec` for the result.
+    ///
+    /// # Example
+    /// ```
+    /// use rect_lib::{BasicRectangle, Rectangle, RectangleRead};
+    ///
+    /// let rect = BasicRectangle::new_from_sides(0, 5, 3, 0);
+    /// let mut out = Vec::new();
+    /// rect.squarified_treemap_into(&[3.0, 2.0, 1.0], &mut out);
+    /// assert_eq!(out, rect.squarified_treemap(&[3.0, 2.0, 1.0]));
+    /// ```
+    fn squarified_treemap_into(&self, weights: &[f64], out: &mut Vec<Self>)
+    where
+        Self::Unit: NumCast,
+    {
+        let mut weights: Vec<f64> = weights.iter().map(|&weight| weight.max(0.0)).collect();
+        if weights.iter().sum::<f64>() <= 0.0 {
+            weights.fill(1.0);
+        }
+        // the largest first, keeping track of where each one goes
+        let mut order: Vec<usize> = (0..weights.len()).collect();
+        order.sort_by(|&a, &b| weights[b].total_cmp(&weights[a]));
+
+        let points = |units: Self::Unit| units.to_f64().unwrap_or(0.0) + 1.0;
+        let mut pieces: Vec<Option<Self>> = vec![None; weights.len()];
+        let mut remaining = *self;
+        let mut start = 0;
+        while start < order.len() {
+            let (width, height) = (points(remaining.width()), points(remaining.height()));
+            let short = width.min(height);
+            let rest: f64 = order[start..].iter().map(|&i| weights[i]).sum();
+            let scale = width * height / rest;
+
+            // the worst aspect ratio in a strip holding weights summing to `sum` between `min` & `max`
+            let worst = |sum: f64, min: f64, max: f64| {
+                let (sum, min, max) = (sum * scale, min * scale, max * scale);
+                (short * short * max / (sum * sum)).max(sum * sum / (short * short * min))
+            };
+
+            let first = weights[order[start]];
+            let (mut end, mut sum, mut min) = (start + 1, first, first);
+            while end < order.len() {
+                let next = weights[order[end]];
+                // the weights are sorted, so the first is the largest & `next` the smallest
+                if rest - sum > 0.0 && worst(sum + next, next, first) > worst(sum, min, first) {
+                    break;
+                }
+                (end, sum, min) = (end + 1, sum + next, next);
+            }
+
+            let strip_weights: Vec<f64> = order[start..end].iter().map(|&i| weights[i]).collect();
+            // the strip runs along the shorter side, so it is cut off the longer one
+            let strip_pieces = if width >= height {
+                let [strip, rest] = remaining.columns_weighted(&[sum, rest - sum])[..] else {
+                    unreachable!("two weights give two columns");
+                };
+                remaining = rest;
+                strip.rows_weighted(&strip_weights)
+            } else {
+                let [strip, rest] = remaining.rows_weighted(&[sum, rest - sum])[..] else {
+                    unreachable!("two weights give two rows");
+                };
+                remaining = rest;
+                strip.columns_weighted(&strip_weights)
+            };
+            for (&i, piece) in order[start..end].iter().zip(strip_pieces) {
+                pieces[i] = Some(piece);
+            }
+            start = end;
+        }
+
+        out.clear();
+        out.extend(pieces.into_iter().flatten());
+    }
+
+    /// Wraps the rectangle into a toroidal world, returning the pieces of its footprint inside the world.
+    /// A rectangle straddling one seam is split into two pieces, & one straddling a corner into four.
+    ///
+    /// The rectangle should be within one world width & height of the world.
+    /// If it is as wide or tall as the world, it covers the world along that axis.
+    ///
+    /// # Example
+    /// ```
+    /// use rect_lib::{BasicRectangle, Rectangle, RectangleRead};
+    ///
+    /// let world = BasicRectangle::new_from_sides(0, 9, 9, 0);
+    /// let rect = BasicRectangle::new_from_sides(8, 11, 5, 4);
+    /// assert_eq!(
+    ///     rect.wrap_pieces(&world),
+    ///     vec![
+    ///         BasicRectangle::new_from_sides(8, 9, 5, 4),
+    ///         BasicRectangle::new_from_sides(0, 1, 5, 4),
+    ///     ]
+    /// );
+    /// ```
+    fn wrap_pieces(&self, world: &impl RectangleRead<Unit = Self::Unit>) -> Vec<Self> {
+        let columns = wrap_range(self.left(), self.right(), world.left(), world.right());
+        let rows = wrap_range(self.bottom(), self.top(), world.bottom(), world.top());
+
+        rows.iter()
+            .flatten()
+            .flat_map(|&(bottom, top)| {
+                columns
+                    .iter()
+                    .flatten()
+                    .map(move |&(left, right)| Self::new_from_sides(left, right, top, bottom))
+            })
+            .collect()
+    }
+
+    /// Checks if this rectangle contains the other in a toroidal world, where either can spill over the seams.
+    ///
+    /// Both rectangles are wrapped into the world with `wrap_pieces` first, so they should be within one world width & height of it.
+    /// Every piece of the other has to fit in one piece of this rectangle, since the pieces of this rectangle never line up end to end.
+    ///
+    /// # Example
+    /// ```
+    /// use rect_lib::{BasicRectangle, Rectangle, RectangleRead};
+    ///
+    /// let world = BasicRectangle::new_from_sides(0, 9, 9, 0);
+    /// let rect = BasicRectangle::new_from_sides(7, 12, 5, 2);
+    /// assert!(rect.contains_rectangle_wrapped(&BasicRectangle::new_from_sides(9, 10, 4, 3), &world));
+    /// assert!(rect.contains_rectangle_wrapped(&BasicRectangle::new_from_sides(-1, 1, 4, 3), &world));
+    /// assert!(!rect.contains_rectangle_wrapped(&BasicRectangle::new_from_sides(2, 4, 4, 3), &world));
+    /// ```
+    fn contains_rectangle_wrapped(
+        &self,
+        other: &impl RectangleRead<Unit = Self::Unit>,
+        world: &impl RectangleRead<Unit = Self::Unit>,
+    ) -> bool {
+        let pieces = self.wrap_pieces(world);
+        let other = Self::new_from_sides(other.left(), other.right(), other.top(), other.bottom());
+        other
+            .wrap_pieces(world)
+            .iter()
+            .all(|piece| pieces.iter().any(|rect| rect.contains_rectangle(piece)))
+    }
+
+    /// Translates the rectangle in a toroidal world, splitting it where it wraps around the edges.
+    /// Returns `(main, wrapped_x, wrapped_y, wrapped_both)`, where `main` holds the bottom left corner
+    /// & the rest are the pieces that re-entered on the left, the bottom, or both.
+    ///
+    /// The rectangle can be moved any distance, it is wrapped back into the world first.
+    /// A rectangle as wide or tall as the world is clamped to the world along that axis, so it never wraps on it.
+    ///
+    /// # Example
+    /// ```
+    /// use rect_lib::{BasicRectangle, Rectangle, RectangleRead};
+    ///
+    /// let world = BasicRectangle::new_from_sides(0, 9, 9, 0);
+    /// let rect = BasicRectangle::new_from_sides(6, 8, 1, 0);
+    /// assert_eq!(
+    ///     rect.translate_wrapping(2, 0, &world),
+    ///     (
+    ///         BasicRectangle::new_from_sides(8, 9, 1, 0),
+    ///         Some(BasicRectangle::new_from_sides(0, 0, 1, 0)),
+    ///         None,
+    ///         None,
+    ///     )
+    /// );
+    /// ```
+    #[allow(clippy::type_complexity)]
+    fn translate_wrapping(
+        &self,
+        dx: Self::Unit,
+        dy: Self::Unit,
+        world: &impl RectangleRead<Unit = Self::Unit>,
+    ) -> (Self, Option<Self>, Option<Self>, Option<Self>) {
+        let moved = self.translate(dx, dy);
+        let (left, bottom) = world.wrap_point(moved.left(), moved.bottom());
+
+        // with the bottom left corner wrapped inside, a range can only spill past the end of the world.
+        // Only an inverted rectangle has no part inside, & it is left where it is
+        let split = |min: Self::Unit, length: Self::Unit, world_min, world_max| {
+            let [inside, wrapped, _] = wrap_range(min, min + length, world_min, world_max);
+            (inside.unwrap_or((min, min + length)), wrapped)
+        };
+        let (x, wrapped_x) = split(left, self.width(), world.left(), world.right());
+        let (y, wrapped_y) = split(bottom, self.height(), world.bottom(), world.top());
+
+        let piece = |(left, right): (Self::Unit, Self::Unit),
+                     (bottom, top): (Self::Unit, Self::Unit)| {
+            Self::new_from_sides(left, right, top, bottom)
+        };
+        (
+            piece(x, y),
+            wrapped_x.map(|x| piece(x, y)),
+            wrapped_y.map(|y| piece(x, y)),
+            wrapped_x.zip(wrapped_y).map(|(x, y)| piece(x, y)),
+        )
+    }
+
+    /// Returns the intersection of two rectangles.
+    /// If the rectangles do not intersect, `None` is returned.
+    ///
+    /// # Example
+    /// ```
+    /// use rect_lib::{BasicRectangle, Rectangle, RectangleRead};
+    ///
+    /// let rect = BasicRectangle::new_from_sides(0, 2, 2, 0);
+    /// let intersection = rect.intersection(&BasicRectangle::new_from_sides(1, 3, 3, 1));
+    /// assert_eq!(intersection, Some(BasicRectangle::new_from_sides(1, 2, 2, 1)));
+    ///
+    /// let no_intersection = rect.intersection(&BasicRectangle::new_from_sides(3, 4, 4, 3));
+    /// assert_eq!(no_intersection, None);
+    /// ```
+    fn intersection(&self, other: &impl RectangleRead<Unit = Self::Unit>) -> Option<Self> {
+        let left = partial_max(self.left(), other.left());
+        let right = partial_min(self.right(), other.right());
+        let top = partial_min(self.top(), other.top());
+        let bottom = partial_max(self.bottom(), other.bottom());
+
+        if left <= right && bottom <= top {
+            Some(Self::new_from_sides(left, right, top, bottom))
+        } else {
+            None
+        }
+    }
+
+    /// The intersection with each of `others` that overlaps this rectangle, along with its index, in the order of `others`.
+    ///
+    /// This is [`Rectangle::intersection`] in a loop, but the sides of this rectangle & of each other one are only read once.
+    ///
+    /// # Example
+    /// ```
+    /// use rect_lib::{BasicRectangle, Rectangle, RectangleRead};
+    ///
+    /// let camera = BasicRectangle::new_from_sides(0, 9, 9, 0);
+    /// let sprites = [
+    ///     BasicRectangle::new_from_sides(20, 22, 2, 0),
+    ///     BasicRectangle::new_from_sides(8, 12, 2, 0),
+    /// ];
+    /// assert_eq!(
+    ///     camera.intersections_with(&sprites),
+    ///     vec![(1, BasicRectangle::new_from_sides(8, 9, 2, 0))]
+    /// );
+    /// ```
+    fn intersections_with(
+        &self,
+        others: &[impl RectangleRead<Unit = Self::Unit>],
+    ) -> Vec<(usize, Self)> {
+        let mut intersections = Vec::new();
+        self.intersections_with_into(others, &mut intersections);
+        intersections
+    }
+
+    /// The same as `intersections_with`, but clears `out` & fills it instead of allocating a new `Vec`.
+    ///
+    /// # Example
+    /// ```
+    /// use rect_lib::{BasicRectangle, Rectangle, RectangleRead};
+    ///
+    /// let camera = BasicRectangle::new_from_sides(0, 9, 9, 0);
+    /// let sprites = [BasicRectangle::new_from_sides(8, 12, 2, 0)];
+    ///
+    /// let mut out = Vec::new();
+    /// camera.intersections_with_into(&sprites, &mut out);
+    /// assert_eq!(out, camera.intersections_with(&sprites));
+    /// ```
+    fn intersections_with_into(
+        &self,
+        others: &[impl RectangleRead<Unit = Self::Unit>],
+        out: &mut Vec<(usize, Self)>,
+    ) {
+        out.clear();
+        let (left, right, top, bottom) = (self.left(), self.right(), self.top(), self.bottom());
+        for (i, other) in others.iter().enumerate() {
+            let left = partial_max(left, other.left());
+            let right = partial_min(right, other.right());
+            let top = partial_min(top, other.top());
+            let bottom = partial_max(bottom, other.bottom());
+            if left <= right && bottom <= top {
+                out.push((i, Self::new_from_sides(left, right, top, bottom)));
+            }
+        }
+    }
+
+    /// The same as `intersections_with` for `others` sorted by their left side, skipping the ones right of this rectangle without looking at them.
+    ///
+    /// Like [`clip_all_sorted`] the cut off is found with a binary search, & the result is only correct if `others` are sorted by left.
+    ///
+    /// # Example
+    /// ```
+    /// use rect_lib::{BasicRectangle, Rectangle, RectangleRead};
+    ///
+    /// let camera = BasicRectangle::new_from_sides(0, 9, 9, 0);
+    /// let sprites: Vec<_> = (0..1000).map(|x| BasicRectangle::new_from_sides(x, x + 1, 1, 0)).collect();
+    /// assert_eq!(camera.intersections_with_sorted(&sprites).len(), 10);
+    /// ```
+    fn intersections_with_sorted(
+        &self,
+        others: &[impl RectangleRead<Unit = Self::Unit>],
+    ) -> Vec<(usize, Self)> {
+        let mut intersections = Vec::new();
+        self.intersections_with_sorted_into(others, &mut intersections);
+        intersections
+    }
+
+    /// The same as `intersections_with_sorted`, but clears `out` & fills it instead of allocating a new `Vec`.
+    fn intersections_with_sorted_into(
+        &self,
+        others: &[impl RectangleRead<Unit = Self::Unit>],
+        out: &mut Vec<(usize, Self)>,
+    ) {
+        debug_assert!(others
+            .windows(2)
+            .all(|pair| pair[0].left() <= pair[1].left()));
+
+        // nothing starting right of this rectangle can reach it, & cutting off the end keeps the indices
+        let right = self.right();
+        let end = others.partition_point(|other| other.left() <= right);
+        self.intersections_with_into(&others[..end], out);
+    }
+
+    /// Returns the intersection of two rectangles along with the penetration depth on each axis.
+    /// The depths are the distance either rectangle must move along that axis to stop overlapping,
+    /// since the sides are inclusive this is one more than the intersection's `width` & `height`.
+    /// If the rectangles do not intersect, `None` is returned.
+    ///
+    /// # Example
+    /// ```
+    /// use rect_lib::{BasicRectangle, Rectangle, RectangleRead};
+    ///
+    /// let rect = BasicRectangle::new_from_sides(0, 2, 2, 0);
+    /// let overlap = rect.overlap_with_depth(&BasicRectangle::new_from_sides(1, 4, 3, 2));
+    /// assert_eq!(overlap, Some((BasicRectangle::new_from_sides(1, 2, 2, 2), 2, 1)));
+    ///
+    /// let no_overlap = rect.overlap_with_depth(&BasicRectangle::new_from_sides(3, 4, 4, 3));
+    /// assert_eq!(no_overlap, None);
+    /// ```
+    fn overlap_with_depth(
+        &self,
+        other: &impl RectangleRead<Unit = Self::Unit>,
+    ) -> Option<(Self, Self::Unit, Self::Unit)> {
+        let intersection = self.intersection(other)?;
+        Some((
+            intersection,
+            intersection.width() + Self::Unit::one(),
+            intersection.height() + Self::Unit::one(),
+        ))
+    }
+
+    /// Applies a 2D affine transform to the corners & returns their axis aligned bounding box, rounded outwards for integer units.
+    ///
+    /// The matrix `[a, b, c, d, e, f]` maps `(x, y)` to `(a * x + c * y + e, b * x + d * y + f)`, the same layout as SVG & canvas.
+    /// When rounding, corners within a billionth of a whole unit are treated as whole, so float noise from rotations doesn't round outwards.
+    /// Sides that can't be represented by the unit type are left where they were.
+    ///
+    /// # Example
+    /// ```
+    /// use rect_lib::{BasicRectangle, Rectangle, RectangleRead};
+    ///
+    /// let rect = BasicRectangle::new_from_sides(0, 4, 2, 0);
+    /// // rotate 90 degrees counterclockwise about the origin
+    /// let rotated = rect.transformed_aabb([0.0, 1.0, -1.0, 0.0, 0.0, 0.0]);
+    /// assert_eq!(rotated, BasicRectangle::new_from_sides(-2, 0, 4, 0));
+    /// ```
+    fn transformed_aabb(&self, matrix: [f64; 6]) -> Self
+    where
+        Self::Unit: NumCast,
+    {
+        let [a, b, c, d, e, f] = matrix;
+        let to_f64 = |unit: Self::Unit| unit.to_f64().unwrap_or(f64::NAN);
+        let (left, right) = (to_f64(self.left()), to_f64(self.right()));
+        let (top, bottom) = (to_f64(self.top()), to_f64(self.bottom()));
+
+        let (mut min_x, mut max_x) = (f64::INFINITY, f64::NEG_INFINITY);
+        let (mut min_y, mut max_y) = (f64::INFINITY, f64::NEG_INFINITY);
+        for (x, y) in [(left, top), (right, top), (left, bottom), (right, bottom)] {
+            let (x, y) = (a * x + c * y + e, b * x + d * y + f);
+            (min_x, max_x) = (min_x.min(x), max_x.max(x));
+            (min_y, max_y) = (min_y.min(y), max_y.max(y));
+        }
+
+        // values a hair off a whole unit are float noise, like cos(90°), & shouldn't grow the box
+        let snap = |value: f64| {
+            if (value - value.round()).abs() < 1e-9 {
+                value.round()
+            } else {
+                value
+            }
+        };
+        // float units can hold the box exactly, integer ones round outwards to keep all of it
+        let integer = integer_unit::<Self::Unit>();
+        let outwards = |value: f64, round: fn(f64) -> f64| {
+            if integer {
+                round(snap(value))
+            } else {
+                value
+            }
+        };
+        let side = |value: f64, fallback: Self::Unit| NumCast::from(value).unwrap_or(fallback);
+        Self::new_from_sides(
+            side(outwards(min_x, f64::floor), self.left()),
+            side(outwards(max_x, f64::ceil), self.right()),
+            side(outwards(max_y, f64::ceil), self.top()),
+            side(outwards(min_y, f64::floor), self.bottom()),
+        )
+    }
+
+    /// Transforms each rectangle by its own matrix, see [`Rectangle::transformed_aabb`], & returns one box around them all.
+    /// Returns `None` if there are no rectangles.
+    ///
+    /// # Example
+    /// ```
+    /// use rect_lib::{BasicRectangle, Rectangle, RectangleRead};
+    ///
+    /// let shift = [1.0, 0.0, 0.0, 1.0, 10.0, 0.0];
+    /// let flip = [-1.0, 0.0, 0.0, 1.0, 0.0, 0.0];
+    /// let items = [
+    ///     (BasicRectangle::new_from_sides(0, 2, 2, 0), shift),
+    ///     (BasicRectangle::new_from_sides(0, 2, 5, 3), flip),
+    /// ];
+    /// let frame = BasicRectangle::aabb_of_transformed_set(&items);
+    /// assert_eq!(frame, Some(BasicRectangle::new_from_sides(-2, 12, 5, 0)));
+    /// ```
+    fn aabb_of_transformed_set(items: &[(Self, [f64; 6])]) -> Option<Self>
+    where
+        Self::Unit: NumCast,
+    {
+        items
+            .iter()
+            .map(|(rect, matrix)| rect.transformed_aabb(*matrix))
+            .reduce(|bounds, rect| {
+                Self::new_from_sides(
+                    partial_min(bounds.left(), rect.left()),
+                    partial_max(bounds.right(), rect.right()),
+                    partial_max(bounds.top(), rect.top()),
+                    partial_min(bounds.bottom(), rect.bottom()),
+                )
+            })
+    }
+
+    /// Linearly interpolates each side between this rectangle & the target.
+    /// `t` is clamped to `0.0..=1.0` & for integer units each side is rounded to the nearest unit.
+    ///
+    /// # Example
+    /// ```
+    /// use rect_lib::{BasicRectangle, Rectangle, RectangleRead};
+    ///
+    /// let rect = BasicRectangle::new_from_sides(0, 2, 2, 0);
+    /// let target = BasicRectangle::new_from_sides(4, 10, 6, 0);
+    /// assert_eq!(rect.lerp(&target, 0.5), BasicRectangle::new_from_sides(2, 6, 4, 0));
+    /// ```
+    fn lerp(&self, target: &impl RectangleRead<Unit = Self::Unit>, t: f64) -> Self
+    where
+        Self::Unit: NumCast,
+    {
+        Self::new_from_sides(
+            lerp_unit(self.left(), target.left(), t),
+            lerp_unit(self.right(), target.right(), t),
+            lerp_unit(self.top(), target.top(), t),
+            lerp_unit(self.bottom(), target.bottom(), t),
+        )
+    }
+
+    /// Interpolates each side between this rectangle & the target like [`Rectangle::lerp`], with `t` passed through `easing` first.
+    ///
+    /// # Example
+    /// ```
+    /// use rect_lib::{BasicRectangle, Easing, Rectangle, RectangleRead};
+    ///
+    /// let rect = BasicRectangle::new_from_sides(0, 2, 2, 0);
+    /// let target = BasicRectangle::new_from_sides(100, 102, 2, 0);
+    /// assert_eq!(rect.ease_to(&target, 0.5, Easing::EaseIn).left(), 25);
+    /// assert_eq!(rect.ease_to(&target, 0.5, Easing::EaseOut).left(), 75);
+    /// ```
+    fn ease_to(
+        &self,
+        target: &impl RectangleRead<Unit = Self::Unit>,
+        t: f64,
+        easing: Easing,
+    ) -> Self
+    where
+        Self::Unit: NumCast,
+    {
+        self.lerp(target, easing.apply(t))
+    }
+
+    /// Linearly interpolates only the size between this rectangle & the target, keeping this rectangle's center.
+    /// `t` is clamped to `0.0..=1.0` & for integer units the width & height are rounded to the nearest unit.
+    ///
+    /// When the size changes by an odd amount the right & bottom sides take the extra unit,
+    /// so the center can drift by half a unit.
+    ///
+    /// # Panics
+    /// In debug builds, if growing around the center puts a side past the limits of the unit,
+    /// like an unsigned rectangle at zero growing to the left.
+    ///
+    /// # Example
+    /// ```
+    /// use rect_lib::{BasicRectangle, Rectangle, RectangleRead};
+    ///
+    /// let rect = BasicRectangle::new_from_sides(4, 6, 6, 4);
+    /// let target = BasicRectangle::new_from_sides(0, 6, 20, 14);
+    /// assert_eq!(rect.lerp_size_centered(&target, 0.5), BasicRectangle::new_from_sides(3, 7, 7, 3));
+    /// ```
+    fn lerp_size_centered(&self, target: &impl RectangleRead<Unit = Self::Unit>, t: f64) -> Self
+    where
+        Self::Unit: NumCast,
+    {
+        let width = lerp_unit(self.width(), target.width(), t);
+        let height = lerp_unit(self.height(), target.height(), t);
+        resize_centered(self, width, height)
+    }
+
+    /// Rounds each side to the nearest multiple of `step`, for putting rectangles on a coarser lattice every client agrees on.
+    ///
+    /// Ties round up towards positive infinity, so the result only depends on where a side is relative to the lattice
+    /// & moving a rectangle by a multiple of `step` moves its quantized version by the same amount.
+    /// The arithmetic is exact for integer & float units alike. Sides round independently, so a small rectangle can collapse to a line.
+    ///
+    /// # Panics
+    /// If `step` isn't positive.
+    ///
+    /// # Example
+    /// ```
+    /// use rect_lib::{BasicRectangle, Rectangle, RectangleRead};
+    ///
+    /// let rect = BasicRectangle::new_from_sides(1, 10, 7, -6);
+    /// assert_eq!(rect.quantize(4), BasicRectangle::new_from_sides(0, 12, 8, -4));
+    /// ```
+    fn quantize(&self, step: Self::Unit) -> Self {
+        let zero = Self::Unit::zero();
+        assert!(step > zero, "the step must be positive");
+
+        let round = |side: Self::Unit| {
+            // the remainder takes the sign of the side, so shift it into `0..step` to round negative sides the same way
+            let mut remainder = side % step;
+            if remainder < zero {
+                remainder = remainder + step;
+            }
+            let below = side - remainder;
+            if remainder + remainder >= step {
+                below + step
+            } else {
+                below
+            }
+        };
+
+        Self::new_from_sides(
+            round(self.left()),
+            round(self.right()),
+            round(self.top()),
+            round(self.bottom()),
+        )
+    }
+
+    /// Translates the rectangle so its edges land on whole pixels, for crisp rendering with float units.
+    ///
+    /// The center is moved to the nearest whole coordinate if the size (rounded to a whole pixel) is even along that axis,
+    /// or the nearest half coordinate if it is odd. The size isn't changed, so edges only land exactly on pixels for whole sizes.
+    /// Integer units are already on the grid & come back unchanged.
+    ///
+    /// # Example
+    /// ```
+    /// use rect_lib::{Rectangle, RectangleRead};
+    ///
+    /// #[derive(Clone, Copy, Debug, PartialEq)]
+    /// struct FloatRect([f64; 4]);
+    ///
+    /// impl RectangleRead for FloatRect {
+    ///     type Unit = f64;
+    ///     fn left(&self) -> f64 { self.0[0] }
+    ///     fn right(&self) -> f64 { self.0[1] }
+    ///     fn top(&self) -> f64 { self.0[2] }
+    ///     fn bottom(&self) -> f64 { self.0[3] }
+    /// }
+    ///
+    /// impl Rectangle for FloatRect {
+    ///     fn new_from_sides(left: f64, right: f64, top: f64, bottom: f64) -> Self {
+    ///         Self([left, right, top, bottom])
+    ///     }
+    /// }
+    ///
+    /// // 4 wide & 3 tall, so the center goes to a whole x & a half y
+    /// let rect = FloatRect::new_from_sides(0.25, 4.25, 3.25, 0.25);
+    /// assert_eq!(rect.align_center_to_pixel_grid(), FloatRect::new_from_sides(0.0, 4.0, 3.0, 0.0));
+    /// ```
+    fn align_center_to_pixel_grid(&self) -> Self
+    where
+        Self::Unit: NumCast,
+    {
+        // the distance to move along an axis to snap its center
+        let offset = |min: Self::Unit, max: Self::Unit| -> Self::Unit {
+            let Some((min, max)) = min.to_f64().zip(max.to_f64()) else {
+                return Self::Unit::zero();
+            };
+            let center = (min + max) / 2.0;
+            let snapped = if (max - min).round() % 2.0 == 0.0 {
+                center.round()
+            } else {
+                (center - 0.5).round() + 0.5
+            };
+            NumCast::from(snapped - center).unwrap_or(Self::Unit::zero())
+        };
+
+        self.translate(
+            offset(self.left(), self.right()),
+            offset(self.bottom(), self.top()),
+        )
+    }
+
+    /// Adjusts the size to the nearest of the [`COMMON_ASPECT_RATIOS`], keeping the center.
+    ///
+    /// The nearest ratio is picked by how many times wider or taller it is, then either the width or height is changed,
+    /// whichever needs the smaller change. Like [`Rectangle::lerp_size_centered`] the right & bottom sides take any odd unit.
+    /// For integer units the changed side is rounded to a whole unit, float units hit the ratio exactly.
+    /// Rectangles with no width or height are returned unchanged.
+    ///
+    /// # Panics
+    /// In debug builds, if growing around the center puts a side past the limits of the unit,
+    /// like an unsigned rectangle at zero growing to the left.
+    ///
+    /// # Example
+    /// ```
+    /// use rect_lib::{BasicRectangle, Rectangle, RectangleRead};
+    ///
+    /// // 30:21 is closest to 3:2
+    /// let rect = BasicRectangle::new_from_sides(0, 30, 21, 0);
+    /// assert_eq!(rect.snap_to_common_aspect(), BasicRectangle::new_from_sides(0, 30, 21, 1));
+    /// ```
+    fn snap_to_common_aspect(&self) -> Self
+    where
+        Self::Unit: NumCast,
+    {
+        let (Some(width), Some(height)) = (self.width().to_f64(), self.height().to_f64()) else {
+            return *self;
+        };
+        if width <= 0.0 || height <= 0.0 {
+            return *self;
+        }
+
+        // compare in log space so 2:1 & 1:2 are equally far from 1:1
+        let aspect = (width / height).ln();
+        let distance = |ratio: f64| (aspect - ratio.ln()).abs();
+        let ratio = COMMON_ASPECT_RATIOS
+            .iter()
+            .flat_map(|&(w, h)| [w as f64 / h as f64, h as f64 / w as f64])
+            .min_by(|a, b| distance(*a).total_cmp(&distance(*b)))
+            .unwrap_or(1.0);
+
+        // keep whichever side needs the other to change the least
+        let round = |value: f64| {
+            if integer_unit::<Self::Unit>() {
+                value.round()
+            } else {
+                value
+            }
+        };
+        let new_height = round(width / ratio);
+        let new_width = round(height * ratio);
+        let (new_width, new_height) = if (new_height - height).abs() <= (new_width - width).abs() {
+            (width, new_height)
+        } else {
+            (new_width, height)
+        };
+        let (Some(new_width), Some(new_height)) = (
+            <Self::Unit as NumCast>::from(new_width),
+            <Self::Unit as NumCast>::from(new_height),
+        ) else {
+            return *self;
+        };
+        resize_centered(self, new_width, new_height)
+    }
+
+    /// Shrinks the rectangle around its center until it overlaps none of the obstructions.
+    ///
+    /// Shrinking moves opposite sides in by the same amount, so the center never moves.
+    /// The result is the largest such shrink by area, with `preserve_aspect` the width & height shrink by the same fraction
+    /// (rounded down to whole units), found with a binary search on that fraction.
+    /// Without it only the horizontal insets where an obstruction stops overlapping can do best, so each of those is tried
+    /// with a binary search for the least vertical inset.
+    /// Returns `None` if even the center point (or points, when the size is odd) is obstructed.
+    ///
+    /// The fractions & insets are worked out in `i128`, so large rectangles don't overflow.
+    ///
+    /// # Example
+    /// ```
+    /// use rect_lib::{BasicRectangle, Rectangle, RectangleRead};
+    ///
+    /// let focus = BasicRectangle::new_from_sides(0, 10, 10, 0);
+    /// let overlay = BasicRectangle::new_from_sides(8, 12, 12, 8);
+    /// assert_eq!(focus.shrink_to_avoid(&[&overlay], true), Some(BasicRectangle::new_from_sides(3, 7, 7, 3)));
+    /// ```
+    fn shrink_to_avoid(
+        &self,
+        obstructions: &[&impl RectangleRead<Unit = Self::Unit>],
+        preserve_aspect: bool,
+    ) -> Option<Self>
+    where
+        Self::Unit: NumCast,
+    {
+        let (zero, one) = (Self::Unit::zero(), Self::Unit::one());
+        let two = one + one;
+        let shrink = |inset_x: Self::Unit, inset_y: Self::Unit| {
+            Self::new_from_sides(
+                self.left() + inset_x,
+                self.right() - inset_x,
+                self.top() - inset_y,
+                self.bottom() + inset_y,
+            )
+        };
+        let clear = |rect: &Self| {
+            !obstructions
+                .iter()
+                .any(|obstruction| rect.overlaps(*obstruction))
+        };
+
+        // the smallest inset in `low..=high` passing the check, which must only get easier as the inset grows
+        let search =
+            |mut low: Self::Unit, mut high: Self::Unit, check: &dyn Fn(Self::Unit) -> bool| {
+                if !check(high) {
+                    return None;
+                }
+                while low < high {
+                    let middle = low + (high - low) / two;
+                    if check(middle) {
+                        high = middle;
+                    } else {
+                        low = middle + one;
+                    }
+                }
+                Some(high)
+            };
+
+        let (max_x, max_y) = (self.width() / two, self.height() / two);
+        // an inverted rectangle holds no points, so there is nothing to shrink & nothing it can overlap
+        if max_x < zero || max_y < zero {
+            return Some(*self);
+        }
+        let wide = |unit: Self::Unit| unit.to_i128();
+        // an inset between zero & `max`, worked out in i128 so it fits back in the unit
+        let narrow = |inset: i128, max: Self::Unit| -> Self::Unit {
+            NumCast::from(inset.clamp(0, wide(max).unwrap_or(0))).unwrap_or(zero)
+        };
+
+        if preserve_aspect {
+            // step `s` of `steps` insets both axes by the same fraction
+            let steps = partial_max(max_x, max_y);
+            let (Some(wide_x), Some(wide_y), Some(wide_steps)) =
+                (wide(max_x), wide(max_y), wide(steps))
+            else {
+                return clear(self).then_some(*self);
+            };
+            if wide_steps <= 0 {
+                return clear(self).then_some(*self);
+            }
+            let at = |step: Self::Unit| {
+                let step = wide(step).unwrap_or(0);
+                shrink(
+                    narrow(wide_x * step / wide_steps, max_x),
+                    narrow(wide_y * step / wide_steps, max_y),
+                )
+            };
+            let step = search(zero, steps, &|step| clear(&at(step)))?;
+            return Some(at(step));
+        }
+
+        // the least vertical inset only drops where an obstruction stops overlapping horizontally,
+        // & between those more horizontal inset only loses area, so those insets are the only ones worth trying
+        let mut insets_x = vec![zero];
+        if let (Some(left), Some(right)) = (wide(self.left()), wide(self.right())) {
+            for obstruction in obstructions {
+                let (Some(obstruction_left), Some(obstruction_right)) =
+                    (wide(obstruction.left()), wide(obstruction.right()))
+                else {
+                    continue;
+                };
+                insets_x.push(narrow(obstruction_right + 1 - left, max_x));
+                insets_x.push(narrow(right + 1 - obstruction_left, max_x));
+            }
+        }
+        insets_x.sort_by(|a, b| a.partial_cmp(b).unwrap_or(core::cmp::Ordering::Equal));
+        insets_x.dedup();
+
+        let mut best: Option<Self> = None;
+        for inset_x in insets_x {
+            let Some(inset_y) = search(zero, max_y, &|inset_y| clear(&shrink(inset_x, inset_y)))
+            else {
+                continue;
+            };
+            let rect = shrink(inset_x, inset_y);
+            if best.is_none_or(|best| points_in_wide(&rect) > points_in_wide(&best)) {
+                best = Some(rect);
+            }
+            // insetting further only loses area once the full height fits
+            if inset_y == zero {
+                break;
+            }
+        }
+        best
+    }
+
+    /// Creates `count` evenly spaced rectangles transitioning from this rectangle to the target.
+    /// The rectangles are interpolated with `lerp` & neither end is included.
+    ///
+    /// # Example
+    /// ```
+    /// use rect_lib::{BasicRectangle, Rectangle, RectangleRead};
+    ///
+    /// let rect = BasicRectangle::new_from_sides(0, 0, 0, 0);
+    /// let target = BasicRectangle::new_from_sides(0, 3, 3, 0);
+    /// assert_eq!(
+    ///     rect.interpolate_to(&target, 2),
+    ///     vec![
+    ///         BasicRectangle::new_from_sides(0, 1, 1, 0),
+    ///         BasicRectangle::new_from_sides(0, 2, 2, 0),
+    ///     ]
+    /// );
+    /// ```
+    fn interpolate_to(
+        &self,
+        target: &impl RectangleRead<Unit = Self::Unit>,
+        count: usize,
+    ) -> Vec<Self>
+    where
+        Self::Unit: NumCast,
+    {
+        (1..=count)
+            .map(|i| self.lerp(target, i as f64 / (count + 1) as f64))
+            .collect()
+    }
+
+    /// This algorithm identifies all unique unobstructed sub-rectangles within a given rectangle by comparing it against a list of obstructions.
+    ///
+    /// The obstructions can be in any order, overlap, repeat, or reach outside the rectangle, see [`normalize_obstructions`].
+    /// Inverted obstructions block nothing, & an inverted rectangle has no subrectangles.
+    ///
+    /// # Panics
+    /// In debug builds, if the right side is the largest value of the unit, as the sweep steps one past it.
+    ///
+    /// # Example
+    /// ```
+    /// use rect_lib::{BasicRectangle, Rectangle, RectangleRead};
+    ///
+    /// let rect = BasicRectangle::new_from_sides(0, 5, 5, 0);
+    /// let obstruction = BasicRectangle::new_from_sides(0, 2, 5, 1);
+    /// let subrects = rect.unobstructed_subrectangles(&vec![&obstruction]);
+    ///
+    /// assert_eq!(subrects.len(), 2);
+    /// assert!(subrects.iter().all(|r| [
+    ///     BasicRectangle::new_from_sides(0, 5, 0, 0),
+    ///     BasicRectangle::new_from_sides(3, 5, 5, 0)
+    /// ].contains(r)));
+    /// ```
+    fn unobstructed_subrectangles(
+        &self,
+        obstructions: &[&impl RectangleRead<Unit = Self::Unit>],
+    ) -> Vec<Self>
+    where
+        Self::Unit: Ord,
+    {
+        self.unobstructed_subrectangles_with(obstructions, &UnobstructedOptions::default())
+            .0
+    }
+
+    /// The same as `unobstructed_subrectangles`, but clears `out` & fills it instead of allocating a new `Vec`.
+    /// Reusing one buffer every frame keeps its capacity, so a steady scene doesn't allocate.
+    ///
+    /// # Example
+    /// ```
+    /// use rect_lib::{BasicRectangle, Rectangle, RectangleRead};
+    ///
+    /// let rect = BasicRectangle::new_from_sides(0, 5, 5, 0);
+    /// let obstruction = BasicRectangle::new_from_sides(0, 2, 5, 1);
+    ///
+    /// let mut out = Vec::new();
+    /// for _frame in 0..3 {
+    ///     rect.unobstructed_subrectangles_into(&[&obstruction], &mut out);
+    ///     assert_eq!(out.len(), 2);
+    /// }
+    /// ```
+    fn unobstructed_subrectangles_into(
+        &self,
+        obstructions: &[&impl RectangleRead<Unit = Self::Unit>],
+        out: &mut Vec<Self>,
+    ) where
+        Self::Unit: Ord,
+    {
+        out.clear();
+        unobstructed_sweep_line::sweep(self, obstructions, |rect| {
+            out.push(rect);
+            ControlFlow::Continue(())
+        });
+    }
+
+    /// The same as `unobstructed_subrectangles`, telling `observer` about every line, gap, & rectangle the sweep goes through.
+    /// The results are identical, this only exists to see what the sweep did, e.g. with a [`RecordingObserver`].
+    ///
+    /// Only available with the `trace` feature.
+    #[cfg(feature = "trace")]
+    fn unobstructed_subrectangles_traced(
+        &self,
+        obstructions: &[&impl RectangleRead<Unit = Self::Unit>],
+        observer: &mut impl SweepObserver<Self::Unit>,
+    ) -> Vec<Self>
+    where
+        Self::Unit: Ord,
+    {
+        let mut unique_rectangles = Vec::new();
+        unobstructed_sweep_line::sweep_observed(self, obstructions, observer, |rect| {
+            unique_rectangles.push(rect);
+            ControlFlow::Continue(())
+        });
+        unique_rectangles
+    }
+
+    /// A bounded version of `unobstructed_subrectangles` for real-time use.
+    /// At most `max_results` rectangles are returned, along with whether any were dropped.
+    ///
+    /// The `strategy` picks which rectangles to keep, see [`BudgetStrategy`].
+    /// With `LargestArea` only the best candidates are held during the sweep, & ties go to the first found.
+    ///
+    /// # Example
+    /// ```
+    /// use rect_lib::{BasicRectangle, BudgetStrategy, Rectangle, RectangleRead};
+    ///
+    /// let rect = BasicRectangle::new_from_sides(0, 5, 5, 0);
+    /// let obstruction = BasicRectangle::new_from_sides(0, 2, 5, 1);
+    /// let (subrects, truncated) =
+    ///     rect.unobstructed_subrectangles_budgeted(&[&obstruction], 1, BudgetStrategy::LargestArea);
+    ///
+    /// assert_eq!(subrects, vec![BasicRectangle::new_from_sides(3, 5, 5, 0)]);
+    /// assert!(truncated);
+    /// ```
+    fn unobstructed_subrectangles_budgeted(
+        &self,
+        obstructions: &[&impl RectangleRead<Unit = Self::Unit>],
+        max_results: usize,
+        strategy: BudgetStrategy,
+    ) -> (Vec<Self>, bool)
+    where
+        Self::Unit: Ord,
+    {
+        let options = UnobstructedOptions::default().with_budget(max_results, strategy);
+        self.unobstructed_subrectangles_with(obstructions, &options)
+    }
+
+    /// `unobstructed_subrectangles` with extra options, like a minimum size, a budget, or sorted output.
+    /// Returns the rectangles & whether any were dropped for the budget, which is always `false` without one.
+    ///
+    /// See [`UnobstructedOptions`] for what each option does & how they combine.
+    ///
+    /// # Example
+    /// ```
+    /// use rect_lib::{BasicRectangle, Rectangle, RectangleRead, Side, UnobstructedOptions};
+    ///
+    /// let rect = BasicRectangle::new_from_sides(0, 5, 5, 0);
+    /// let obstruction = BasicRectangle::new_from_sides(0, 2, 5, 1);
+    /// let options = UnobstructedOptions::default().with_touching(Side::Top);
+    ///
+    /// let (found, truncated) = rect.unobstructed_subrectangles_with(&[&obstruction], &options);
+    /// assert_eq!(found, vec![BasicRectangle::new_from_sides(3, 5, 5, 0)]);
+    /// assert!(!truncated);
+    /// ```
+    fn unobstructed_subrectangles_with(
+        &self,
+        obstructions: &[&impl RectangleRead<Unit = Self::Unit>],
+        options: &UnobstructedOptions<Self::Unit>,
+    ) -> (Vec<Self>, bool)
+    where
+        Self::Unit: Ord,
+    {
+        unobstructed_sweep_line::with_options(self, obstructions, options)
+    }
+
+    /// Places every piece exactly once so together they exactly cover this rectangle, returning `(piece index, placement)` pairs.
+    ///
+    /// The search backtracks, always filling the lowest leftmost uncovered point & trying the biggest pieces first.
+    /// With `allow_rotation` pieces may also be turned on their side.
+    /// Sizes are measured between inclusive sides like [`RectangleRead::width`], so a piece covering 2x2 points is `Extent::new(1, 1)`.
+    ///
+    /// This is exponential in the worst case, so `node_budget` caps how many placements are tried.
+    /// `None` means there is no tiling or the budget ran out, the placements are sorted by piece index otherwise.
+    ///
+    /// # Example
+    /// ```
+    /// use rect_lib::{BasicRectangle, Extent, Rectangle, RectangleRead};
+    ///
+    /// let alcove = BasicRectangle::new_from_sides(0, 3, 1, 0);
+    /// let sizes = [Extent::new(0, 1), Extent::new(2, 1)];
+    /// let tiling = alcove.tile_with_sizes(&sizes, false, 1000).unwrap();
+    /// assert_eq!(tiling[0], (0, BasicRectangle::new_from_sides(3, 3, 1, 0)));
+    /// assert_eq!(tiling[1], (1, BasicRectangle::new_from_sides(0, 2, 1, 0)));
+    /// ```
+    fn tile_with_sizes(
+        &self,
+        sizes: &[Extent<Self::Unit>],
+        allow_rotation: bool,
+        node_budget: usize,
+    ) -> Option<Vec<(usize, Self)>>
+    where
+        Self::Unit: NumCast,
+    {
+        tiling::tile(self, sizes, allow_rotation, node_budget)
+    }
+
+    /// Finds a straight corridor through unobstructed space connecting `a` & `b` within this rectangle.
+    /// The corridor is a single rectangle overlapping both, at least `min_thickness` units across.
+    ///
+    /// A corridor can only run vertically where `a` & `b` line up on the x axis, or horizontally where they line up on the y axis.
+    /// It spans from the near side of one to the near side of the other, & the widest one is returned.
+    /// `a` & `b` themselves should not be in `obstructions`.
+    ///
+    /// # Example
+    /// ```
+    /// use rect_lib::{BasicRectangle, Rectangle, RectangleRead};
+    ///
+    /// let level = BasicRectangle::new_from_sides(0, 20, 10, 0);
+    /// let a = BasicRectangle::new_from_sides(0, 4, 6, 2);
+    /// let b = BasicRectangle::new_from_sides(12, 16, 8, 4);
+    /// let pillar = BasicRectangle::new_from_sides(6, 7, 6, 5);
+    ///
+    /// let corridor = level.find_corridor(&a, &b, &[&pillar], 1);
+    /// assert_eq!(corridor, Some(BasicRectangle::new_from_sides(4, 12, 4, 4)));
+    /// assert_eq!(level.find_corridor(&a, &b, &[&pillar], 2), None);
+    /// ```
+    fn find_corridor(
+        &self,
+        a: &Self,
+        b: &Self,
+        obstructions: &[&impl RectangleRead<Unit = Self::Unit>],
+        min_thickness: Self::Unit,
+    ) -> Option<Self>
+    where
+        Self::Unit: Ord,
+    {
+        [Axis::Y, Axis::X]
+            .into_iter()
+            .find_map(|along| corridor::straight(self, a, b, obstructions, along, min_thickness))
+    }
+
+    /// Finds an L-shaped corridor through unobstructed space connecting `a` & `b` within this rectangle.
+    /// Returns `(horizontal, vertical)` legs, each `thickness` units across & overlapping at the corner.
+    ///
+    /// Both elbows are tried: leaving `a` horizontally & arriving at `b` vertically first, then the other way around.
+    /// Only the placements where an obstruction or one of the rectangles starts or stops are checked,
+    /// so the work grows with the number of obstructions rather than the size of the rectangles.
+    /// `a` & `b` themselves should not be in `obstructions`.
+    ///
+    /// # Example
+    /// ```
+    /// use rect_lib::{BasicRectangle, Rectangle, RectangleRead};
+    ///
+    /// let level = BasicRectangle::new_from_sides(0, 20, 20, 0);
+    /// let a = BasicRectangle::new_from_sides(0, 4, 18, 14);
+    /// let b = BasicRectangle::new_from_sides(12, 16, 6, 2);
+    /// let no_obstructions: &[&BasicRectangle] = &[];
+    ///
+    /// let (horizontal, vertical) = level.find_l_corridor(&a, &b, no_obstructions, 1).unwrap();
+    /// assert_eq!(horizontal, BasicRectangle::new_from_sides(4, 12, 18, 18));
+    /// assert_eq!(vertical, BasicRectangle::new_from_sides(12, 12, 18, 6));
+    /// ```
+    fn find_l_corridor(
+        &self,
+        a: &Self,
+        b: &Self,
+        obstructions: &[&impl RectangleRead<Unit = Self::Unit>],
+        thickness: Self::Unit,
+    ) -> Option<(Self, Self)> {
+        corridor::l_shaped(self, a, b, obstructions, thickness)
+            .or_else(|| corridor::l_shaped(self, b, a, obstructions, thickness))
+    }
+
+    /// Splits the free space left by the obstructions into connected components, like finding the rooms made by walls.
+    /// Each component is a region of disjoint rectangles, & no two components are connected through free space.
+    ///
+    /// Free space is connected above, below, left, & right, so a diagonal gap between walls doesn't join two rooms.
+    /// See [`RectRegion::components`].
+    ///
+    /// # Example
+    /// ```
+    /// use rect_lib::{BasicRectangle, Rectangle, RectangleRead};
+    ///
+    /// let house = BasicRectangle::new_from_sides(0, 9, 4, 0);
+    /// let wall = BasicRectangle::new_from_sides(4, 4, 4, 0);
+    ///
+    /// let rooms = house.free_components(&[&wall]);
+    /// assert_eq!(rooms.len(), 2);
+    /// assert_eq!(rooms[0].area(), 20);
+    /// assert_eq!(rooms[1].area(), 25);
+    /// ```
+    fn free_components(
+        &self,
+        obstructions: &[&impl RectangleRead<Unit = Self::Unit>],
+    ) -> Vec<RectRegion<Self>>
+    where
+        Self::Unit: Ord,
+    {
+        let walls: RectRegion<Self> = obstructions
+            .iter()
+            .map(|rect| Self::new_from_sides(rect.left(), rect.right(), rect.top(), rect.bottom()))
+            .collect();
+        RectRegion::from(*self).subtract(&walls).components()
+    }
+
+    /// The number of points within this rectangle not covered by any obstruction.
+    ///
+    /// The count is widened to an `i128`, so it is correct even when it doesn't fit in the unit type.
+    ///
+    /// # Example
+    /// ```
+    /// use rect_lib::{BasicRectangle, Rectangle, RectangleRead};
+    ///
+    /// let rect = BasicRectangle::new_from_sides(0, 3, 3, 0);
+    /// let obstruction = BasicRectangle::new_from_sides(2, 9, 9, 2);
+    /// assert_eq!(rect.unobstructed_area(&[&obstruction]), 12);
+    /// ```
+    fn unobstructed_area(&self, obstructions: &[&impl RectangleRead<Unit = Self::Unit>]) -> i128
+    where
+        Self::Unit: Ord + ToPrimitive,
+    {
+        let clipped = normalize_obstructions(
+            self,
+            obstructions.iter().map(|rect| {
+                Self::new_from_sides(rect.left(), rect.right(), rect.top(), rect.bottom())
+            }),
+        );
+        points_in_wide(self) - union_area(&clipped)
+    }
+
+    /// The number of points within this rectangle covered by at least one of the others, each point counted once.
+    /// This is the opposite of [`Rectangle::unobstructed_area`], for "how much of me is covered",
+    /// & together they add up to the points in this rectangle, `(width + 1) * (height + 1)` rather than [`RectangleRead::area`].
+    ///
+    /// It counts points & returns an `i128` to match [`Rectangle::unobstructed_area`], so the two can be added & compared directly.
+    /// The covered points of a large rectangle easily outgrow the unit type, which the widened count stays correct for.
+    ///
+    /// # Example
+    /// ```
+    /// use rect_lib::{BasicRectangle, Rectangle, RectangleRead};
+    ///
+    /// let rect = BasicRectangle::new_from_sides(0, 3, 3, 0);
+    /// let occluders = [
+    ///     BasicRectangle::new_from_sides(-5, 1, 3, 0),
+    ///     BasicRectangle::new_from_sides(0, 1, 9, 2),
+    /// ];
+    /// assert_eq!(rect.total_overlap_points(&occluders), 8);
+    /// ```
+    fn total_overlap_points(&self, others: &[impl RectangleRead<Unit = Self::Unit>]) -> i128
+    where
+        Self::Unit: Ord + ToPrimitive,
+    {
+        let clipped = normalize_obstructions(
+            self,
+            others.iter().map(|rect| {
+                Self::new_from_sides(rect.left(), rect.right(), rect.top(), rect.bottom())
+            }),
+        );
+        clipped
+            .into_iter()
+            .collect::<RectRegion<Self>>()
+            .area_wide()
+    }
+
+    /// The candidate positions for bottom left fill packing: the bottom left corner of this rectangle,
+    /// & for each obstruction the points just right of its bottom right corner & just above its top left corner.
+    ///
+    /// Only points inside this rectangle & clear of every obstruction are kept,
+    /// sorted from bottom to top then left to right without duplicates.
+    ///
+    /// # Example
+    /// ```
+    /// use rect_lib::{BasicRectangle, Rectangle, RectangleRead};
+    ///
+    /// let bin = BasicRectangle::new_from_sides(0, 9, 9, 0);
+    /// let placed = BasicRectangle::new_from_sides(0, 3, 2, 0);
+    /// assert_eq!(bin.anchor_points(&[&placed]), vec![(4, 0), (0, 3)]);
+    /// ```
+    fn anchor_points(
+        &self,
+        obstructions: &[&impl RectangleRead<Unit = Self::Unit>],
+    ) -> Vec<(Self::Unit, Self::Unit)>
+    where
+        Self::Unit: Ord,
+    {
+        let one = Self::Unit::one();
+        let mut anchors: Vec<(Self::Unit, Self::Unit)> = vec![(self.left(), self.bottom())];
+        for obstruction in obstructions {
+            anchors.push((obstruction.right() + one, obstruction.bottom()));
+            anchors.push((obstruction.left(), obstruction.top() + one));
+        }
+
+        anchors.retain(|&(x, y)| {
+            self.contains_point(x, y)
+                && !obstructions
+                    .iter()
+                    .any(|obstruction| obstruction.contains_point(x, y))
+        });
+        anchors.sort_unstable_by_key(|&(x, y)| (y, x));
+        anchors.dedup();
+        anchors
+    }
+
+    /// The largest free rectangle with its bottom left corner at `anchor`, by the number of points it covers,
+    /// or `None` if the anchor is outside this rectangle or obstructed.
+    /// Ties go to the widest rectangle.
+    ///
+    /// # Example
+    /// ```
+    /// use rect_lib::{BasicRectangle, Rectangle, RectangleRead};
+    ///
+    /// let bin = BasicRectangle::new_from_sides(0, 9, 9, 0);
+    /// let placed = BasicRectangle::new_from_sides(0, 3, 2, 0);
+    /// assert_eq!(
+    ///     bin.max_extent_at(&[&placed], (0, 3)),
+    ///     Some(BasicRectangle::new_from_sides(0, 9, 9, 3))
+    /// );
+    /// ```
+    fn max_extent_at(
+        &self,
+        obstructions: &[&impl RectangleRead<Unit = Self::Unit>],
+        anchor: (Self::Unit, Self::Unit),
+    ) -> Option<Self>
+    where
+        Self::Unit: Ord,
+    {
+        let (x, y) = anchor;
+        if !self.contains_point(x, y)
+            || obstructions
+                .iter()
+                .any(|obstruction| obstruction.contains_point(x, y))
+        {
+            return None;
+        }
+
+        // every free rectangle anchored here fits in a maximal one touching the corner of this quadrant
+        let quadrant = Self::new_from_sides(x, self.right(), self.top(), y);
+        quadrant
+            .unobstructed_subrectangles(obstructions)
+            .into_iter()
+            .filter(|free| free.left() == x && free.bottom() == y)
+            .max_by(|a, b| {
+                points_in(a)
+                    .cmp(&points_in(b))
+                    .then(a.width().cmp(&b.width()))
+            })
     }
 }
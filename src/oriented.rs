@@ -0,0 +1,107 @@
+use crate::{Rectangle, RectangleRead};
+
+/// Adapts a rectangle using the y down convention, where the top is numerically smaller than the bottom,
+/// to the y up convention used by the rest of the crate.
+///
+/// With `Y_DOWN` set `top` & `bottom` swap meaning, so the inner rectangle's larger y is presented as the top
+/// & `new_from_sides` hands the sides to the inner rectangle in its own order.
+/// The points covered don't change, so no surface height is needed & every default method works as is.
+/// With `Y_DOWN` unset the rectangle is passed through untouched.
+///
+/// # Example
+/// ```
+/// use rect_lib::{Oriented, Rectangle, RectangleRead};
+///
+/// /// A window rectangle where y grows downwards.
+/// #[derive(Clone, Copy, Debug, PartialEq)]
+/// struct Window {
+///     x: i32,
+///     y: i32,
+///     width: i32,
+///     height: i32,
+/// }
+///
+/// impl RectangleRead for Window {
+///     type Unit = i32;
+///
+///     fn left(&self) -> i32 {
+///         self.x
+///     }
+///
+///     fn right(&self) -> i32 {
+///         self.x + self.width - 1
+///     }
+///
+///     fn top(&self) -> i32 {
+///         self.y
+///     }
+///
+///     fn bottom(&self) -> i32 {
+///         self.y + self.height - 1
+///     }
+/// }
+///
+/// impl Rectangle for Window {
+///     fn new_from_sides(left: i32, right: i32, top: i32, bottom: i32) -> Self {
+///         Self {
+///             x: left,
+///             y: top,
+///             width: right - left + 1,
+///             height: bottom - top + 1,
+///         }
+///     }
+/// }
+///
+/// let screen = Oriented::<_, true>(Window { x: 0, y: 0, width: 10, height: 10 });
+/// let title_bar = Oriented::<_, true>(Window { x: 0, y: 0, width: 10, height: 2 });
+///
+/// let free = screen.unobstructed_subrectangles(&[&title_bar]);
+/// assert_eq!(free, vec![Oriented(Window { x: 0, y: 2, width: 10, height: 8 })]);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Oriented<R, const Y_DOWN: bool>(pub R);
+
+impl<R, const Y_DOWN: bool> Oriented<R, Y_DOWN> {
+    /// Unwraps the inner rectangle.
+    pub fn into_inner(self) -> R {
+        self.0
+    }
+}
+
+impl<R: RectangleRead, const Y_DOWN: bool> RectangleRead for Oriented<R, Y_DOWN> {
+    type Unit = R::Unit;
+
+    fn left(&self) -> R::Unit {
+        self.0.left()
+    }
+
+    fn right(&self) -> R::Unit {
+        self.0.right()
+    }
+
+    fn top(&self) -> R::Unit {
+        if Y_DOWN {
+            self.0.bottom()
+        } else {
+            self.0.top()
+        }
+    }
+
+    fn bottom(&self) -> R::Unit {
+        if Y_DOWN {
+            self.0.top()
+        } else {
+            self.0.bottom()
+        }
+    }
+}
+
+impl<R: Rectangle, const Y_DOWN: bool> Rectangle for Oriented<R, Y_DOWN> {
+    fn new_from_sides(left: R::Unit, right: R::Unit, top: R::Unit, bottom: R::Unit) -> Self {
+        if Y_DOWN {
+            Self(R::new_from_sides(left, right, bottom, top))
+        } else {
+            Self(R::new_from_sides(left, right, top, bottom))
+        }
+    }
+}
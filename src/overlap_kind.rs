@@ -0,0 +1,16 @@
+/// How two rectangles relate to each other, see [`RectangleRead::overlap_kind`](crate::RectangleRead::overlap_kind).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum OverlapKind {
+    /// The rectangles share no points & no edges.
+    Disjoint,
+    /// The rectangles share no points, but an edge of one lies right next to an edge of the other.
+    Touching,
+    /// The rectangles share some points, but neither contains the other.
+    Overlapping,
+    /// This rectangle contains the other.
+    Contains,
+    /// The other rectangle contains this one.
+    ContainedBy,
+    /// Both rectangles have the same sides.
+    Equal,
+}
@@ -0,0 +1,67 @@
+use crate::{BasicRectangle, Extent, Rectangle};
+
+/// Creates a rectangle of the given size centered on a point.
+/// Odd sizes can't be centered exactly, the extra unit goes to the right & top.
+fn centered_at(size: Extent<i32>, (x, y): (i32, i32)) -> BasicRectangle {
+    let left = x - size.width / 2;
+    let bottom = y - size.height / 2;
+    BasicRectangle::new_from_sides(left, left + size.width, bottom + size.height, bottom)
+}
+
+/// Places a rectangle of a fixed size centered at evenly spaced points along a path.
+///
+/// Each segment of the path is walked from its start, placing a rectangle every `step` units of distance.
+/// A rectangle is always placed on every point of the path, including both ends, so corners are never skipped;
+/// this means the last gap on each segment can be shorter than `step`.
+/// The centers are rounded to the nearest unit, & if `step` is zero or less only the points of the path are used.
+///
+/// Unioning the result gives the region something of that size occupies while moving along the path.
+///
+/// # Example
+/// ```
+/// use rect_lib::{sweep_along_path, BasicRectangle, Extent, Rectangle, RectangleRead};
+///
+/// let rects = sweep_along_path(Extent::new(2, 2), &[(0, 0), (5, 0)], 2);
+/// assert_eq!(
+///     rects,
+///     vec![
+///         BasicRectangle::new_from_sides(-1, 1, 1, -1),
+///         BasicRectangle::new_from_sides(1, 3, 1, -1),
+///         BasicRectangle::new_from_sides(3, 5, 1, -1),
+///         BasicRectangle::new_from_sides(4, 6, 1, -1),
+///     ]
+/// );
+/// ```
+pub fn sweep_along_path(size: Extent<i32>, path: &[(i32, i32)], step: i32) -> Vec<BasicRectangle> {
+    let Some(&first) = path.first() else {
+        return Vec::new();
+    };
+
+    let mut rects = vec![centered_at(size, first)];
+
+    for segment in path.windows(2) {
+        let ((x0, y0), (x1, y1)) = (segment[0], segment[1]);
+        let (dx, dy) = ((x1 - x0) as f64, (y1 - y0) as f64);
+        let length = dx.hypot(dy);
+
+        if step > 0 {
+            let mut distance = step as f64;
+            while distance < length {
+                let t = distance / length;
+                let center = (
+                    (x0 as f64 + dx * t).round() as i32,
+                    (y0 as f64 + dy * t).round() as i32,
+                );
+                rects.push(centered_at(size, center));
+                distance += step as f64;
+            }
+        }
+
+        // always include the end of the segment
+        if (x1, y1) != (x0, y0) {
+            rects.push(centered_at(size, (x1, y1)));
+        }
+    }
+
+    rects
+}
@@ -0,0 +1,19 @@
+//! The common parts of the crate, so a single import brings them into scope.
+//!
+//! This holds the traits you need for the methods to resolve, along with the types most code touches.
+//! Free functions & less common types are left out, import those directly.
+//!
+//! # Example
+//! ```
+//! use rect_lib::prelude::*;
+//!
+//! let a = BasicRectangle::new_from_sides(0, 3, 3, 0);
+//! let b = BasicRectangle::new_from_sides(2, 5, 3, 0);
+//! assert!(a.overlaps(&b));
+//! assert_eq!(a.extent_along(Axis::X), 3);
+//!
+//! let region = RectRegion::from(a).union(&RectRegion::from(b));
+//! assert_eq!(region.bounding_box(), Some(BasicRectangle::new_from_sides(0, 5, 3, 0)));
+//! ```
+
+pub use crate::{Axis, BasicRectangle, RectRegion, Rectangle, RectangleRead};
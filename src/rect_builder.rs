@@ -0,0 +1,98 @@
+use crate::{RectError, Rectangle};
+
+/// Builds a rectangle from sides given by name, so they can't be passed in the wrong order.
+///
+/// Every side must be set, [`RectBuilder::build`] checks them before handing them to [`Rectangle::new_from_sides`].
+/// The [`rect!`](crate::rect) macro is shorthand for the same thing.
+///
+/// # Example
+/// ```
+/// use rect_lib::{BasicRectangle, RectBuilder, RectError, Rectangle, RectangleRead};
+///
+/// let rect = RectBuilder::new().left(0).right(5).top(5).bottom(0).build::<BasicRectangle>();
+/// assert_eq!(rect, Ok(BasicRectangle::new_from_sides(0, 5, 5, 0)));
+///
+/// let inverted = RectBuilder::new().left(5).right(0).top(5).bottom(0).build::<BasicRectangle>();
+/// assert_eq!(inverted, Err(RectError::NegativeSize));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RectBuilder<U> {
+    left: Option<U>,
+    right: Option<U>,
+    top: Option<U>,
+    bottom: Option<U>,
+}
+
+impl<U> Default for RectBuilder<U> {
+    fn default() -> Self {
+        Self {
+            left: None,
+            right: None,
+            top: None,
+            bottom: None,
+        }
+    }
+}
+
+impl<U: Copy + PartialOrd> RectBuilder<U> {
+    /// Creates a builder with no sides set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the left side.
+    pub fn left(mut self, left: U) -> Self {
+        self.left = Some(left);
+        self
+    }
+
+    /// Sets the right side.
+    pub fn right(mut self, right: U) -> Self {
+        self.right = Some(right);
+        self
+    }
+
+    /// Sets the top side.
+    pub fn top(mut self, top: U) -> Self {
+        self.top = Some(top);
+        self
+    }
+
+    /// Sets the bottom side.
+    pub fn bottom(mut self, bottom: U) -> Self {
+        self.bottom = Some(bottom);
+        self
+    }
+
+    /// Creates the rectangle.
+    ///
+    /// - `Err(RectError::MissingSide)` means a side was never set.
+    /// - `Err(RectError::NegativeSize)` means the right is left of the left or the top is below the bottom.
+    pub fn build<R: Rectangle<Unit = U>>(self) -> Result<R, RectError> {
+        let (Some(left), Some(right), Some(top), Some(bottom)) =
+            (self.left, self.right, self.top, self.bottom)
+        else {
+            return Err(RectError::MissingSide);
+        };
+        if right < left || top < bottom {
+            return Err(RectError::NegativeSize);
+        }
+        Ok(R::new_from_sides(left, right, top, bottom))
+    }
+}
+
+/// Builds a rectangle from named sides, given in any order, using [`RectBuilder`].
+///
+/// # Example
+/// ```
+/// use rect_lib::{rect, BasicRectangle, Rectangle, RectangleRead};
+///
+/// let rect: BasicRectangle = rect!(left: 0, right: 5, top: 5, bottom: 0).unwrap();
+/// assert_eq!(rect, BasicRectangle::new_from_sides(0, 5, 5, 0));
+/// ```
+#[macro_export]
+macro_rules! rect {
+    ($($side:ident: $value:expr),* $(,)?) => {
+        $crate::RectBuilder::new()$(.$side($value))*.build()
+    };
+}
@@ -0,0 +1,181 @@
+use core::hash::{Hash, Hasher};
+use std::collections::{HashMap, HashSet};
+
+use crate::Rectangle;
+
+/// A 64 bit FNV-1a hasher, unlike the std hasher it isn't seeded so fingerprints are the same every run.
+pub(crate) struct Fnv(pub(crate) u64);
+
+impl Fnv {
+    /// The FNV-1a offset basis every hash starts from.
+    pub(crate) const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+}
+
+impl Hasher for Fnv {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 ^= byte as u64;
+            self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+}
+
+/// The exact bytes of a unit for [`RectangleRead::content_hash`](crate::RectangleRead::content_hash), the same on every platform.
+///
+/// Integers are widened to an `i128` first, so the same value hashes the same whatever its width.
+/// Floats use their bit pattern, so every distinct value hashes differently, `0.0` & `-0.0` as well as each NaN.
+pub trait HashBytes {
+    /// The value as 16 little endian bytes.
+    fn hash_bytes(&self) -> [u8; 16];
+}
+
+macro_rules! hash_bytes_widened {
+    ($($unit:ty),*) => {
+        $(
+            impl HashBytes for $unit {
+                fn hash_bytes(&self) -> [u8; 16] {
+                    (*self as i128).to_le_bytes()
+                }
+            }
+        )*
+    };
+}
+
+hash_bytes_widened!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, usize);
+
+impl HashBytes for u128 {
+    fn hash_bytes(&self) -> [u8; 16] {
+        self.to_le_bytes()
+    }
+}
+
+impl HashBytes for f32 {
+    fn hash_bytes(&self) -> [u8; 16] {
+        (self.to_bits() as u128).to_le_bytes()
+    }
+}
+
+impl HashBytes for f64 {
+    fn hash_bytes(&self) -> [u8; 16] {
+        (self.to_bits() as u128).to_le_bytes()
+    }
+}
+
+/// A set remembering which rectangles have been seen by a 64 bit fingerprint of their sides.
+///
+/// By default only the fingerprints are stored, 8 bytes per rectangle, so two rectangles with the same fingerprint
+/// are wrongly treated as the same. In exact mode the rectangles are kept in a side table & compared on a fingerprint match,
+/// which costs the memory of the rectangles but never gets it wrong.
+///
+/// # Example
+/// ```
+/// use rect_lib::{BasicRectangle, RectFingerprintSet, Rectangle, RectangleRead};
+///
+/// let mut seen = RectFingerprintSet::new();
+/// assert!(seen.insert_if_new(&BasicRectangle::new_from_sides(0, 1, 1, 0)));
+/// assert!(!seen.insert_if_new(&BasicRectangle::new_from_sides(0, 1, 1, 0)));
+/// assert_eq!(seen.len(), 1);
+/// ```
+#[derive(Clone, Debug)]
+pub struct RectFingerprintSet<R: Rectangle> {
+    fingerprints: HashSet<u64>,
+    /// The rectangles behind each fingerprint, only kept in exact mode.
+    side_table: Option<HashMap<u64, Vec<R>>>,
+    key: fn(&R) -> u64,
+}
+
+impl<R: Rectangle> RectFingerprintSet<R>
+where
+    R::Unit: Hash,
+{
+    /// Creates an empty set storing only fingerprints.
+    pub fn new() -> Self {
+        Self {
+            fingerprints: HashSet::new(),
+            side_table: None,
+            key: Self::fingerprint,
+        }
+    }
+
+    /// Creates an empty set that also keeps the rectangles, so fingerprint collisions are caught.
+    pub fn exact() -> Self {
+        Self {
+            side_table: Some(HashMap::new()),
+            ..Self::new()
+        }
+    }
+
+    /// The stable 64 bit fingerprint of a rectangle's sides, the same on every run.
+    pub fn fingerprint(rect: &R) -> u64 {
+        let mut hasher = Fnv(Fnv::OFFSET_BASIS);
+        (rect.left(), rect.right(), rect.top(), rect.bottom()).hash(&mut hasher);
+        hasher.finish()
+    }
+}
+
+impl<R: Rectangle> Default for RectFingerprintSet<R>
+where
+    R::Unit: Hash,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<R: Rectangle> RectFingerprintSet<R> {
+    /// Replaces the fingerprint function, mostly useful for testing collisions.
+    pub fn with_key(mut self, key: fn(&R) -> u64) -> Self {
+        self.key = key;
+        self
+    }
+
+    /// Adds the rectangle, returning `true` if it hadn't been seen before.
+    pub fn insert_if_new(&mut self, rect: &R) -> bool {
+        let fingerprint = (self.key)(rect);
+        let Some(side_table) = &mut self.side_table else {
+            return self.fingerprints.insert(fingerprint);
+        };
+
+        let same_sides = |other: &R| {
+            (other.left(), other.right(), other.top(), other.bottom())
+                == (rect.left(), rect.right(), rect.top(), rect.bottom())
+        };
+        let rects = side_table.entry(fingerprint).or_default();
+        if rects.iter().any(same_sides) {
+            return false;
+        }
+        rects.push(*rect);
+        self.fingerprints.insert(fingerprint);
+        true
+    }
+
+    /// The number of distinct rectangles seen.
+    /// In approximate mode rectangles sharing a fingerprint only count once.
+    pub fn len(&self) -> usize {
+        match &self.side_table {
+            Some(side_table) => side_table.values().map(Vec::len).sum(),
+            None => self.fingerprints.len(),
+        }
+    }
+
+    /// Checks if no rectangles have been seen.
+    pub fn is_empty(&self) -> bool {
+        self.fingerprints.is_empty()
+    }
+
+    /// The number of fingerprints the set can hold without reallocating.
+    pub fn capacity(&self) -> usize {
+        self.fingerprints.capacity()
+    }
+
+    /// The number of rectangles kept in the side table, always 0 in approximate mode.
+    pub fn stored_rects(&self) -> usize {
+        self.side_table
+            .as_ref()
+            .map_or(0, |side_table| side_table.values().map(Vec::len).sum())
+    }
+}
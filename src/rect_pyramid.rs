@@ -0,0 +1,407 @@
+use num::{NumCast, ToPrimitive};
+
+use crate::{canonical_key, Rectangle, RectangleRead};
+
+/// How much of a region is covered by the rectangles in a [`RectPyramid`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Occupancy {
+    /// No part of the region is covered.
+    Empty,
+    /// Every point in the region is covered.
+    Full,
+    /// Some points in the region are covered & some are not.
+    Partial,
+}
+
+/// One level of the pyramid, a grid of equally sized cells over the bounds.
+///
+/// Cells are indexed by column from the left & row from the bottom, & stored row-major.
+/// Offsets are counted in `i128` from the bottom left of the bounds, so any integer unit fits.
+#[derive(Debug)]
+struct Level {
+    columns: usize,
+    rows: usize,
+    cell_w: i128,
+    cell_h: i128,
+    occupancy: Vec<Occupancy>,
+}
+
+impl Level {
+    fn get(&self, column: usize, row: usize) -> Occupancy {
+        self.occupancy[row * self.columns + column]
+    }
+}
+
+/// A multi-resolution occupancy pyramid for answering "is anything in this region?" cheaply.
+///
+/// Level 0 is a single cell covering the bounds, each level below it is a grid with cells half as wide & tall,
+/// so every cell is split into (up to) four cells on the next level.
+/// Queries start at the coarsest level & only descend into partially occupied cells, so empty or full areas are answered early.
+/// Partial cells on the deepest level are checked against the rectangles directly, so queries are always exact.
+///
+/// Rectangles outside of the bounds are ignored & queries are clipped to the bounds.
+///
+/// # Example
+/// ```
+/// use rect_lib::{BasicRectangle, Occupancy, RectPyramid, Rectangle, RectangleRead};
+///
+/// let bounds = BasicRectangle::new_from_sides(0, 7, 7, 0);
+/// let rects = [BasicRectangle::new_from_sides(0, 3, 7, 4)];
+/// let pyramid = RectPyramid::new(bounds, &rects, 3);
+///
+/// assert_eq!(pyramid.query(&BasicRectangle::new_from_sides(0, 1, 7, 6)), Occupancy::Full);
+/// assert_eq!(pyramid.query(&BasicRectangle::new_from_sides(4, 7, 3, 0)), Occupancy::Empty);
+/// assert_eq!(pyramid.query(&bounds), Occupancy::Partial);
+/// ```
+#[derive(Debug)]
+pub struct RectPyramid<R: Rectangle> {
+    bounds: R,
+    rects: Vec<R>,
+    levels: Vec<Level>,
+}
+
+impl<R: Rectangle> RectPyramid<R> {
+    /// Builds a pyramid over `bounds` with up to `depth` levels below the first.
+    ///
+    /// Every level is rasterized from the rectangles on its own, so building costs `O(rects + cells)` per level.
+    /// A level has up to `4^level` cells, & levels stop once their cells are a single unit.
+    pub fn new(bounds: R, rects: &[R], depth: usize) -> Self
+    where
+        R::Unit: Ord + ToPrimitive,
+    {
+        let wide = |unit: R::Unit| unit.to_i128().unwrap_or(0);
+        // the sides are inclusive so +1
+        let width = wide(bounds.right()) - wide(bounds.left()) + 1;
+        let height = wide(bounds.top()) - wide(bounds.bottom()) + 1;
+        if width <= 0 || height <= 0 {
+            return Self {
+                bounds,
+                rects: rects.to_vec(),
+                levels: Vec::new(),
+            };
+        }
+
+        // no point in going past cells a single unit big
+        let mut deepest = 0;
+        while deepest < depth && (width.max(height) - 1) >> deepest > 0 {
+            deepest += 1;
+        }
+        let cell_size = |length: i128| ((length - 1) >> deepest) + 1;
+        let (finest_w, finest_h) = (cell_size(width), cell_size(height));
+
+        // each rectangle clipped to the bounds, as offsets from the bottom left
+        let clipped: Vec<[i128; 4]> = rects
+            .iter()
+            .filter_map(|rect| {
+                let left = (wide(rect.left()) - wide(bounds.left())).max(0);
+                let right = (wide(rect.right()) - wide(bounds.left())).min(width - 1);
+                let top = (wide(rect.top()) - wide(bounds.bottom())).min(height - 1);
+                let bottom = (wide(rect.bottom()) - wide(bounds.bottom())).max(0);
+                (left <= right && bottom <= top).then_some([left, right, top, bottom])
+            })
+            .collect();
+
+        let mut levels: Vec<Level> = (0..=deepest)
+            .map(|level| {
+                let shift = deepest - level;
+                rasterize(
+                    &clipped,
+                    width,
+                    height,
+                    finest_w << shift,
+                    finest_h << shift,
+                )
+            })
+            .collect();
+
+        // a cell covered by several rectangles together is full if all of its children are
+        for level in (0..deepest).rev() {
+            let (parents, children) = levels.split_at_mut(level + 1);
+            let (parent, child) = (&mut parents[level], &children[0]);
+            for row in 0..parent.rows {
+                for column in 0..parent.columns {
+                    let index = row * parent.columns + column;
+                    if parent.occupancy[index] == Occupancy::Partial
+                        && children_of(child, column, row)
+                            .all(|(column, row)| child.get(column, row) == Occupancy::Full)
+                    {
+                        parent.occupancy[index] = Occupancy::Full;
+                    }
+                }
+            }
+        }
+
+        Self {
+            bounds,
+            rects: rects.to_vec(),
+            levels,
+        }
+    }
+
+    /// The area covered by the pyramid.
+    pub fn bounds(&self) -> R {
+        self.bounds
+    }
+
+    /// The number of rectangles the pyramid was built from, including any outside of its bounds.
+    pub fn len(&self) -> usize {
+        self.rects.len()
+    }
+
+    /// Checks if the pyramid was built from no rectangles.
+    pub fn is_empty(&self) -> bool {
+        self.rects.is_empty()
+    }
+
+    /// Iterates over the rectangles in the order they were passed to [`RectPyramid::new`], whatever the depth.
+    pub fn iter(&self) -> core::slice::Iter<'_, R> {
+        self.rects.iter()
+    }
+
+    /// The rectangles in the canonical rectangle order, see [`RectRegion::iter_sorted`](crate::RectRegion::iter_sorted).
+    pub fn iter_sorted(&self) -> std::vec::IntoIter<&R>
+    where
+        R::Unit: Ord,
+    {
+        let mut rects: Vec<&R> = self.rects.iter().collect();
+        rects.sort_by_key(|rect| canonical_key(*rect));
+        rects.into_iter()
+    }
+
+    /// Checks how much of the given region is covered.
+    ///
+    /// The region is clipped to the bounds of the pyramid, so a region outside of them is `Empty`.
+    pub fn query(&self, region: &impl RectangleRead<Unit = R::Unit>) -> Occupancy
+    where
+        R::Unit: Ord + ToPrimitive + NumCast,
+    {
+        let Some(region) = self.bounds.intersection(region) else {
+            return Occupancy::Empty;
+        };
+        if self.levels.is_empty() {
+            return Occupancy::Empty;
+        }
+
+        let mut query = Query {
+            pyramid: self,
+            region,
+            // only needed if the query reaches a partial cell on the deepest level
+            candidates: None,
+            seen_empty: false,
+            seen_full: false,
+        };
+        query.visit(0, 0, 0);
+
+        match (query.seen_empty, query.seen_full) {
+            (true, true) => Occupancy::Partial,
+            (false, true) => Occupancy::Full,
+            _ => Occupancy::Empty,
+        }
+    }
+}
+
+/// The state of a single [`RectPyramid::query`].
+struct Query<'a, R: Rectangle> {
+    pyramid: &'a RectPyramid<R>,
+    region: R,
+    candidates: Option<Vec<&'a R>>,
+    seen_empty: bool,
+    seen_full: bool,
+}
+
+impl<R: Rectangle> Query<'_, R>
+where
+    R::Unit: Ord + ToPrimitive + NumCast,
+{
+    /// Recursively records whether empty or full areas of the region fall within a cell.
+    fn visit(&mut self, level: usize, column: usize, row: usize) {
+        // once we have seen both the answer is partial
+        if self.seen_empty && self.seen_full {
+            return;
+        }
+
+        let grid = &self.pyramid.levels[level];
+        let Some(region) = self
+            .cell_bounds(grid, column, row)
+            .and_then(|cell| cell.intersection(&self.region))
+        else {
+            return;
+        };
+
+        match grid.get(column, row) {
+            Occupancy::Empty => self.seen_empty = true,
+            Occupancy::Full => self.seen_full = true,
+            Occupancy::Partial if level + 1 == self.pyramid.levels.len() => {
+                // the deepest level, check the region against the rectangles directly
+                let region_of_query = self.region;
+                let candidates = self.candidates.get_or_insert_with(|| {
+                    self.pyramid
+                        .rects
+                        .iter()
+                        .filter(|rect| rect.overlaps(&region_of_query))
+                        .collect()
+                });
+                let obstructions: Vec<&R> = candidates
+                    .iter()
+                    .copied()
+                    .filter(|rect| rect.overlaps(&region))
+                    .collect();
+
+                if obstructions.is_empty() {
+                    self.seen_empty = true;
+                } else if region.unobstructed_subrectangles(&obstructions).is_empty() {
+                    self.seen_full = true;
+                } else {
+                    self.seen_empty = true;
+                    self.seen_full = true;
+                }
+            }
+            Occupancy::Partial => {
+                let children: Vec<_> =
+                    children_of(&self.pyramid.levels[level + 1], column, row).collect();
+                for (column, row) in children {
+                    self.visit(level + 1, column, row);
+                }
+            }
+        }
+    }
+
+    /// The bounds of a cell in the units of the pyramid, cells past the top or right are cut off by the bounds.
+    fn cell_bounds(&self, grid: &Level, column: usize, row: usize) -> Option<R> {
+        let bounds = &self.pyramid.bounds;
+        let wide = |unit: R::Unit| unit.to_i128().unwrap_or(0);
+        let (column, row) = (column as i128, row as i128);
+
+        let left = wide(bounds.left()) + column * grid.cell_w;
+        let right = (left + grid.cell_w - 1).min(wide(bounds.right()));
+        let bottom = wide(bounds.bottom()) + row * grid.cell_h;
+        let top = (bottom + grid.cell_h - 1).min(wide(bounds.top()));
+
+        Some(R::new_from_sides(
+            <R::Unit as NumCast>::from(left)?,
+            <R::Unit as NumCast>::from(right)?,
+            <R::Unit as NumCast>::from(top)?,
+            <R::Unit as NumCast>::from(bottom)?,
+        ))
+    }
+}
+
+/// Rasterizes the clipped rectangles into a grid of `cell_w` by `cell_h` cells.
+///
+/// Two difference arrays count the rectangles touching & fully containing each cell,
+/// so each rectangle costs the same however many cells it covers.
+fn rasterize(
+    clipped: &[[i128; 4]],
+    width: i128,
+    height: i128,
+    cell_w: i128,
+    cell_h: i128,
+) -> Level {
+    let columns = ((width - 1) / cell_w + 1) as usize;
+    let rows = ((height - 1) / cell_h + 1) as usize;
+
+    // one extra row & column so the ends of ranges have somewhere to go
+    let stride = columns + 1;
+    let mut touching = vec![0i64; stride * (rows + 1)];
+    let mut containing = vec![0i64; stride * (rows + 1)];
+    // the range of cells fully inside of `from..=to`, which the last cell is if it reaches the far side
+    let inside = |from: i128, to: i128, cell: i128, length: i128, count: usize| {
+        let first = (from + cell - 1) / cell;
+        let last = if to == length - 1 {
+            count as i128 - 1
+        } else {
+            (to + 1) / cell - 1
+        };
+        (first <= last).then_some((first as usize, last as usize))
+    };
+
+    for &[left, right, top, bottom] in clipped {
+        add(
+            &mut touching,
+            stride,
+            ((left / cell_w) as usize, (right / cell_w) as usize),
+            ((bottom / cell_h) as usize, (top / cell_h) as usize),
+        );
+        if let (Some(columns), Some(rows)) = (
+            inside(left, right, cell_w, width, columns),
+            inside(bottom, top, cell_h, height, rows),
+        ) {
+            add(&mut containing, stride, columns, rows);
+        }
+    }
+
+    // sum the differences up into counts
+    for counts in [&mut touching, &mut containing] {
+        for row in 0..rows {
+            for column in 0..columns {
+                let mut count = counts[row * stride + column];
+                if row > 0 {
+                    count += counts[(row - 1) * stride + column];
+                }
+                if column > 0 {
+                    count += counts[row * stride + column - 1];
+                }
+                if row > 0 && column > 0 {
+                    count -= counts[(row - 1) * stride + column - 1];
+                }
+                counts[row * stride + column] = count;
+            }
+        }
+    }
+
+    let occupancy = (0..rows)
+        .flat_map(|row| (0..columns).map(move |column| row * stride + column))
+        .map(|index| match (touching[index], containing[index]) {
+            (0, _) => Occupancy::Empty,
+            (_, 0) => Occupancy::Partial,
+            _ => Occupancy::Full,
+        })
+        .collect();
+
+    Level {
+        columns,
+        rows,
+        cell_w,
+        cell_h,
+        occupancy,
+    }
+}
+
+/// Adds one to a range of cells in a difference array, given as inclusive `(first, last)` columns & rows.
+fn add(
+    counts: &mut [i64],
+    stride: usize,
+    (first_column, last_column): (usize, usize),
+    (first_row, last_row): (usize, usize),
+) {
+    counts[first_row * stride + first_column] += 1;
+    counts[first_row * stride + last_column + 1] -= 1;
+    counts[(last_row + 1) * stride + first_column] -= 1;
+    counts[(last_row + 1) * stride + last_column + 1] += 1;
+}
+
+/// The cells on the level below that a cell splits into, cut off at the edges of the grid.
+fn children_of(child: &Level, column: usize, row: usize) -> impl Iterator<Item = (usize, usize)> {
+    let columns = (column * 2..(column * 2 + 2).min(child.columns)).collect::<Vec<_>>();
+    (row * 2..(row * 2 + 2).min(child.rows))
+        .flat_map(move |row| columns.clone().into_iter().map(move |column| (column, row)))
+}
+
+impl<R: Rectangle> IntoIterator for RectPyramid<R> {
+    type Item = R;
+    type IntoIter = std::vec::IntoIter<R>;
+
+    /// Takes the rectangles in the order they were passed to [`RectPyramid::new`].
+    fn into_iter(self) -> Self::IntoIter {
+        self.rects.into_iter()
+    }
+}
+
+impl<'a, R: Rectangle> IntoIterator for &'a RectPyramid<R> {
+    type Item = &'a R;
+    type IntoIter = core::slice::Iter<'a, R>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.rects.iter()
+    }
+}
@@ -0,0 +1,695 @@
+use std::collections::BTreeMap;
+
+use num::{One, ToPrimitive, Zero};
+
+use crate::{canonical_key, partial_max, partial_min, points_in, points_in_wide, Rectangle};
+
+/// A region made of disjoint rectangles.
+///
+/// The rectangles are kept in a canonical form: the region is split into vertical strips wherever coverage changes,
+/// then neighbouring strips with the same vertical extent are merged. Two regions covering the same points
+/// always hold the same rectangles, in reading order (top to bottom, then left to right).
+///
+/// Like the rest of the crate the sides are inclusive, so a region is the set of points its rectangles contain.
+/// Inverted rectangles contain no points & are dropped. The operations work with the lines one past each right & top side,
+/// so in debug builds they panic on sides at the largest value of the unit.
+///
+/// # Example
+/// ```
+/// use rect_lib::{BasicRectangle, RectRegion, Rectangle, RectangleRead};
+///
+/// let a = RectRegion::from(BasicRectangle::new_from_sides(0, 3, 3, 0));
+/// let b = RectRegion::from(BasicRectangle::new_from_sides(2, 5, 3, 0));
+///
+/// let union = a.union(&b);
+/// assert_eq!(union.iter().collect::<Vec<_>>(), vec![&BasicRectangle::new_from_sides(0, 5, 3, 0)]);
+///
+/// let difference = a.subtract(&b);
+/// assert_eq!(difference.iter().collect::<Vec<_>>(), vec![&BasicRectangle::new_from_sides(0, 1, 3, 0)]);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct RectRegion<R: Rectangle> {
+    pub(crate) rects: Vec<R>,
+}
+
+impl<R: Rectangle> Default for RectRegion<R> {
+    fn default() -> Self {
+        Self { rects: Vec::new() }
+    }
+}
+
+impl<R: Rectangle> RectRegion<R>
+where
+    R::Unit: Ord,
+{
+    /// Creates an empty region.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Checks if the region covers no points.
+    pub fn is_empty(&self) -> bool {
+        self.rects.is_empty()
+    }
+
+    /// The number of disjoint rectangles making up the region.
+    pub fn len(&self) -> usize {
+        self.rects.len()
+    }
+
+    /// Iterates over the disjoint rectangles making up the region, in reading order.
+    pub fn iter(&self) -> core::slice::Iter<'_, R> {
+        self.rects.iter()
+    }
+
+    /// Iterates over the disjoint rectangles in the canonical rectangle order,
+    /// top to bottom, then left to right, with the bottom & right breaking ties.
+    ///
+    /// The region is kept in this order, so this is the same as [`RectRegion::iter`] & doesn't allocate,
+    /// it is here so code diffing containers can ask for the order explicitly.
+    pub fn iter_sorted(&self) -> core::slice::Iter<'_, R> {
+        debug_assert!(self
+            .rects
+            .windows(2)
+            .all(|pair| canonical_key(&pair[0]) < canonical_key(&pair[1])));
+        self.rects.iter()
+    }
+
+    /// The points covered by either region.
+    pub fn union(&self, other: &Self) -> Self {
+        Self::combine(&self.rects, &other.rects, |a, b| BoolOp::Union.keeps(a, b))
+    }
+
+    /// The points covered by both regions.
+    pub fn intersect(&self, other: &Self) -> Self {
+        Self::combine(&self.rects, &other.rects, |a, b| {
+            BoolOp::Intersection.keeps(a, b)
+        })
+    }
+
+    /// The points covered by this region but not the other.
+    pub fn subtract(&self, other: &Self) -> Self {
+        Self::combine(&self.rects, &other.rects, |a, b| {
+            BoolOp::Difference.keeps(a, b)
+        })
+    }
+
+    /// The points covered by exactly one of the regions.
+    pub fn xor(&self, other: &Self) -> Self {
+        Self::combine(&self.rects, &other.rects, |a, b| BoolOp::Xor.keeps(a, b))
+    }
+
+    /// The same as [`RectRegion::union`], but clears `out` & fills it instead of allocating a new region.
+    pub fn union_into(&self, other: &Self, out: &mut Self) {
+        Self::combine_into(
+            &self.rects,
+            &other.rects,
+            |a, b| BoolOp::Union.keeps(a, b),
+            &mut out.rects,
+        );
+    }
+
+    /// The same as [`RectRegion::intersect`], but clears `out` & fills it instead of allocating a new region.
+    pub fn intersect_into(&self, other: &Self, out: &mut Self) {
+        Self::combine_into(
+            &self.rects,
+            &other.rects,
+            |a, b| BoolOp::Intersection.keeps(a, b),
+            &mut out.rects,
+        );
+    }
+
+    /// The same as [`RectRegion::subtract`], but clears `out` & fills it instead of allocating a new region.
+    ///
+    /// # Example
+    /// ```
+    /// use rect_lib::{BasicRectangle, RectRegion, Rectangle, RectangleRead};
+    ///
+    /// let a = RectRegion::from(BasicRectangle::new_from_sides(0, 3, 3, 0));
+    /// let b = RectRegion::from(BasicRectangle::new_from_sides(2, 5, 3, 0));
+    ///
+    /// // one buffer reused for every frame
+    /// let mut out = RectRegion::new();
+    /// a.subtract_into(&b, &mut out);
+    /// assert_eq!(out, a.subtract(&b));
+    /// ```
+    pub fn subtract_into(&self, other: &Self, out: &mut Self) {
+        Self::combine_into(
+            &self.rects,
+            &other.rects,
+            |a, b| BoolOp::Difference.keeps(a, b),
+            &mut out.rects,
+        );
+    }
+
+    /// The same as [`RectRegion::xor`], but clears `out` & fills it instead of allocating a new region.
+    pub fn xor_into(&self, other: &Self, out: &mut Self) {
+        Self::combine_into(
+            &self.rects,
+            &other.rects,
+            |a, b| BoolOp::Xor.keeps(a, b),
+            &mut out.rects,
+        );
+    }
+
+    /// Checks if the region contains the given point.
+    ///
+    /// # Example
+    /// ```
+    /// use rect_lib::{BasicRectangle, RectRegion, Rectangle, RectangleRead};
+    ///
+    /// let region = RectRegion::from(BasicRectangle::new_from_sides(0, 1, 1, 0));
+    /// assert!(region.contains_point(1, 1));
+    /// assert!(!region.contains_point(2, 1));
+    /// ```
+    pub fn contains_point(&self, x: R::Unit, y: R::Unit) -> bool {
+        self.rects.iter().any(|rect| rect.contains_point(x, y))
+    }
+
+    /// The number of points covered by the region.
+    ///
+    /// Because the sides are inclusive each rectangle covers `(width + 1) * (height + 1)` points,
+    /// which is more than its `area`.
+    ///
+    /// The total is summed in the unit type, in debug builds this panics if it overflows.
+    /// Use [`RectRegion::area_wide`] for regions too large for the unit type.
+    ///
+    /// # Example
+    /// ```
+    /// use rect_lib::{BasicRectangle, RectRegion, Rectangle, RectangleRead};
+    ///
+    /// let region: RectRegion<_> = [
+    ///     BasicRectangle::new_from_sides(0, 1, 1, 0),
+    ///     BasicRectangle::new_from_sides(1, 2, 1, 0),
+    /// ]
+    /// .into_iter()
+    /// .collect();
+    /// assert_eq!(region.area(), 6);
+    /// ```
+    pub fn area(&self) -> R::Unit {
+        self.rects.iter().fold(R::Unit::zero(), |area, rect| {
+            let points = points_in(rect);
+            let total = area + points;
+            // catch wrapping units in debug builds, adding points can't make the total smaller
+            debug_assert!(
+                total >= area && total >= points,
+                "region area overflowed its unit type"
+            );
+            total
+        })
+    }
+
+    /// The number of points covered by the region, summed in an `i128` so it can't overflow the unit type.
+    ///
+    /// # Example
+    /// ```
+    /// use rect_lib::{BasicRectangle, RectRegion, Rectangle, RectangleRead};
+    ///
+    /// let region = RectRegion::from(BasicRectangle::new_from_sides(0, 99_999, 99_999, 0));
+    /// assert_eq!(region.area_wide(), 10_000_000_000);
+    /// ```
+    pub fn area_wide(&self) -> i128
+    where
+        R::Unit: ToPrimitive,
+    {
+        self.rects.iter().map(points_in_wide).sum()
+    }
+
+    /// The smallest rectangle containing the whole region, or `None` if it is empty.
+    pub fn bounding_box(&self) -> Option<R> {
+        let (first, rest) = self.rects.split_first()?;
+        Some(rest.iter().fold(*first, |bounds, rect| {
+            R::new_from_sides(
+                partial_min(bounds.left(), rect.left()),
+                partial_max(bounds.right(), rect.right()),
+                partial_max(bounds.top(), rect.top()),
+                partial_min(bounds.bottom(), rect.bottom()),
+            )
+        }))
+    }
+
+    /// Splits the region into its connected components, in reading order of their first rectangle.
+    ///
+    /// Points are connected to their neighbours above, below, left, & right, so regions only touching diagonally are separate.
+    ///
+    /// # Example
+    /// ```
+    /// use rect_lib::{BasicRectangle, RectRegion, Rectangle, RectangleRead};
+    ///
+    /// let region: RectRegion<_> = [
+    ///     BasicRectangle::new_from_sides(0, 1, 1, 0),
+    ///     BasicRectangle::new_from_sides(2, 3, 0, 0),
+    ///     BasicRectangle::new_from_sides(4, 5, 3, 2),
+    /// ]
+    /// .into_iter()
+    /// .collect();
+    /// assert_eq!(region.components().len(), 2);
+    /// ```
+    pub fn components(&self) -> Vec<Self> {
+        let one = R::Unit::one();
+        // shares an edge, not just a corner
+        let touching = |a: &R, b: &R| {
+            let x_overlap = a.left() <= b.right() && b.left() <= a.right();
+            let y_overlap = a.bottom() <= b.top() && b.bottom() <= a.top();
+            (x_overlap && (a.bottom() == b.top() + one || b.bottom() == a.top() + one))
+                || (y_overlap && (a.left() == b.right() + one || b.left() == a.right() + one))
+        };
+
+        let mut seen = vec![false; self.rects.len()];
+        let mut components = Vec::new();
+        for start in 0..self.rects.len() {
+            if seen[start] {
+                continue;
+            }
+
+            // flood out from the first rectangle not in a component yet
+            seen[start] = true;
+            let mut component = vec![self.rects[start]];
+            let mut stack = vec![start];
+            while let Some(i) = stack.pop() {
+                for (j, rect) in self.rects.iter().enumerate() {
+                    if !seen[j] && touching(&self.rects[i], rect) {
+                        seen[j] = true;
+                        component.push(*rect);
+                        stack.push(j);
+                    }
+                }
+            }
+
+            components.push(component.into_iter().collect());
+        }
+
+        components
+    }
+
+    /// Combines two sets of rectangles, keeping the points where `keep(in_a, in_b)` is true.
+    ///
+    /// The sets may overlap themselves, the result is always in canonical form.
+    pub(crate) fn combine(a: &[R], b: &[R], keep: impl Fn(bool, bool) -> bool) -> Self {
+        let mut rects = Vec::new();
+        Self::combine_into(a, b, keep, &mut rects);
+        Self { rects }
+    }
+
+    /// The same as `combine`, but clears `rects` & fills it with the canonical rectangles.
+    ///
+    /// A sweep from left to right over the lines where rectangles start & stop, keeping how many rectangles of each set cover
+    /// every band between the horizontal lines. Only the strips next to the bands a line changes are looked at again,
+    /// so a line costs about the bands it touches instead of the whole height.
+    pub(crate) fn combine_into(
+        a: &[R],
+        b: &[R],
+        keep: impl Fn(bool, bool) -> bool,
+        rects: &mut Vec<R>,
+    ) {
+        let one = R::Unit::one();
+        rects.clear();
+
+        // the sides of every rectangle, read once, along with the set it is from
+        // inverted rectangles don't contain any points
+        let sides: Vec<(usize, [R::Unit; 4])> = a
+            .iter()
+            .map(|rect| (0, rect))
+            .chain(b.iter().map(|rect| (1, rect)))
+            .map(|(set, rect)| (set, [rect.left(), rect.right(), rect.top(), rect.bottom()]))
+            .filter(|(_, [left, right, top, bottom])| left <= right && bottom <= top)
+            .collect();
+
+        // coverage can only change on these horizontal lines, so every band between two of them is covered all or nothing
+        let mut ys: Vec<R::Unit> = sides
+            .iter()
+            .flat_map(|&(_, [_, _, top, bottom])| [bottom, top + one])
+            .collect();
+        ys.sort_unstable();
+        ys.dedup();
+        let band = |y: R::Unit| ys.partition_point(|&line| line < y);
+
+        // each rectangle covers its bands from its left side until one past its right, as (x, set, bands, added)
+        let mut events: Vec<_> = sides
+            .iter()
+            .flat_map(|&(set, [left, right, top, bottom])| {
+                let bands = (band(bottom), band(top + one));
+                [(left, set, bands, true), (right + one, set, bands, false)]
+            })
+            .collect();
+        events.sort_unstable_by_key(|event| event.0);
+
+        // how many rectangles from each set cover each band
+        let mut counts: Vec<[usize; 2]> = vec![[0, 0]; ys.len().saturating_sub(1)];
+        let kept = |[in_a, in_b]: [usize; 2]| (in_a > 0 || in_b > 0) && keep(in_a > 0, in_b > 0);
+
+        // strips that might continue past the current line, by their first band, as (one past their last band, left)
+        // they are runs of kept bands as long as they can be, so no two touch
+        let mut open: BTreeMap<usize, (usize, R::Unit)> = BTreeMap::new();
+        let mut changed: Vec<(usize, usize)> = Vec::new();
+        let mut runs: Vec<(usize, usize)> = Vec::new();
+
+        let mut events = events.into_iter().peekable();
+        while let Some(&(x, ..)) = events.peek() {
+            changed.clear();
+            while let Some((_, set, (first, last), added)) = events.next_if(|event| event.0 == x) {
+                for count in &mut counts[first..last] {
+                    if added {
+                        count[set] += 1;
+                    } else {
+                        count[set] -= 1;
+                    }
+                }
+                changed.push((first, last));
+            }
+
+            // widen each changed range over the strips overlapping or touching it, so strips are only compared whole
+            for (first, last) in &mut changed {
+                for (&start, &(end, _)) in open.range(..=*last).rev() {
+                    if end < *first {
+                        break;
+                    }
+                    *first = (*first).min(start);
+                    *last = (*last).max(end);
+                }
+            }
+            // a strip can run across touching ranges, so they are looked at together
+            changed.sort_unstable();
+            let mut merged = 0;
+            for i in 1..changed.len() {
+                if changed[i].0 <= changed[merged].1 {
+                    changed[merged].1 = changed[merged].1.max(changed[i].1);
+                } else {
+                    merged += 1;
+                    changed[merged] = changed[i];
+                }
+            }
+            changed.truncate(merged + 1);
+
+            for &(first, last) in &changed {
+                // the strips after the line, the bands either side of the range aren't kept so these are whole
+                runs.clear();
+                let mut band = first;
+                while band < last {
+                    if !kept(counts[band]) {
+                        band += 1;
+                        continue;
+                    }
+                    let start = band;
+                    while band < last && kept(counts[band]) {
+                        band += 1;
+                    }
+                    runs.push((start, band));
+                }
+
+                // close the strips that don't carry on past the line as they were
+                let stopped: Vec<usize> = open
+                    .range(first..last)
+                    .filter(|(&start, &(end, _))| runs.binary_search(&(start, end)).is_err())
+                    .map(|(&start, _)| start)
+                    .collect();
+                for start in stopped {
+                    let (end, left) = open.remove(&start).expect("the strip was just found");
+                    rects.push(R::new_from_sides(left, x - one, ys[end] - one, ys[start]));
+                }
+
+                // open the strips that just started
+                for &(start, end) in &runs {
+                    open.entry(start).or_insert((end, x));
+                }
+            }
+        }
+
+        // the last line is past every rectangle, so everything was closed
+        debug_assert!(open.is_empty());
+
+        rects.sort_unstable_by_key(canonical_key);
+    }
+}
+
+/// The number of points covered by any of the rectangles, counting overlaps once.
+///
+/// The sum is widened to an `i128`, so it is correct even when it doesn't fit in the unit type.
+///
+/// # Example
+/// ```
+/// use rect_lib::{union_area, BasicRectangle, Rectangle, RectangleRead};
+///
+/// let rects = [
+///     BasicRectangle::new_from_sides(0, 1, 1, 0),
+///     BasicRectangle::new_from_sides(1, 2, 1, 0),
+/// ];
+/// assert_eq!(union_area(&rects), 6);
+/// ```
+pub fn union_area<R: Rectangle>(rects: &[R]) -> i128
+where
+    R::Unit: Ord,
+    R::Unit: ToPrimitive,
+{
+    RectRegion::combine(rects, &[], |a, _| a).area_wide()
+}
+
+/// The fraction of the points in `bounds` covered by the rectangles, from `0.0` to `1.0`.
+///
+/// # Example
+/// ```
+/// use rect_lib::{coverage_fraction, BasicRectangle, Rectangle, RectangleRead};
+///
+/// let bounds = BasicRectangle::new_from_sides(0, 3, 3, 0);
+/// let rects = [BasicRectangle::new_from_sides(0, 1, 5, 0)];
+/// assert_eq!(coverage_fraction(&bounds, &rects), 0.5);
+/// ```
+pub fn coverage_fraction<R: Rectangle>(bounds: &R, rects: &[R]) -> f64
+where
+    R::Unit: Ord,
+    R::Unit: ToPrimitive,
+{
+    let clipped: Vec<R> = rects
+        .iter()
+        .filter_map(|rect| bounds.intersection(rect))
+        .collect();
+    union_area(&clipped) as f64 / points_in_wide(bounds) as f64
+}
+
+/// Covers the region with a few large rectangles that may overlap, for when fewer rectangles matter more than a partition.
+///
+/// This is the greedy set cover over the maximal rectangles inside the region:
+/// each step takes the one covering the most points not yet covered, ties going to the first found.
+/// It isn't always the fewest possible, but it never uses more than the disjoint rectangles of the region,
+/// falling back to those when greedy does worse.
+///
+/// With `max_rects` the last rectangle is the bounding box of whatever is still uncovered,
+/// so the region is always covered but points outside of it can be too. A cap of zero is treated as one.
+///
+/// # Example
+/// ```
+/// use rect_lib::{greedy_cover, BasicRectangle, RectRegion, Rectangle, RectangleRead};
+///
+/// // a plus sign is 3 disjoint rectangles, but 2 overlapping bars
+/// let region: RectRegion<_> = [
+///     BasicRectangle::new_from_sides(0, 8, 5, 3),
+///     BasicRectangle::new_from_sides(3, 5, 8, 0),
+/// ]
+/// .into_iter()
+/// .collect();
+/// assert_eq!(region.iter().count(), 3);
+/// assert_eq!(greedy_cover(&region, None).len(), 2);
+/// ```
+pub fn greedy_cover<R: Rectangle>(region: &RectRegion<R>, max_rects: Option<usize>) -> Vec<R>
+where
+    R::Unit: Ord + ToPrimitive,
+{
+    let Some(bounds) = region.bounding_box() else {
+        return Vec::new();
+    };
+    let max_rects = max_rects.map_or(usize::MAX, |max| max.max(1));
+
+    // the maximal rectangles inside the region are the ones avoiding everything else in its bounds
+    let outside = RectRegion::from(bounds).subtract(region);
+    let outside: Vec<&R> = outside.iter().collect();
+    let candidates = bounds.unobstructed_subrectangles(&outside);
+
+    // the uncovered points are kept as disjoint rectangles, so a gain is just the sum of the clipped areas,
+    // & picking a rectangle only takes away the gain of the pieces it newly covers
+    let clipped_area = |rect: &R, pieces: &[R]| -> i128 {
+        pieces
+            .iter()
+            .filter_map(|piece| rect.intersection(piece))
+            .map(|piece| points_in_wide(&piece))
+            .sum()
+    };
+    let mut uncovered = region.rects.clone();
+    let mut gains: Vec<i128> = candidates
+        .iter()
+        .map(|candidate| clipped_area(candidate, &uncovered))
+        .collect();
+
+    let mut cover = Vec::new();
+    let mut newly_covered = Vec::new();
+    let mut remaining = Vec::new();
+    while !uncovered.is_empty() {
+        if cover.len() + 1 == max_rects {
+            // the pieces aren't canonical, but the bounding box doesn't care
+            cover.extend(RectRegion { rects: uncovered }.bounding_box());
+            break;
+        }
+
+        let mut best = 0;
+        for (i, gain) in gains.iter().enumerate().skip(1) {
+            if *gain > gains[best] {
+                best = i;
+            }
+        }
+        let best = candidates[best];
+
+        newly_covered.clear();
+        remaining.clear();
+        for piece in &uncovered {
+            match best.intersection(piece) {
+                Some(covered) => {
+                    newly_covered.push(covered);
+                    remaining.extend(piece.carve_out(&covered));
+                }
+                None => remaining.push(*piece),
+            }
+        }
+        core::mem::swap(&mut uncovered, &mut remaining);
+        for (candidate, gain) in candidates.iter().zip(&mut gains) {
+            *gain -= clipped_area(candidate, &newly_covered);
+        }
+        cover.push(best);
+    }
+
+    if cover.len() > region.rects.len() {
+        return region.rects.clone();
+    }
+    cover
+}
+
+/// Drops the slivers from a region, returning what is left & the number of points dropped so callers can check the loss.
+///
+/// A rectangle of the region is a sliver if it is fewer than `min_width` points wide, fewer than `min_height` points tall,
+/// or covers fewer than `min_area` points. Only whole rectangles are dropped, so the survivors stay disjoint,
+/// though they may be merged back together to keep the region in its canonical form.
+///
+/// # Example
+/// ```
+/// use rect_lib::{drop_slivers, BasicRectangle, RectRegion, Rectangle, RectangleRead};
+///
+/// let region: RectRegion<_> = [
+///     BasicRectangle::new_from_sides(0, 3, 3, 0),
+///     BasicRectangle::new_from_sides(5, 5, 3, 0),
+/// ]
+/// .into_iter()
+/// .collect();
+/// let (kept, dropped) = drop_slivers(region, 2, 2, 0);
+/// assert_eq!(kept, RectRegion::from(BasicRectangle::new_from_sides(0, 3, 3, 0)));
+/// assert_eq!(dropped, 4);
+/// ```
+pub fn drop_slivers<R: Rectangle>(
+    region: RectRegion<R>,
+    min_width: R::Unit,
+    min_height: R::Unit,
+    min_area: R::Unit,
+) -> (RectRegion<R>, R::Unit)
+where
+    R::Unit: Ord,
+{
+    let one = R::Unit::one();
+    let (slivers, kept): (Vec<R>, Vec<R>) = region.rects.into_iter().partition(|rect| {
+        rect.width() + one < min_width
+            || rect.height() + one < min_height
+            || points_in(rect) < min_area
+    });
+
+    let dropped = RectRegion { rects: slivers }.area();
+    (kept.into_iter().collect(), dropped)
+}
+
+/// A boolean operation between two sets of rectangles, see [`boolean_op`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum BoolOp {
+    /// The points covered by either set.
+    Union,
+    /// The points covered by both sets.
+    Intersection,
+    /// The points covered by the first set but not the second.
+    Difference,
+    /// The points covered by exactly one of the sets.
+    Xor,
+}
+
+impl BoolOp {
+    /// Checks if a point is kept, given whether it is in the first & second set.
+    fn keeps(self, in_a: bool, in_b: bool) -> bool {
+        match self {
+            BoolOp::Union => in_a || in_b,
+            BoolOp::Intersection => in_a && in_b,
+            BoolOp::Difference => in_a && !in_b,
+            BoolOp::Xor => in_a != in_b,
+        }
+    }
+}
+
+/// Applies a boolean operation between two sets of rectangles within `bounds`,
+/// returning the result as disjoint rectangles in the canonical form of [`RectRegion`].
+///
+/// The rectangles in each set may overlap each other.
+///
+/// # Example
+/// ```
+/// use rect_lib::{boolean_op, BasicRectangle, BoolOp, Rectangle, RectangleRead};
+///
+/// let bounds = BasicRectangle::new_from_sides(0, 9, 9, 0);
+/// let a = [BasicRectangle::new_from_sides(0, 3, 3, 0)];
+/// let b = [BasicRectangle::new_from_sides(2, 5, 3, 0)];
+///
+/// assert_eq!(
+///     boolean_op(&bounds, &a, &b, BoolOp::Intersection),
+///     vec![BasicRectangle::new_from_sides(2, 3, 3, 0)]
+/// );
+/// ```
+pub fn boolean_op<R: Rectangle>(bounds: &R, a: &[R], b: &[R], op: BoolOp) -> Vec<R>
+where
+    R::Unit: Ord,
+{
+    let result = RectRegion::combine(a, b, |in_a, in_b| op.keeps(in_a, in_b));
+    RectRegion::combine(&result.rects, &[*bounds], |in_result, in_bounds| {
+        in_result && in_bounds
+    })
+    .rects
+}
+
+impl<R: Rectangle> From<R> for RectRegion<R>
+where
+    R::Unit: Ord,
+{
+    fn from(rect: R) -> Self {
+        Self::combine(&[rect], &[], |a, _| a)
+    }
+}
+
+impl<R: Rectangle> IntoIterator for RectRegion<R> {
+    type Item = R;
+    type IntoIter = std::vec::IntoIter<R>;
+
+    /// Takes the disjoint rectangles in reading order.
+    fn into_iter(self) -> Self::IntoIter {
+        self.rects.into_iter()
+    }
+}
+
+impl<'a, R: Rectangle> IntoIterator for &'a RectRegion<R> {
+    type Item = &'a R;
+    type IntoIter = core::slice::Iter<'a, R>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.rects.iter()
+    }
+}
+
+impl<R: Rectangle> FromIterator<R> for RectRegion<R>
+where
+    R::Unit: Ord,
+{
+    /// Creates a region from rectangles that may overlap.
+    fn from_iter<I: IntoIterator<Item = R>>(iter: I) -> Self {
+        let rects: Vec<R> = iter.into_iter().collect();
+        Self::combine(&rects, &[], |a, _| a)
+    }
+}
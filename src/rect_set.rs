@@ -0,0 +1,527 @@
+use num::{One, Zero};
+
+use crate::{partial_max, partial_min, points_in, Axis, RectRegion, Rectangle, RectangleRead};
+
+/// Returns the indices of the rectangles not contained by any other rectangle, in their original order.
+///
+/// When several rectangles are identical only the first is kept.
+///
+/// # Example
+/// ```
+/// use rect_lib::{remove_contained_indices, BasicRectangle, Rectangle, RectangleRead};
+///
+/// let rects = [
+///     BasicRectangle::new_from_sides(1, 2, 2, 1),
+///     BasicRectangle::new_from_sides(0, 3, 3, 0),
+///     BasicRectangle::new_from_sides(5, 6, 6, 5),
+/// ];
+/// assert_eq!(remove_contained_indices(&rects), vec![1, 2]);
+/// ```
+pub fn remove_contained_indices<R: Rectangle>(rects: &[R]) -> Vec<usize> {
+    (0..rects.len())
+        .filter(|&i| {
+            !rects.iter().enumerate().any(|(j, other)| {
+                // identical rectangles contain each other, so only the first one survives
+                j != i
+                    && other.contains_rectangle(&rects[i])
+                    && (j < i || !rects[i].contains_rectangle(other))
+            })
+        })
+        .collect()
+}
+
+/// Removes the rectangles contained by any other rectangle.
+/// This is the owned version of [`remove_contained_indices`].
+///
+/// # Example
+/// ```
+/// use rect_lib::{remove_contained, BasicRectangle, Rectangle, RectangleRead};
+///
+/// let rects = [
+///     BasicRectangle::new_from_sides(1, 2, 2, 1),
+///     BasicRectangle::new_from_sides(0, 3, 3, 0),
+/// ];
+/// assert_eq!(remove_contained(&rects), vec![BasicRectangle::new_from_sides(0, 3, 3, 0)]);
+/// ```
+pub fn remove_contained<R: Rectangle>(rects: &[R]) -> Vec<R> {
+    remove_contained_indices(rects)
+        .into_iter()
+        .map(|i| rects[i])
+        .collect()
+}
+
+/// Groups the rectangles into clusters connected by overlaps, returning the indices in each cluster.
+///
+/// Clusters are ordered by their first index & the indices within each cluster are sorted.
+///
+/// # Example
+/// ```
+/// use rect_lib::{cluster_overlapping, BasicRectangle, Rectangle, RectangleRead};
+///
+/// let rects = [
+///     BasicRectangle::new_from_sides(0, 2, 2, 0),
+///     BasicRectangle::new_from_sides(5, 6, 6, 5),
+///     BasicRectangle::new_from_sides(2, 3, 3, 2),
+/// ];
+/// assert_eq!(cluster_overlapping(&rects), vec![vec![0, 2], vec![1]]);
+/// ```
+pub fn cluster_overlapping<R: Rectangle>(rects: &[R]) -> Vec<Vec<usize>> {
+    let mut cluster_of: Vec<Option<usize>> = vec![None; rects.len()];
+    let mut clusters: Vec<Vec<usize>> = Vec::new();
+
+    for start in 0..rects.len() {
+        if cluster_of[start].is_some() {
+            continue;
+        }
+
+        // flood out from the first rectangle not in a cluster yet
+        let id = clusters.len();
+        cluster_of[start] = Some(id);
+        let mut cluster = vec![start];
+        let mut stack = vec![start];
+
+        while let Some(i) = stack.pop() {
+            for j in 0..rects.len() {
+                if cluster_of[j].is_none() && rects[i].overlaps(&rects[j]) {
+                    cluster_of[j] = Some(id);
+                    cluster.push(j);
+                    stack.push(j);
+                }
+            }
+        }
+
+        cluster.sort_unstable();
+        clusters.push(cluster);
+    }
+
+    clusters
+}
+
+/// The overlap graph of the rectangles as weighted edges `(i, j, shared)`, one for each overlapping pair with `i < j`.
+///
+/// The weight is the number of points both rectangles cover, so rectangles sharing only an edge are still connected.
+/// Edges are sorted by `i` then `j`.
+///
+/// # Example
+/// ```
+/// use rect_lib::{overlap_adjacency, BasicRectangle, Rectangle, RectangleRead};
+///
+/// let rects = [
+///     BasicRectangle::new_from_sides(0, 3, 3, 0),
+///     BasicRectangle::new_from_sides(2, 5, 3, 0),
+///     BasicRectangle::new_from_sides(9, 9, 9, 9),
+/// ];
+/// assert_eq!(overlap_adjacency(&rects), vec![(0, 1, 8)]);
+/// ```
+pub fn overlap_adjacency<R: Rectangle>(rects: &[R]) -> Vec<(usize, usize, R::Unit)> {
+    let mut edges = Vec::new();
+    for (i, a) in rects.iter().enumerate() {
+        for (j, b) in rects.iter().enumerate().skip(i + 1) {
+            if let Some(shared) = a.intersection(b) {
+                edges.push((i, j, points_in(&shared)));
+            }
+        }
+    }
+    edges
+}
+
+/// One rectangle produced by [`merge_plan`]: the bounding box of the rectangles at `sources`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MergeStep {
+    /// The indices of the input rectangles merged together, sorted.
+    pub sources: Vec<usize>,
+}
+
+impl MergeStep {
+    /// Replays the step on the given rectangles, returning the merged rectangle.
+    ///
+    /// # Panics
+    /// If `sources` is out of bounds for `rects`.
+    pub fn apply<R: Rectangle>(&self, rects: &[R]) -> R {
+        let first = rects[self.sources[0]];
+        self.sources[1..].iter().fold(first, |merged, &i| {
+            let rect = &rects[i];
+            R::new_from_sides(
+                partial_min(merged.left(), rect.left()),
+                partial_max(merged.right(), rect.right()),
+                partial_max(merged.top(), rect.top()),
+                partial_min(merged.bottom(), rect.bottom()),
+            )
+        })
+    }
+}
+
+/// Checks if the union of two rectangles is exactly a rectangle,
+/// either one contains the other or they line up on one axis & touch or overlap on the other.
+fn mergeable<R: Rectangle>(a: &R, b: &R) -> bool {
+    let one = R::Unit::one();
+    let touch = |a_min: R::Unit, a_max: R::Unit, b_min: R::Unit, b_max: R::Unit| {
+        a_min <= b_max + one && b_min <= a_max + one
+    };
+
+    a.contains_rectangle(b)
+        || b.contains_rectangle(a)
+        || (a.left() == b.left()
+            && a.right() == b.right()
+            && touch(a.bottom(), a.top(), b.bottom(), b.top()))
+        || (a.top() == b.top()
+            && a.bottom() == b.bottom()
+            && touch(a.left(), a.right(), b.left(), b.right()))
+}
+
+/// Plans which rectangles to merge, without copying them.
+///
+/// Rectangles are merged whenever their union is exactly a rectangle, until no more merges are possible.
+/// Each step lists the input indices making up one output rectangle, ordered by their first index,
+/// so callers can replay the plan on their own storage with [`MergeStep::apply`].
+///
+/// # Example
+/// ```
+/// use rect_lib::{merge_plan, BasicRectangle, MergeStep, Rectangle, RectangleRead};
+///
+/// let rects = [
+///     BasicRectangle::new_from_sides(0, 1, 1, 0),
+///     BasicRectangle::new_from_sides(5, 6, 6, 5),
+///     BasicRectangle::new_from_sides(2, 3, 1, 0),
+/// ];
+/// let plan = merge_plan(&rects);
+/// assert_eq!(plan, vec![MergeStep { sources: vec![0, 2] }, MergeStep { sources: vec![1] }]);
+/// assert_eq!(plan[0].apply(&rects), BasicRectangle::new_from_sides(0, 3, 1, 0));
+/// ```
+pub fn merge_plan<R: Rectangle>(rects: &[R]) -> Vec<MergeStep> {
+    let mut groups: Vec<(R, MergeStep)> = rects
+        .iter()
+        .enumerate()
+        .map(|(i, rect)| (*rect, MergeStep { sources: vec![i] }))
+        .collect();
+
+    // keep merging until nothing changes
+    let mut merged_any = true;
+    while merged_any {
+        merged_any = false;
+
+        let mut i = 0;
+        while i < groups.len() {
+            let mut j = i + 1;
+            while j < groups.len() {
+                if mergeable(&groups[i].0, &groups[j].0) {
+                    let (_, other) = groups.remove(j);
+                    let (hull, step) = &mut groups[i];
+                    step.sources.extend(other.sources);
+                    step.sources.sort_unstable();
+                    *hull = step.apply(rects);
+                    merged_any = true;
+                } else {
+                    j += 1;
+                }
+            }
+            i += 1;
+        }
+    }
+
+    groups.into_iter().map(|(_, step)| step).collect()
+}
+
+/// Merges rectangles whenever their union is exactly a rectangle.
+/// This is the owned version of [`merge_plan`].
+///
+/// # Example
+/// ```
+/// use rect_lib::{merge_rects, BasicRectangle, Rectangle, RectangleRead};
+///
+/// let rects = [
+///     BasicRectangle::new_from_sides(0, 1, 1, 0),
+///     BasicRectangle::new_from_sides(0, 1, 3, 2),
+/// ];
+/// assert_eq!(merge_rects(&rects), vec![BasicRectangle::new_from_sides(0, 1, 3, 0)]);
+/// ```
+pub fn merge_rects<R: Rectangle>(rects: &[R]) -> Vec<R> {
+    let mut merged = Vec::new();
+    merge_rects_into(rects, &mut merged);
+    merged
+}
+
+/// The same as [`merge_rects`], but clears `out` & fills it instead of allocating a new `Vec` for the result.
+///
+/// # Example
+/// ```
+/// use rect_lib::{merge_rects, merge_rects_into, BasicRectangle, Rectangle, RectangleRead};
+///
+/// let rects = [
+///     BasicRectangle::new_from_sides(0, 1, 1, 0),
+///     BasicRectangle::new_from_sides(0, 1, 3, 2),
+/// ];
+/// let mut out = Vec::new();
+/// merge_rects_into(&rects, &mut out);
+/// assert_eq!(out, merge_rects(&rects));
+/// ```
+pub fn merge_rects_into<R: Rectangle>(rects: &[R], out: &mut Vec<R>) {
+    out.clear();
+    out.extend(merge_plan(rects).iter().map(|step| step.apply(rects)));
+}
+
+/// Replaces rectangles that may overlap with disjoint ones covering exactly the same points.
+///
+/// Unlike [`merge_rects`] this handles any overlap: the union is swept into the canonical strips of a [`RectRegion`],
+/// then strips whose union is exactly a rectangle are merged. The result is small but not always the fewest possible.
+///
+/// # Example
+/// ```
+/// use rect_lib::{merge_overlapping, BasicRectangle, Rectangle, RectangleRead};
+///
+/// let rects = [
+///     BasicRectangle::new_from_sides(0, 4, 4, 0),
+///     BasicRectangle::new_from_sides(2, 6, 4, 0),
+/// ];
+/// assert_eq!(merge_overlapping(&rects), vec![BasicRectangle::new_from_sides(0, 6, 4, 0)]);
+/// ```
+pub fn merge_overlapping<R: Rectangle>(rects: &[R]) -> Vec<R>
+where
+    R::Unit: Ord,
+{
+    let mut merged = Vec::new();
+    merge_overlapping_into(rects, &mut merged);
+    merged
+}
+
+/// The same as [`merge_overlapping`], but clears `out` & fills it instead of allocating a new `Vec` for the result.
+///
+/// # Example
+/// ```
+/// use rect_lib::{merge_overlapping, merge_overlapping_into, BasicRectangle, Rectangle, RectangleRead};
+///
+/// let rects = [
+///     BasicRectangle::new_from_sides(0, 4, 4, 0),
+///     BasicRectangle::new_from_sides(2, 6, 4, 0),
+/// ];
+/// let mut out = Vec::new();
+/// merge_overlapping_into(&rects, &mut out);
+/// assert_eq!(out, merge_overlapping(&rects));
+/// ```
+pub fn merge_overlapping_into<R: Rectangle>(rects: &[R], out: &mut Vec<R>)
+where
+    R::Unit: Ord,
+{
+    let region: RectRegion<R> = rects.iter().copied().collect();
+    merge_rects_into(&region.rects, out);
+}
+
+/// Pushes overlapping rectangles apart until they are disjoint, returning whether that was reached.
+///
+/// Each iteration visits every pair in index order, & moves any overlapping pair apart along the axis needing the smaller move,
+/// splitting the move between them. Ties go to the x axis, & the later rectangle moves right or up when their centers line up,
+/// so the same input always gives the same layout.
+///
+/// Rectangles are kept at least `padding` units apart, counted like the gap between inclusive sides.
+/// With `bounds` every rectangle is pushed back inside after each move, which can stop a crowded layout from resolving.
+/// Already disjoint rectangles are never moved.
+///
+/// # Example
+/// ```
+/// use rect_lib::{separate_rects, BasicRectangle, Rectangle, RectangleRead};
+///
+/// let mut rects = [
+///     BasicRectangle::new_from_sides(0, 3, 1, 0),
+///     BasicRectangle::new_from_sides(2, 5, 1, 0),
+/// ];
+/// assert!(separate_rects(&mut rects, 0, None, 10));
+/// assert_eq!(
+///     rects,
+///     [BasicRectangle::new_from_sides(-1, 2, 1, 0), BasicRectangle::new_from_sides(3, 6, 1, 0)]
+/// );
+/// ```
+pub fn separate_rects<R: Rectangle>(
+    rects: &mut [R],
+    padding: R::Unit,
+    bounds: Option<&R>,
+    max_iterations: usize,
+) -> bool {
+    let one = R::Unit::one();
+    let two = one + one;
+
+    // how far `b` has to move along each axis to clear `a` & its padding, or `None` if they are already clear
+    let depths = |a: &R, b: &R| -> Option<(R::Unit, R::Unit)> {
+        let (left, right) = (a.left() - padding, a.right() + padding);
+        let (bottom, top) = (a.bottom() - padding, a.top() + padding);
+        if !b.overlaps_sides(left, right, top, bottom) {
+            return None;
+        }
+        Some((
+            partial_min(right - b.left(), b.right() - left) + one,
+            partial_min(top - b.bottom(), b.top() - bottom) + one,
+        ))
+    };
+
+    // moves a rectangle back inside the bounds, if it fits
+    let confine = |rect: R| -> R {
+        let Some(bounds) = bounds else {
+            return rect;
+        };
+        let shift = |min: R::Unit, max: R::Unit, bounds_min: R::Unit, bounds_max: R::Unit| {
+            if min < bounds_min {
+                bounds_min - min
+            } else if max > bounds_max {
+                partial_max(bounds_max - max, bounds_min - min)
+            } else {
+                R::Unit::zero()
+            }
+        };
+        rect.translate(
+            shift(rect.left(), rect.right(), bounds.left(), bounds.right()),
+            shift(rect.bottom(), rect.top(), bounds.bottom(), bounds.top()),
+        )
+    };
+
+    let mut separated = false;
+    for _ in 0..max_iterations {
+        separated = true;
+        for i in 0..rects.len() {
+            for j in i + 1..rects.len() {
+                let Some((depth_x, depth_y)) = depths(&rects[i], &rects[j]) else {
+                    continue;
+                };
+                separated = false;
+
+                let axis = if depth_x <= depth_y { Axis::X } else { Axis::Y };
+                let depth = if axis == Axis::X { depth_x } else { depth_y };
+                // the later rectangle moves towards the larger side, comparing doubled centers avoids rounding
+                let center = |rect: &R| rect.min_along(axis) + rect.max_along(axis);
+                let (first_move, second_move) = (depth / two, depth - depth / two);
+                let (first_move, second_move) = if center(&rects[j]) >= center(&rects[i]) {
+                    (R::Unit::zero() - first_move, second_move)
+                } else {
+                    (first_move, R::Unit::zero() - second_move)
+                };
+
+                rects[i] = confine(rects[i].translate_along(axis, first_move));
+                rects[j] = confine(rects[j].translate_along(axis, second_move));
+            }
+        }
+
+        if separated {
+            return true;
+        }
+    }
+
+    // the last iteration may have finished the job
+    separated
+        || (0..rects.len())
+            .all(|i| (i + 1..rects.len()).all(|j| depths(&rects[i], &rects[j]).is_none()))
+}
+
+/// Clips every rectangle to `clip`, dropping the ones entirely outside of it.
+///
+/// # Example
+/// ```
+/// use rect_lib::{clip_all, BasicRectangle, Rectangle, RectangleRead};
+///
+/// let viewport = BasicRectangle::new_from_sides(0, 9, 9, 0);
+/// let rects = [
+///     BasicRectangle::new_from_sides(-5, 2, 2, -5),
+///     BasicRectangle::new_from_sides(20, 22, 2, 0),
+/// ];
+/// assert_eq!(clip_all(&rects, &viewport), vec![BasicRectangle::new_from_sides(0, 2, 2, 0)]);
+/// ```
+pub fn clip_all<R: Rectangle>(rects: &[R], clip: &impl RectangleRead<Unit = R::Unit>) -> Vec<R> {
+    let mut clipped = Vec::new();
+    clip_all_into(rects, clip, &mut clipped);
+    clipped
+}
+
+/// The same as [`clip_all`], but clears `out` & fills it instead of allocating a new `Vec`.
+///
+/// # Example
+/// ```
+/// use rect_lib::{clip_all, clip_all_into, BasicRectangle, Rectangle, RectangleRead};
+///
+/// let viewport = BasicRectangle::new_from_sides(0, 9, 9, 0);
+/// let rects = [BasicRectangle::new_from_sides(-5, 2, 2, -5)];
+///
+/// let mut out = vec![viewport];
+/// clip_all_into(&rects, &viewport, &mut out);
+/// assert_eq!(out, clip_all(&rects, &viewport));
+/// ```
+pub fn clip_all_into<R: Rectangle>(
+    rects: &[R],
+    clip: &impl RectangleRead<Unit = R::Unit>,
+    out: &mut Vec<R>,
+) {
+    out.clear();
+    out.extend(rects.iter().filter_map(|rect| rect.intersection(clip)));
+}
+
+/// The same as [`clip_all`] for rectangles sorted by their left side, skipping the ones right of `clip` without looking at them.
+///
+/// When most rectangles lie outside of a small clip this is much faster, as the cut off is found with a binary search.
+/// The result is only correct if `rects` are sorted by left.
+///
+/// # Example
+/// ```
+/// use rect_lib::{clip_all_sorted, BasicRectangle, Rectangle, RectangleRead};
+///
+/// let viewport = BasicRectangle::new_from_sides(0, 9, 9, 0);
+/// let rects: Vec<_> = (0..1000).map(|x| BasicRectangle::new_from_sides(x, x + 1, 1, 0)).collect();
+/// assert_eq!(clip_all_sorted(&rects, &viewport).len(), 10);
+/// ```
+pub fn clip_all_sorted<R: Rectangle>(
+    rects: &[R],
+    clip: &impl RectangleRead<Unit = R::Unit>,
+) -> Vec<R> {
+    debug_assert!(rects
+        .windows(2)
+        .all(|pair| pair[0].left() <= pair[1].left()));
+
+    // nothing starting right of the clip can reach it
+    let end = rects.partition_point(|rect| rect.left() <= clip.right());
+    clip_all(&rects[..end], clip)
+}
+
+/// The same as [`clip_all_sorted`], but clears `out` & fills it instead of allocating a new `Vec`.
+///
+/// # Example
+/// ```
+/// use rect_lib::{clip_all_sorted, clip_all_sorted_into, BasicRectangle, Rectangle, RectangleRead};
+///
+/// let viewport = BasicRectangle::new_from_sides(0, 9, 9, 0);
+/// let rects: Vec<_> = (0..1000).map(|x| BasicRectangle::new_from_sides(x, x + 1, 1, 0)).collect();
+/// let mut out = Vec::new();
+/// clip_all_sorted_into(&rects, &viewport, &mut out);
+/// assert_eq!(out, clip_all_sorted(&rects, &viewport));
+/// ```
+pub fn clip_all_sorted_into<R: Rectangle>(
+    rects: &[R],
+    clip: &impl RectangleRead<Unit = R::Unit>,
+    out: &mut Vec<R>,
+) {
+    debug_assert!(rects
+        .windows(2)
+        .all(|pair| pair[0].left() <= pair[1].left()));
+
+    let end = rects.partition_point(|rect| rect.left() <= clip.right());
+    clip_all_into(&rects[..end], clip, out);
+}
+
+/// Clips every rectangle to `clip` in place, removing the ones entirely outside of it.
+/// This is the in-place version of [`clip_all`], reusing the allocation.
+///
+/// # Example
+/// ```
+/// use rect_lib::{retain_clipped, BasicRectangle, Rectangle, RectangleRead};
+///
+/// let viewport = BasicRectangle::new_from_sides(0, 9, 9, 0);
+/// let mut rects = vec![
+///     BasicRectangle::new_from_sides(8, 12, 2, 0),
+///     BasicRectangle::new_from_sides(20, 22, 2, 0),
+/// ];
+/// retain_clipped(&mut rects, &viewport);
+/// assert_eq!(rects, vec![BasicRectangle::new_from_sides(8, 9, 2, 0)]);
+/// ```
+pub fn retain_clipped<R: Rectangle>(rects: &mut Vec<R>, clip: &impl RectangleRead<Unit = R::Unit>) {
+    rects.retain_mut(|rect| match rect.intersection(clip) {
+        Some(clipped) => {
+            *rect = clipped;
+            true
+        }
+        None => false,
+    });
+}
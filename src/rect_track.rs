@@ -0,0 +1,169 @@
+use core::ops::RangeInclusive;
+use num::NumCast;
+
+use crate::{partial_max, partial_min, RectRegion, Rectangle};
+
+/// The rectangle occupied by one object over time, recorded as samples on some frames.
+///
+/// Frames between two samples are interpolated with [`Rectangle::lerp`], so tracks sampled at different rates can be compared.
+/// There is no rectangle before the first sample or after the last one.
+///
+/// # Example
+/// ```
+/// use rect_lib::{BasicRectangle, RectTrack, Rectangle, RectangleRead};
+///
+/// let mut track = RectTrack::new();
+/// track.push(0, BasicRectangle::new_from_sides(0, 1, 1, 0));
+/// track.push(10, BasicRectangle::new_from_sides(10, 11, 1, 0));
+///
+/// assert_eq!(track.at(5), Some(BasicRectangle::new_from_sides(5, 6, 1, 0)));
+/// assert_eq!(track.at(11), None);
+/// assert_eq!(track.hull_between(0, 4), Some(BasicRectangle::new_from_sides(0, 5, 1, 0)));
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct RectTrack<R: Rectangle> {
+    /// Sorted by frame, with at most one sample per frame.
+    samples: Vec<(u32, R)>,
+}
+
+impl<R: Rectangle> Default for RectTrack<R> {
+    fn default() -> Self {
+        Self {
+            samples: Vec::new(),
+        }
+    }
+}
+
+impl<R: Rectangle> RectTrack<R> {
+    /// Creates a track without any samples.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records the rectangle on the given frame, replacing any earlier sample on the same frame.
+    /// Samples can be pushed in any order.
+    pub fn push(&mut self, frame: u32, rect: R) {
+        match self.samples.binary_search_by_key(&frame, |&(f, _)| f) {
+            Ok(i) => self.samples[i].1 = rect,
+            Err(i) => self.samples.insert(i, (frame, rect)),
+        }
+    }
+
+    /// The recorded samples, sorted by frame.
+    pub fn samples(&self) -> &[(u32, R)] {
+        &self.samples
+    }
+
+    /// The first & last sampled frames, or `None` if there are no samples.
+    pub fn frames(&self) -> Option<(u32, u32)> {
+        Some((self.samples.first()?.0, self.samples.last()?.0))
+    }
+}
+
+impl<R: Rectangle> RectTrack<R>
+where
+    R::Unit: NumCast,
+{
+    /// The rectangle on the given frame, interpolated between the samples either side of it.
+    pub fn at(&self, frame: u32) -> Option<R> {
+        let i = self.samples.partition_point(|&(f, _)| f < frame);
+        let &(next_frame, next) = self.samples.get(i)?;
+        if next_frame == frame {
+            return Some(next);
+        }
+
+        let &(previous_frame, previous) = self.samples.get(i.checked_sub(1)?)?;
+        let t = (frame - previous_frame) as f64 / (next_frame - previous_frame) as f64;
+        Some(previous.lerp(&next, t))
+    }
+
+    /// The smallest rectangle containing every rectangle between frames `a` & `b` inclusive,
+    /// or `None` if the track has no rectangle in that range.
+    ///
+    /// Sides move linearly between samples, so only the samples & the ends of the range need checking.
+    pub fn hull_between(&self, a: u32, b: u32) -> Option<R> {
+        let (start, end) = self.clamp(a, b)?;
+
+        let inner = self
+            .samples
+            .iter()
+            .filter(|&&(frame, _)| start < frame && frame < end)
+            .map(|&(_, rect)| rect);
+
+        [self.at(start)?, self.at(end)?]
+            .into_iter()
+            .chain(inner)
+            .reduce(|hull, rect| {
+                R::new_from_sides(
+                    partial_min(hull.left(), rect.left()),
+                    partial_max(hull.right(), rect.right()),
+                    partial_max(hull.top(), rect.top()),
+                    partial_min(hull.bottom(), rect.bottom()),
+                )
+            })
+    }
+
+    /// The points covered on any frame between `a` & `b` inclusive.
+    pub fn union_between(&self, a: u32, b: u32) -> RectRegion<R>
+    where
+        R::Unit: Ord,
+    {
+        let Some((start, end)) = self.clamp(a, b) else {
+            return RectRegion::new();
+        };
+        (start..=end).filter_map(|frame| self.at(frame)).collect()
+    }
+
+    /// The frames between `a` & `b` inclusive where both tracks have overlapping rectangles, as inclusive ranges of consecutive frames.
+    /// The ranges are inclusive so a run can end on the last frame, `u32::MAX`.
+    ///
+    /// # Example
+    /// ```
+    /// use rect_lib::{BasicRectangle, RectTrack, Rectangle, RectangleRead};
+    ///
+    /// let mut moving = RectTrack::new();
+    /// moving.push(0, BasicRectangle::new_from_sides(0, 1, 1, 0));
+    /// moving.push(10, BasicRectangle::new_from_sides(10, 11, 1, 0));
+    ///
+    /// let mut still = RectTrack::new();
+    /// still.push(0, BasicRectangle::new_from_sides(4, 5, 1, 0));
+    /// still.push(20, BasicRectangle::new_from_sides(4, 5, 1, 0));
+    ///
+    /// assert_eq!(moving.overlap_frames(&still, 0, 20), vec![3..=5]);
+    /// assert_eq!(moving.overlap_frames(&still, 4, 20), vec![4..=5]);
+    /// ```
+    pub fn overlap_frames(&self, other: &Self, a: u32, b: u32) -> Vec<RangeInclusive<u32>> {
+        let (Some((start, end)), Some((other_start, other_end))) =
+            (self.clamp(a, b), other.clamp(a, b))
+        else {
+            return Vec::new();
+        };
+
+        let mut ranges = Vec::new();
+        // the first & last frame of the current run
+        let mut current: Option<(u32, u32)> = None;
+        for frame in start.max(other_start)..=end.min(other_end) {
+            let overlapping = self
+                .at(frame)
+                .zip(other.at(frame))
+                .is_some_and(|(a, b)| a.overlaps(&b));
+
+            match (&mut current, overlapping) {
+                (Some((_, last)), true) => *last = frame,
+                (None, true) => current = Some((frame, frame)),
+                (Some(_), false) => ranges.extend(current.take().map(|(first, last)| first..=last)),
+                (None, false) => {}
+            }
+        }
+        ranges.extend(current.map(|(first, last)| first..=last));
+
+        ranges
+    }
+
+    /// Clamps the inclusive range of frames to the sampled frames, or `None` if they don't meet.
+    fn clamp(&self, a: u32, b: u32) -> Option<(u32, u32)> {
+        let (first, last) = self.frames()?;
+        let (start, end) = (a.min(b).max(first), a.max(b).min(last));
+        (start <= end).then_some((start, end))
+    }
+}
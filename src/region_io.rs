@@ -0,0 +1,216 @@
+use core::cmp::Reverse;
+use num::{NumCast, ToPrimitive};
+use std::io::{self, Read, Write};
+
+use crate::{canonical_key, RectRegion, Rectangle, RegionDecodeError};
+
+/// The first bytes of every encoded region.
+const MAGIC: &[u8; 4] = b"RRGN";
+
+/// The newest version of the format, older versions are still read.
+const VERSION: u8 = 1;
+
+/// Appends an unsigned LEB128 varint.
+fn write_varint(bytes: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        bytes.push(value as u8 | 0x80);
+        value >>= 7;
+    }
+    bytes.push(value as u8);
+}
+
+/// Appends a signed varint, zigzagged so small negative numbers stay small.
+fn write_signed(bytes: &mut Vec<u8>, value: i64) {
+    write_varint(bytes, ((value << 1) ^ (value >> 63)) as u64);
+}
+
+fn read_byte(r: &mut impl Read) -> io::Result<u8> {
+    let mut byte = [0];
+    r.read_exact(&mut byte)?;
+    Ok(byte[0])
+}
+
+fn read_varint(r: &mut impl Read) -> Result<u64, RegionDecodeError> {
+    let mut value = 0;
+    // a u64 needs at most 10 bytes
+    for shift in (0..70).step_by(7) {
+        let byte = read_byte(r)?;
+        let bits = (byte & 0x7f) as u64;
+        if shift == 63 && bits > 1 {
+            return Err(RegionDecodeError::BadVarint);
+        }
+        value |= bits << shift;
+        if byte & 0x80 == 0 {
+            return Ok(value);
+        }
+    }
+    Err(RegionDecodeError::BadVarint)
+}
+
+fn read_signed(r: &mut impl Read) -> Result<i64, RegionDecodeError> {
+    let value = read_varint(r)?;
+    Ok((value >> 1) as i64 ^ -((value & 1) as i64))
+}
+
+impl<R: Rectangle> RectRegion<R>
+where
+    R::Unit: NumCast,
+{
+    /// Writes the region in a compact, versioned binary format.
+    ///
+    /// The format is a small header, the number of rectangles, then each rectangle in canonical order as
+    /// the change in top & left from the previous rectangle followed by its width & height, all as varints.
+    /// Neighbouring rectangles are close together, so most rectangles take a handful of bytes.
+    ///
+    /// The whole region is encoded before being written in one go, so `w` doesn't need to be buffered.
+    ///
+    /// # Example
+    /// ```
+    /// use rect_lib::{BasicRectangle, RectRegion, Rectangle, RectangleRead};
+    ///
+    /// let region = RectRegion::from(BasicRectangle::new_from_sides(0, 3, 3, 0));
+    /// let mut bytes = Vec::new();
+    /// region.write_to(&mut bytes).unwrap();
+    ///
+    /// assert_eq!(RectRegion::read_from(bytes.as_slice()).unwrap(), region);
+    /// ```
+    pub fn write_to(&self, mut w: impl Write) -> io::Result<()> {
+        let to_i64 = |unit: R::Unit| {
+            unit.to_i64().ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "region sides don't fit in an i64",
+                )
+            })
+        };
+
+        let mut bytes = Vec::with_capacity(8 + self.rects.len() * 4);
+        bytes.extend_from_slice(MAGIC);
+        bytes.push(VERSION);
+        write_varint(&mut bytes, self.rects.len() as u64);
+
+        let (mut last_top, mut last_left) = (0, 0);
+        for rect in &self.rects {
+            let (top, left) = (to_i64(rect.top())?, to_i64(rect.left())?);
+            write_signed(&mut bytes, top.wrapping_sub(last_top));
+            write_signed(&mut bytes, left.wrapping_sub(last_left));
+            // canonical rectangles are never inverted, so these are never negative
+            write_varint(&mut bytes, to_i64(rect.right())?.wrapping_sub(left) as u64);
+            write_varint(&mut bytes, top.wrapping_sub(to_i64(rect.bottom())?) as u64);
+            (last_top, last_left) = (top, left);
+        }
+
+        w.write_all(&bytes)
+    }
+
+    /// Reads a region written by [`RectRegion::write_to`], from any version of the format so far.
+    ///
+    /// The header, the sides, & the canonical order of the rectangles are validated.
+    /// Reading happens a byte at a time, so wrap files in a `BufReader`.
+    pub fn read_from(r: impl Read) -> Result<Self, RegionDecodeError>
+    where
+        R::Unit: Ord,
+    {
+        Self::read_from_checked(r, false)
+    }
+
+    /// Reads a region like [`RectRegion::read_from`], optionally checking that the rectangles are in canonical form.
+    ///
+    /// Checking makes sure no rectangles overlap & that the strips were merged the way [`RectRegion`] keeps them,
+    /// so regions read this way compare equal to regions built from the same points. It sorts & sweeps the rectangles,
+    /// which is far slower than decoding, so it is only worth it for data from untrusted sources.
+    pub fn read_from_checked(
+        mut r: impl Read,
+        check_canonical: bool,
+    ) -> Result<Self, RegionDecodeError>
+    where
+        R::Unit: Ord,
+    {
+        let mut magic = [0; 4];
+        r.read_exact(&mut magic)?;
+        if &magic != MAGIC {
+            return Err(RegionDecodeError::BadMagic);
+        }
+        let version = read_byte(&mut r)?;
+        if version == 0 || version > VERSION {
+            return Err(RegionDecodeError::UnsupportedVersion(version));
+        }
+
+        let count = read_varint(&mut r)?;
+        // don't trust the count with a huge allocation before any rectangles are read
+        let mut rects: Vec<R> = Vec::with_capacity(count.min(1 << 16) as usize);
+
+        let (mut top, mut left) = (0i64, 0i64);
+        for _ in 0..count {
+            top = top
+                .checked_add(read_signed(&mut r)?)
+                .ok_or(RegionDecodeError::Overflow)?;
+            left = left
+                .checked_add(read_signed(&mut r)?)
+                .ok_or(RegionDecodeError::Overflow)?;
+            let width =
+                i64::try_from(read_varint(&mut r)?).map_err(|_| RegionDecodeError::Overflow)?;
+            let height =
+                i64::try_from(read_varint(&mut r)?).map_err(|_| RegionDecodeError::Overflow)?;
+
+            let unit = |value: Option<i64>| -> Result<R::Unit, RegionDecodeError> {
+                value
+                    .and_then(NumCast::from)
+                    .ok_or(RegionDecodeError::Overflow)
+            };
+            let rect = R::new_from_sides(
+                unit(Some(left))?,
+                unit(left.checked_add(width))?,
+                unit(Some(top))?,
+                unit(top.checked_sub(height))?,
+            );
+
+            if let Some(last) = rects.last() {
+                if (Reverse(last.top()), last.left()) >= (Reverse(rect.top()), rect.left()) {
+                    return Err(RegionDecodeError::Unordered);
+                }
+            }
+            rects.push(rect);
+        }
+
+        if check_canonical {
+            if !disjoint(&rects) {
+                return Err(RegionDecodeError::Overlapping);
+            }
+            // the canonical form is unique, so rebuilding the points has to give the same rectangles back
+            let rebuilt = Self::combine(&rects, &[], |in_a, _| in_a);
+            if !rebuilt
+                .rects
+                .iter()
+                .map(canonical_key)
+                .eq(rects.iter().map(canonical_key))
+            {
+                return Err(RegionDecodeError::NotCanonical);
+            }
+        }
+
+        Ok(Self { rects })
+    }
+}
+
+/// Checks that none of the rectangles overlap, by sweeping them from left to right.
+fn disjoint<R: Rectangle>(rects: &[R]) -> bool
+where
+    R::Unit: Ord,
+{
+    let mut by_left: Vec<&R> = rects.iter().collect();
+    by_left.sort_unstable_by_key(|rect| rect.left());
+
+    for (i, rect) in by_left.iter().enumerate() {
+        // only rectangles starting before this one ends can overlap it
+        for other in by_left[i + 1..]
+            .iter()
+            .take_while(|other| other.left() <= rect.right())
+        {
+            if rect.overlaps(*other) {
+                return false;
+            }
+        }
+    }
+    true
+}
@@ -0,0 +1,71 @@
+use num::ToPrimitive;
+
+use crate::{partial_max, partial_min, RectangleRead};
+
+/// The most points [`render_ascii`] will draw, beyond this it gives up rather than build a huge string.
+pub const MAX_ASCII_POINTS: usize = 1 << 16;
+
+/// Draws rectangles within `bounds` as ASCII art, one character per point & one line per row from top to bottom.
+///
+/// Empty points are `.`, later rectangles are drawn over earlier ones & anything outside of `bounds` is cut off.
+/// Every line ends with a newline, so the output can be compared against a multi-line string literal.
+/// Returns `None` if `bounds` holds more than [`MAX_ASCII_POINTS`] points.
+///
+/// # Example
+/// ```
+/// use rect_lib::{render_ascii, BasicRectangle, Rectangle, RectangleRead};
+///
+/// let bounds = BasicRectangle::new_from_sides(0, 3, 1, 0);
+/// let wall = BasicRectangle::new_from_sides(1, 2, 1, 1);
+/// assert_eq!(render_ascii(&bounds, &[(&wall, '#')]).unwrap(), ".##.\n....\n");
+/// ```
+pub fn render_ascii<B, R>(bounds: &B, rects: &[(&R, char)]) -> Option<String>
+where
+    B: RectangleRead,
+    B::Unit: ToPrimitive,
+    R: RectangleRead<Unit = B::Unit>,
+{
+    let to_i128 = |unit: B::Unit| unit.to_i128();
+    let (left, top) = (to_i128(bounds.left())?, to_i128(bounds.top())?);
+    let columns = usize::try_from(to_i128(bounds.right())? - left + 1).ok()?;
+    let rows = usize::try_from(top - to_i128(bounds.bottom())? + 1).ok()?;
+    if columns.checked_mul(rows)? > MAX_ASCII_POINTS {
+        return None;
+    }
+
+    let mut grid = vec![vec!['.'; columns]; rows];
+    for (rect, fill) in rects {
+        let Some(clipped) = clipped_sides(bounds, *rect) else {
+            continue;
+        };
+        let (rect_left, rect_right, rect_top, rect_bottom) = clipped;
+        for row in &mut grid[(top - rect_top) as usize..=(top - rect_bottom) as usize] {
+            row[(rect_left - left) as usize..=(rect_right - left) as usize].fill(*fill);
+        }
+    }
+
+    let mut output = String::with_capacity((columns + 1) * rows);
+    for row in grid {
+        output.extend(row);
+        output.push('\n');
+    }
+    Some(output)
+}
+
+/// The sides of `rect` clipped to `bounds` as `(left, right, top, bottom)`, or `None` if they don't overlap.
+fn clipped_sides<B, R>(bounds: &B, rect: &R) -> Option<(i128, i128, i128, i128)>
+where
+    B: RectangleRead,
+    B::Unit: ToPrimitive,
+    R: RectangleRead<Unit = B::Unit>,
+{
+    if !bounds.overlaps(rect) {
+        return None;
+    }
+    Some((
+        partial_max(bounds.left(), rect.left()).to_i128()?,
+        partial_min(bounds.right(), rect.right()).to_i128()?,
+        partial_min(bounds.top(), rect.top()).to_i128()?,
+        partial_max(bounds.bottom(), rect.bottom()).to_i128()?,
+    ))
+}
@@ -0,0 +1,351 @@
+use core::cmp::Reverse;
+use num::{Num, One, ToPrimitive};
+
+use crate::{partial_max, partial_min, RectangleRead};
+
+/// A vertical line the sweep needs to check for gaps.
+pub(crate) struct Line<U> {
+    pub(crate) x: U,
+    pub(crate) opens: bool,
+}
+
+/// A gap between two obstructions.
+pub(crate) struct Gap<U> {
+    pub(crate) top: U,
+    pub(crate) bottom: U,
+}
+
+/// Collects the lines where coverage can change within `bounds`, given the `(left, right)` of each rectangle.
+///
+/// The lines are sorted from left to right & deduped; where an opening & closing line meet only the closing line is kept.
+pub(crate) fn sweep_lines<R: RectangleRead>(
+    bounds: &R,
+    spans: impl IntoIterator<Item = (R::Unit, R::Unit)>,
+) -> Vec<Line<R::Unit>>
+where
+    R::Unit: Ord,
+{
+    let mut lines: Vec<Line<R::Unit>> = vec![Line {
+        x: bounds.left(),
+        opens: true,
+    }];
+
+    for (left, right) in spans {
+        // gaps might close on the left of each rectangle
+        lines.push(Line {
+            x: left,
+            opens: false,
+        });
+
+        // gaps might open just after the right of each rectangle
+        lines.push(Line {
+            x: right + R::Unit::one(),
+            opens: true,
+        });
+    }
+
+    // order from left to right
+    // closing lines come first so they survive the dedup, a closing line can also open gaps
+    lines.sort_unstable_by_key(|line| (line.x, line.opens));
+    lines.dedup_by_key(|line| line.x);
+
+    // filter out lines that are outside the bounds
+    lines.retain(|line| bounds.left() <= line.x && line.x <= bounds.right());
+
+    lines
+}
+
+/// Returns the sorted & deduped x coordinates within `bounds` where the coverage of `rects` can change.
+///
+/// These are the left edge of `bounds`, the left of each rectangle, & one past the right of each rectangle.
+/// They are the same lines `unobstructed_subrectangles` sweeps across.
+///
+/// # Example
+/// ```
+/// use rect_lib::{x_event_lines, BasicRectangle, Rectangle, RectangleRead};
+///
+/// let bounds = BasicRectangle::new_from_sides(0, 9, 9, 0);
+/// let rects = [
+///     BasicRectangle::new_from_sides(2, 4, 9, 5),
+///     BasicRectangle::new_from_sides(4, 12, 3, 0),
+/// ];
+/// assert_eq!(x_event_lines(&bounds, &rects), vec![0, 2, 4, 5]);
+/// ```
+pub fn x_event_lines<B, R>(bounds: &B, rects: &[R]) -> Vec<B::Unit>
+where
+    B: RectangleRead,
+    B::Unit: Ord,
+    R: RectangleRead<Unit = B::Unit>,
+{
+    sweep_lines(bounds, rects.iter().map(|rect| (rect.left(), rect.right())))
+        .into_iter()
+        .map(|line| line.x)
+        .collect()
+}
+
+/// Collects the gaps within `bounds` between the `(top, bottom)` of each rectangle crossing a line.
+///
+/// The rectangles must be sorted by top in descending order, the gaps are returned in the same order.
+pub(crate) fn gaps_between<R: RectangleRead>(
+    bounds: &R,
+    spans: impl IntoIterator<Item = (R::Unit, R::Unit)>,
+) -> Vec<Gap<R::Unit>> {
+    let mut gaps: Vec<Gap<R::Unit>> = Vec::new();
+
+    // think of each rectangle as a shingle on a roof
+    // if the bottom of one shingle is above the top of the next there is a gap between them
+    let mut last_rectange_bottom: R::Unit = bounds.top();
+
+    for (top, bottom) in spans {
+        // ignore gaps that are entirely below the roof
+        if last_rectange_bottom > top && last_rectange_bottom >= bounds.bottom() {
+            gaps.push(Gap {
+                top: last_rectange_bottom,
+                // the top is inclusive so +1, but the gap can't extend past the roof
+                bottom: partial_max(top + R::Unit::one(), bounds.bottom()),
+            });
+        }
+
+        // a shingle reaching the bottom of the roof leaves no gaps below it,
+        // stopping here also avoids `bottom - 1` underflowing unsigned units
+        if bottom <= bounds.bottom() {
+            return gaps;
+        }
+
+        // if a later shingle starts in the same place we could get a fake gap
+        // so we avoid that by getting the lowest point
+        last_rectange_bottom = partial_min(last_rectange_bottom, bottom - R::Unit::one());
+    }
+
+    // check if there is a gap between the bottom of the last shingle and the end of the roof
+    // the bottom is inclusive so >=, a shingle ending one above the roof's bottom leaves a gap one unit tall
+    if last_rectange_bottom >= bounds.bottom() {
+        gaps.push(Gap {
+            top: last_rectange_bottom,
+            bottom: bounds.bottom(),
+        });
+    }
+
+    gaps
+}
+
+/// Returns the vertical intervals at `x` within `bounds` not covered by any of `rects`.
+///
+/// Each interval is an inclusive `(top, bottom)` pair, ordered from top to bottom.
+/// If `x` is outside of `bounds` there are no gaps.
+///
+/// # Example
+/// ```
+/// use rect_lib::{vertical_gaps_at, BasicRectangle, Rectangle, RectangleRead};
+///
+/// let bounds = BasicRectangle::new_from_sides(0, 9, 9, 0);
+/// let rects = [BasicRectangle::new_from_sides(2, 4, 6, 3)];
+///
+/// assert_eq!(vertical_gaps_at(&bounds, &rects, 3), vec![(9, 7), (2, 0)]);
+/// assert_eq!(vertical_gaps_at(&bounds, &rects, 5), vec![(9, 0)]);
+/// ```
+pub fn vertical_gaps_at<B, R>(bounds: &B, rects: &[R], x: B::Unit) -> Vec<(B::Unit, B::Unit)>
+where
+    B: RectangleRead,
+    B::Unit: Ord,
+    R: RectangleRead<Unit = B::Unit>,
+{
+    if x < bounds.left() || bounds.right() < x {
+        return Vec::new();
+    }
+
+    let mut spans: Vec<(B::Unit, B::Unit)> = rects
+        .iter()
+        .filter(|rect| rect.left() <= x && x <= rect.right())
+        .map(|rect| (rect.top(), rect.bottom()))
+        .collect();
+    // descending order by top
+    spans.sort_unstable_by_key(|span| Reverse(span.0));
+
+    gaps_between(bounds, spans)
+        .into_iter()
+        .map(|gap| (gap.top, gap.bottom))
+        .collect()
+}
+
+/// A row & the inclusive `(left, right)` spans covered on it.
+type Row<U> = (U, Vec<(U, U)>);
+
+/// Yields each row of `bounds` from top to bottom, along with the horizontal spans covered by `rects` on that row.
+///
+/// Each span is an inclusive `(left, right)` pair clipped to `bounds`, ordered from left to right.
+/// Overlapping & touching spans are merged, so a row never holds two spans that could be blitted as one.
+///
+/// # Example
+/// ```
+/// use rect_lib::{scanline_spans, BasicRectangle, Rectangle, RectangleRead};
+///
+/// let bounds = BasicRectangle::new_from_sides(0, 9, 2, 0);
+/// let rects = [
+///     BasicRectangle::new_from_sides(0, 3, 2, 1),
+///     BasicRectangle::new_from_sides(6, 7, 1, 0),
+/// ];
+/// let rows: Vec<_> = scanline_spans(&bounds, &rects).collect();
+/// assert_eq!(
+///     rows,
+///     vec![(2, vec![(0, 3)]), (1, vec![(0, 3), (6, 7)]), (0, vec![(6, 7)])]
+/// );
+/// ```
+pub fn scanline_spans<'a, B, R>(
+    bounds: &B,
+    rects: &'a [R],
+) -> impl Iterator<Item = Row<B::Unit>> + 'a
+where
+    B: RectangleRead,
+    B::Unit: Ord + 'a,
+    R: RectangleRead<Unit = B::Unit>,
+{
+    let one = B::Unit::one();
+    let (left, right, top, bottom) = (bounds.left(), bounds.right(), bounds.top(), bounds.bottom());
+
+    let rows = core::iter::successors((bottom <= top).then_some(top), move |&y| {
+        (y > bottom).then(|| y - one)
+    });
+
+    rows.map(move |y| {
+        let mut spans: Vec<(B::Unit, B::Unit)> = rects
+            .iter()
+            .filter(|rect| rect.bottom() <= y && y <= rect.top())
+            .map(|rect| {
+                (
+                    partial_max(rect.left(), left),
+                    partial_min(rect.right(), right),
+                )
+            })
+            .filter(|&(span_left, span_right)| span_left <= span_right)
+            .collect();
+        spans.sort_unstable();
+
+        let mut merged: Vec<(B::Unit, B::Unit)> = Vec::with_capacity(spans.len());
+        for (span_left, span_right) in spans {
+            match merged.last_mut() {
+                Some((_, last_right)) if span_left <= *last_right + one => {
+                    *last_right = partial_max(*last_right, span_right);
+                }
+                _ => merged.push((span_left, span_right)),
+            }
+        }
+
+        (y, merged)
+    })
+}
+
+/// Rasterizes `rects` into a grid of booleans over `bounds`, where a cell is `true` if any rectangle covers it.
+///
+/// The grid is indexed as `grid[row][column]`, with one cell per unit.
+/// Row `0` is the top of `bounds` & column `0` is its left, so `grid[row][column]` is the point
+/// `(left + column, top - row)`, matching the top to bottom order of [`scanline_spans`].
+///
+/// # Example
+/// ```
+/// use rect_lib::{occupancy_grid, BasicRectangle, Rectangle};
+///
+/// let bounds = BasicRectangle::new_from_sides(0, 2, 1, 0);
+/// let obstacles = [BasicRectangle::new_from_sides(1, 2, 1, 1)];
+/// assert_eq!(
+///     occupancy_grid(&bounds, &obstacles),
+///     vec![vec![false, true, true], vec![false, false, false]]
+/// );
+/// ```
+pub fn occupancy_grid<B, R>(bounds: &B, rects: &[R]) -> Vec<Vec<bool>>
+where
+    B: RectangleRead,
+    B::Unit: Ord + ToPrimitive,
+    R: RectangleRead<Unit = B::Unit>,
+{
+    // the sides are inclusive so +1
+    let index = |from: B::Unit, to: B::Unit| (to - from).to_usize().unwrap_or(0);
+    let columns = index(bounds.left(), bounds.right() + B::Unit::one());
+
+    scanline_spans(bounds, rects)
+        .map(|(_, spans)| {
+            let mut row = vec![false; columns];
+            for (left, right) in spans {
+                let (start, end) = (index(bounds.left(), left), index(bounds.left(), right));
+                row[start..=end].fill(true);
+            }
+            row
+        })
+        .collect()
+}
+
+/// Sweeps the `(min, max)` intervals along one axis, returning each maximal run with a constant count.
+fn projection_profile<U: Copy + Ord + Num>(
+    intervals: impl Iterator<Item = (U, U)>,
+) -> Vec<(U, U, u32)> {
+    // +1 where an interval starts & -1 just past where it ends
+    let mut events: Vec<(U, i64)> = intervals
+        .filter(|(min, max)| min <= max)
+        .flat_map(|(min, max)| [(min, 1), (max + U::one(), -1)])
+        .collect();
+    events.sort_unstable_by_key(|&(at, _)| at);
+
+    let mut profile: Vec<(U, U, u32)> = Vec::new();
+    let mut count = 0i64;
+    let mut i = 0;
+    while i < events.len() {
+        let at = events[i].0;
+        while i < events.len() && events[i].0 == at {
+            count += events[i].1;
+            i += 1;
+        }
+
+        let Some(&(next, _)) = events.get(i) else {
+            break;
+        };
+        match profile.last_mut() {
+            // runs with the same count are merged
+            Some((_, end, last)) if *last as i64 == count => *end = next - U::one(),
+            _ => profile.push((at, next - U::one(), count as u32)),
+        }
+    }
+
+    profile
+}
+
+/// Returns how many rectangles cover each x interval, from left to right.
+///
+/// Each entry is an inclusive `(start, end, count)` with a maximal run of the same count,
+/// spanning from the leftmost to the rightmost rectangle so gaps show up with a count of 0.
+///
+/// # Example
+/// ```
+/// use rect_lib::{projection_profile_x, BasicRectangle, Rectangle, RectangleRead};
+///
+/// let rects = [
+///     BasicRectangle::new_from_sides(0, 3, 1, 0),
+///     BasicRectangle::new_from_sides(2, 5, 9, 8),
+/// ];
+/// assert_eq!(projection_profile_x(&rects), vec![(0, 1, 1), (2, 3, 2), (4, 5, 1)]);
+/// ```
+pub fn projection_profile_x<R: RectangleRead>(rects: &[R]) -> Vec<(R::Unit, R::Unit, u32)>
+where
+    R::Unit: Ord,
+{
+    projection_profile(rects.iter().map(|rect| (rect.left(), rect.right())))
+}
+
+/// Returns how many rectangles cover each y interval, from bottom to top.
+/// This is the y version of [`projection_profile_x`].
+///
+/// # Example
+/// ```
+/// use rect_lib::{projection_profile_y, BasicRectangle, Rectangle, RectangleRead};
+///
+/// let rects = [
+///     BasicRectangle::new_from_sides(0, 3, 1, 0),
+///     BasicRectangle::new_from_sides(0, 3, 5, 4),
+/// ];
+/// assert_eq!(projection_profile_y(&rects), vec![(0, 1, 1), (2, 3, 0), (4, 5, 1)]);
+/// ```
+pub fn projection_profile_y<R: RectangleRead>(rects: &[R]) -> Vec<(R::Unit, R::Unit, u32)>
+where
+    R::Unit: Ord,
+{
+    projection_profile(rects.iter().map(|rect| (rect.bottom(), rect.top())))
+}
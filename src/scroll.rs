@@ -0,0 +1,69 @@
+use num::Zero;
+
+use crate::{RectRegion, Rectangle};
+
+/// What to do on screen after scrolling part of it, see [`scroll_region`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct BlitPlan<R: Rectangle> {
+    /// The `(source, destination)` pairs to copy, empty when everything scrolled out of view.
+    pub blits: Vec<(R, R)>,
+    /// The area uncovered by the scroll, which has nothing to copy & must be repainted.
+    pub exposed: RectRegion<R>,
+}
+
+/// Scrolls the contents of `region` by `delta`, planning the copies & updating the dirty region to match.
+///
+/// Content moves from `(x, y)` to `(x + delta.0, y + delta.1)`, anything leaving the region is dropped.
+/// Afterwards the dirty region has the exposed area added, & any dirty area inside the region that was copied
+/// is moved along with it, stale content stays stale wherever it ends up. Dirty areas outside the region are untouched.
+///
+/// # Example
+/// ```
+/// use rect_lib::{scroll_region, BasicRectangle, RectRegion, Rectangle, RectangleRead};
+///
+/// let view = BasicRectangle::new_from_sides(0, 9, 9, 0);
+/// let mut dirty = RectRegion::new();
+///
+/// // scroll up by 2 rows
+/// let plan = scroll_region(&view, (0, 2), &mut dirty);
+/// assert_eq!(
+///     plan.blits,
+///     vec![(BasicRectangle::new_from_sides(0, 9, 7, 0), BasicRectangle::new_from_sides(0, 9, 9, 2))]
+/// );
+/// assert_eq!(plan.exposed, RectRegion::from(BasicRectangle::new_from_sides(0, 9, 1, 0)));
+/// assert_eq!(dirty, plan.exposed);
+/// ```
+pub fn scroll_region<R: Rectangle>(
+    region: &R,
+    delta: (R::Unit, R::Unit),
+    dirty: &mut RectRegion<R>,
+) -> BlitPlan<R>
+where
+    R::Unit: Ord,
+{
+    let (dx, dy) = delta;
+    let zero = R::Unit::zero();
+
+    // the part of the region whose content lands back inside it
+    let source = region.intersection(&region.translate(zero - dx, zero - dy));
+    let blits: Vec<(R, R)> = source
+        .map(|source| (source, source.translate(dx, dy)))
+        .into_iter()
+        .collect();
+
+    let whole = RectRegion::from(*region);
+    let covered: RectRegion<R> = blits.iter().map(|&(_, destination)| destination).collect();
+    let exposed = whole.subtract(&covered);
+
+    // dirty content that gets copied is still dirty at its destination
+    let sources: RectRegion<R> = blits.iter().map(|&(source, _)| source).collect();
+    let moved: RectRegion<R> = dirty
+        .intersect(&sources)
+        .iter()
+        .map(|rect| rect.translate(dx, dy))
+        .collect();
+
+    *dirty = dirty.subtract(&whole).union(&moved).union(&exposed);
+
+    BlitPlan { blits, exposed }
+}
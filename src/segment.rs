@@ -0,0 +1,84 @@
+use num::{NumCast, ToPrimitive};
+
+use crate::{partial_max, partial_min, RectangleRead};
+
+/// A straight line between two points.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Segment<U> {
+    pub start: (U, U),
+    pub end: (U, U),
+}
+
+impl<U> Segment<U> {
+    /// Creates a new segment between two points.
+    pub fn new(start: (U, U), end: (U, U)) -> Self {
+        Self { start, end }
+    }
+}
+
+/// The part of a segment inside a rectangle, & whether each end had to be moved.
+pub(crate) struct Clipped<U> {
+    pub(crate) segment: Segment<U>,
+    pub(crate) start_moved: bool,
+    pub(crate) end_moved: bool,
+}
+
+/// Clips a segment to a rectangle with the Liang-Barsky algorithm, rounding moved ends to the nearest unit.
+pub(crate) fn clip<R: RectangleRead>(
+    rect: &R,
+    segment: &Segment<R::Unit>,
+) -> Option<Clipped<R::Unit>>
+where
+    R::Unit: NumCast,
+{
+    let to_f64 = |unit: R::Unit| unit.to_f64().unwrap_or(f64::NAN);
+    let (x0, y0) = (to_f64(segment.start.0), to_f64(segment.start.1));
+    let (dx, dy) = (to_f64(segment.end.0) - x0, to_f64(segment.end.1) - y0);
+
+    // each edge limits how far along the segment we can be, as (p, q) with p * t <= q
+    let limits = [
+        (-dx, x0 - to_f64(rect.left())),
+        (dx, to_f64(rect.right()) - x0),
+        (-dy, y0 - to_f64(rect.bottom())),
+        (dy, to_f64(rect.top()) - y0),
+    ];
+
+    let (mut t0, mut t1) = (0.0f64, 1.0f64);
+    for (p, q) in limits {
+        if p == 0.0 {
+            // parallel to this edge, so either always or never inside it
+            if q < 0.0 {
+                return None;
+            }
+        } else if p < 0.0 {
+            t0 = t0.max(q / p);
+        } else {
+            t1 = t1.min(q / p);
+        }
+    }
+    if t0 > t1 {
+        return None;
+    }
+
+    // rounding could nudge a point just outside, so clamp it back in
+    let point_at = |t: f64| -> Option<(R::Unit, R::Unit)> {
+        let x: R::Unit = NumCast::from((x0 + dx * t).round())?;
+        let y: R::Unit = NumCast::from((y0 + dy * t).round())?;
+        Some((
+            partial_min(partial_max(x, rect.left()), rect.right()),
+            partial_min(partial_max(y, rect.bottom()), rect.top()),
+        ))
+    };
+
+    let start = if t0 > 0.0 {
+        point_at(t0)?
+    } else {
+        segment.start
+    };
+    let end = if t1 < 1.0 { point_at(t1)? } else { segment.end };
+    Some(Clipped {
+        segment: Segment::new(start, end),
+        start_moved: t0 > 0.0,
+        end_moved: t1 < 1.0,
+    })
+}
@@ -0,0 +1,84 @@
+use core::ops::Mul;
+
+use num::{Num, ToPrimitive};
+
+use crate::integer_unit;
+
+/// One of the four sides of a rectangle, see [`RectangleRead::exit_side`](crate::RectangleRead::exit_side).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Side {
+    /// The side at the smallest x.
+    Left,
+    /// The side at the largest x.
+    Right,
+    /// The side at the largest y, y points up.
+    Top,
+    /// The side at the smallest y.
+    Bottom,
+}
+
+/// The side of `left..=right` by `bottom..=top` the path from `from` to `to` crosses first, if it starts inside & ends outside.
+///
+/// Everything is compared by cross multiplying distances, so there is no division & no rounding.
+/// For integer units the distances are widened first, every product of two distances between 64 bit units fits in a `u128`.
+/// A path through a corner goes out the side its axis overshoots more, & the left or right side if both overshoot the same.
+pub(crate) fn exit_side<U: Num + Copy + PartialOrd + ToPrimitive>(
+    (left, right, top, bottom): (U, U, U, U),
+    from: (U, U),
+    to: (U, U),
+) -> Option<Side> {
+    let inside = |(x, y): (U, U)| left <= x && x <= right && bottom <= y && y <= top;
+    if !inside(from) || inside(to) {
+        return None;
+    }
+
+    // the side crossed along each axis & the line it lies on
+    let x_exit = if to.0 < left {
+        Some((Side::Left, left))
+    } else if to.0 > right {
+        Some((Side::Right, right))
+    } else {
+        None
+    };
+    let y_exit = if to.1 > top {
+        Some((Side::Top, top))
+    } else if to.1 < bottom {
+        Some((Side::Bottom, bottom))
+    } else {
+        None
+    };
+
+    match (x_exit, y_exit) {
+        (Some((x_side, x_line)), Some((y_side, y_line))) => {
+            let x_first = if integer_unit::<U>() {
+                let distance = |a: U, b: U| {
+                    (a.to_i128().unwrap_or(0) - b.to_i128().unwrap_or(0)).unsigned_abs()
+                };
+                reaches_x_first(distance, (x_line, y_line), from, to)
+            } else {
+                // floats don't wrap, subtracting the smaller from the larger is fine
+                let distance = |a: U, b: U| if a > b { a - b } else { b - a };
+                reaches_x_first(distance, (x_line, y_line), from, to)
+            };
+            Some(if x_first { x_side } else { y_side })
+        }
+        (Some((side, _)), None) | (None, Some((side, _))) => Some(side),
+        (None, None) => None,
+    }
+}
+
+/// Checks if a path from `from` to `to` reaches the vertical line before the horizontal one, breaking ties at a corner
+/// by which axis it overshoots more, with `distance` measuring in whatever type can't overflow.
+fn reaches_x_first<U: Copy, D: Mul<Output = D> + PartialOrd>(
+    distance: impl Fn(U, U) -> D,
+    (x_line, y_line): (U, U),
+    from: (U, U),
+    to: (U, U),
+) -> bool {
+    // the path reaches each line a fraction of the way along, `reached / travelled` on that axis,
+    // so cross multiplying the two fractions tells which comes first
+    let x_reached = distance(x_line, from.0) * distance(to.1, from.1);
+    let y_reached = distance(y_line, from.1) * distance(to.0, from.0);
+    x_reached < y_reached
+        || x_reached == y_reached && distance(to.0, x_line) >= distance(to.1, y_line)
+}
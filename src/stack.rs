@@ -0,0 +1,45 @@
+use num::One;
+
+use crate::{Axis, Rectangle};
+
+/// Lays out rectangles of the given `(width, height)` one after another, starting at the top left of `start`.
+///
+/// Along [`Axis::X`] they go left to right with their tops lined up, along [`Axis::Y`] they go top to bottom with their lefts lined up.
+/// Sizes are measured between inclusive sides like [`RectangleRead::width`](crate::RectangleRead::width),
+/// & `spacing` is the number of empty units between neighbours, so zero leaves them touching.
+///
+/// # Example
+/// ```
+/// use rect_lib::{stack, Axis, BasicRectangle, Rectangle, RectangleRead};
+///
+/// let start = BasicRectangle::new_from_sides(0, 0, 10, 10);
+/// let column = stack(&start, &[(4, 1), (4, 2)], Axis::Y, 1);
+/// assert_eq!(
+///     column,
+///     vec![
+///         BasicRectangle::new_from_sides(0, 4, 10, 9),
+///         BasicRectangle::new_from_sides(0, 4, 7, 5),
+///     ]
+/// );
+/// ```
+pub fn stack<R: Rectangle>(
+    start: &R,
+    sizes: &[(R::Unit, R::Unit)],
+    direction: Axis,
+    spacing: R::Unit,
+) -> Vec<R> {
+    let one = R::Unit::one();
+    let mut placed: Vec<R> = Vec::with_capacity(sizes.len());
+
+    for &(width, height) in sizes {
+        // each one starts past the far side of the last & the spacing
+        let (left, top) = match (placed.last(), direction) {
+            (None, _) => (start.left(), start.top()),
+            (Some(last), Axis::X) => (last.right() + one + spacing, last.top()),
+            (Some(last), Axis::Y) => (last.left(), last.bottom() - one - spacing),
+        };
+        placed.push(R::new_from_sides(left, left + width, top, top - height));
+    }
+
+    placed
+}
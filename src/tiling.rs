@@ -0,0 +1,146 @@
+use num::{NumCast, ToPrimitive};
+
+use crate::{Extent, Rectangle};
+
+/// The backtracking state for [`tile`], working in grid cells from the bottom left of the parent.
+struct Search<'a> {
+    columns: usize,
+    rows: usize,
+    filled: Vec<bool>,
+    /// The `(index, width, height)` of each piece in cells, sorted largest first.
+    pieces: &'a [(usize, usize, usize)],
+    allow_rotation: bool,
+    used: Vec<bool>,
+    /// The `(index, column, row, width, height)` of each placed piece.
+    placed: Vec<(usize, usize, usize, usize, usize)>,
+    nodes_left: usize,
+}
+
+impl Search<'_> {
+    fn fits(&self, column: usize, row: usize, width: usize, height: usize) -> bool {
+        column + width <= self.columns
+            && row + height <= self.rows
+            && (row..row + height)
+                .all(|y| (column..column + width).all(|x| !self.filled[y * self.columns + x]))
+    }
+
+    fn fill(&mut self, column: usize, row: usize, width: usize, height: usize, value: bool) {
+        for y in row..row + height {
+            for x in column..column + width {
+                self.filled[y * self.columns + x] = value;
+            }
+        }
+    }
+
+    /// Returns `Some(true)` once everything is covered, or `None` when the budget runs out.
+    fn solve(&mut self, cursor: usize) -> Option<bool> {
+        // the lowest leftmost free cell must be the bottom left corner of whichever piece covers it
+        let Some(cell) = (cursor..self.filled.len()).find(|&i| !self.filled[i]) else {
+            return Some(true);
+        };
+        let (column, row) = (cell % self.columns, cell / self.columns);
+
+        let mut tried: Vec<(usize, usize)> = Vec::new();
+        for slot in 0..self.pieces.len() {
+            let (index, width, height) = self.pieces[slot];
+            // identical pieces would only repeat the same search
+            if self.used[slot] || tried.contains(&(width, height)) {
+                continue;
+            }
+            tried.push((width, height));
+
+            let mut orientations = vec![(width, height)];
+            if self.allow_rotation && width != height {
+                orientations.push((height, width));
+            }
+            for (width, height) in orientations {
+                self.nodes_left = self.nodes_left.checked_sub(1)?;
+                if !self.fits(column, row, width, height) {
+                    continue;
+                }
+
+                self.fill(column, row, width, height, true);
+                self.used[slot] = true;
+                self.placed.push((index, column, row, width, height));
+                if self.solve(cell + width)? {
+                    return Some(true);
+                }
+                self.placed.pop();
+                self.used[slot] = false;
+                self.fill(column, row, width, height, false);
+            }
+        }
+
+        Some(false)
+    }
+}
+
+/// Places every piece exactly once so together they cover the parent with no overlaps, see [`Rectangle::tile_with_sizes`].
+pub(crate) fn tile<R: Rectangle>(
+    parent: &R,
+    sizes: &[Extent<R::Unit>],
+    allow_rotation: bool,
+    node_budget: usize,
+) -> Option<Vec<(usize, R)>>
+where
+    R::Unit: NumCast,
+{
+    // sizes are between inclusive sides, so one more cell each way
+    let cells = |size: R::Unit| size.to_usize().and_then(|size| size.checked_add(1));
+    let columns = cells(parent.width())?;
+    let rows = cells(parent.height())?;
+
+    let mut pieces = sizes
+        .iter()
+        .enumerate()
+        .map(|(index, size)| Some((index, cells(size.width)?, cells(size.height)?)))
+        .collect::<Option<Vec<_>>>()?;
+
+    // no point searching if the areas don't add up
+    let total = columns.checked_mul(rows)?;
+    let covered = pieces.iter().try_fold(0usize, |sum, (_, width, height)| {
+        sum.checked_add(width.checked_mul(*height)?)
+    })?;
+    if covered != total {
+        return None;
+    }
+
+    // the biggest pieces are the hardest to fit, so they go first
+    pieces.sort_by_key(|&(index, width, height)| (core::cmp::Reverse(width * height), index));
+
+    let mut search = Search {
+        columns,
+        rows,
+        filled: vec![false; total],
+        pieces: &pieces,
+        allow_rotation,
+        used: vec![false; pieces.len()],
+        placed: Vec::new(),
+        nodes_left: node_budget,
+    };
+    if !search.solve(0)? {
+        return None;
+    }
+
+    let unit = |cells: usize| -> Option<R::Unit> { NumCast::from(cells) };
+    let mut placed = search
+        .placed
+        .iter()
+        .map(|&(index, column, row, width, height)| {
+            let left = parent.left() + unit(column)?;
+            let bottom = parent.bottom() + unit(row)?;
+            Some((
+                index,
+                R::new_from_sides(
+                    left,
+                    left + unit(width - 1)?,
+                    bottom + unit(height - 1)?,
+                    bottom,
+                ),
+            ))
+        })
+        .collect::<Option<Vec<_>>>()?;
+    placed.sort_by_key(|&(index, _)| index);
+
+    Some(placed)
+}
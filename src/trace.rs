@@ -0,0 +1,190 @@
+#[cfg(feature = "trace")]
+use num::ToPrimitive;
+
+#[cfg(feature = "trace")]
+use crate::{render_ascii, RectangleRead};
+
+/// Watches what the sweep behind [`Rectangle::unobstructed_subrectangles`](crate::Rectangle::unobstructed_subrectangles) does,
+/// see `unobstructed_subrectangles_traced` with the `trace` feature.
+///
+/// Every method does nothing by default, so only the interesting ones need implementing.
+pub trait SweepObserver<U> {
+    /// A line at `x` is being swept, `opens` if obstructions start on it rather than end just before it.
+    fn on_line(&mut self, _x: U, _opens: bool) {}
+
+    /// There is a gap between obstructions from `top` to `bottom` on the current line.
+    fn on_gap(&mut self, _top: U, _bottom: U) {}
+
+    /// A rectangle from `left` was started between `top` & `bottom`, its right side isn't known yet.
+    fn on_open(&mut self, _left: U, _top: U, _bottom: U) {}
+
+    /// A rectangle was finished & handed back.
+    fn on_close(&mut self, _left: U, _right: U, _top: U, _bottom: U) {}
+}
+
+/// Watches nothing, the untraced sweep uses this so the hooks compile away.
+pub(crate) struct NoObserver;
+
+impl<U> SweepObserver<U> for NoObserver {}
+
+/// One thing the sweep did, as recorded by [`RecordingObserver`].
+#[cfg(feature = "trace")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SweepEvent<U> {
+    /// A line was swept, see [`SweepObserver::on_line`].
+    Line {
+        /// Where the line is.
+        x: U,
+        /// If obstructions start on the line rather than end just before it.
+        opens: bool,
+    },
+    /// A gap between obstructions on the current line, see [`SweepObserver::on_gap`].
+    Gap {
+        /// The highest point of the gap.
+        top: U,
+        /// The lowest point of the gap.
+        bottom: U,
+    },
+    /// A rectangle was started, see [`SweepObserver::on_open`].
+    Open {
+        /// The left side of the rectangle.
+        left: U,
+        /// The top side of the rectangle.
+        top: U,
+        /// The bottom side of the rectangle.
+        bottom: U,
+    },
+    /// A rectangle was finished, see [`SweepObserver::on_close`].
+    Close {
+        /// The left side of the rectangle.
+        left: U,
+        /// The right side of the rectangle.
+        right: U,
+        /// The top side of the rectangle.
+        top: U,
+        /// The bottom side of the rectangle.
+        bottom: U,
+    },
+}
+
+/// Records every event of a sweep in order, for attaching to bug reports.
+///
+/// # Example
+/// ```
+/// use rect_lib::{BasicRectangle, RecordingObserver, Rectangle, RectangleRead, SweepEvent};
+///
+/// let rect = BasicRectangle::new_from_sides(0, 5, 5, 0);
+/// let obstruction = BasicRectangle::new_from_sides(0, 2, 5, 1);
+/// let mut recording = RecordingObserver::new();
+/// rect.unobstructed_subrectangles_traced(&[&obstruction], &mut recording);
+///
+/// assert_eq!(
+///     recording.events().last(),
+///     Some(&SweepEvent::Close { left: 3, right: 5, top: 5, bottom: 0 })
+/// );
+/// // later rectangles are drawn over earlier ones
+/// assert_eq!(
+///     recording.render_closed(&rect).unwrap(),
+///     "...bbb\n...bbb\n...bbb\n...bbb\n...bbb\naaabbb\n",
+/// );
+/// ```
+#[cfg(feature = "trace")]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RecordingObserver<U> {
+    events: Vec<SweepEvent<U>>,
+}
+
+#[cfg(feature = "trace")]
+impl<U> Default for RecordingObserver<U> {
+    fn default() -> Self {
+        Self { events: Vec::new() }
+    }
+}
+
+#[cfg(feature = "trace")]
+impl<U> RecordingObserver<U> {
+    /// Creates an observer with nothing recorded.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The events in the order they happened.
+    pub fn events(&self) -> &[SweepEvent<U>] {
+        &self.events
+    }
+}
+
+#[cfg(feature = "trace")]
+impl<U: num::Num + Copy + PartialOrd + ToPrimitive> RecordingObserver<U> {
+    /// Draws the closed rectangles within `bounds` with [`render_ascii`], lettered `a` to `z` in the order they closed & then wrapping around.
+    pub fn render_closed(&self, bounds: &impl RectangleRead<Unit = U>) -> Option<String> {
+        let closed: Vec<Sides<U>> = self
+            .events
+            .iter()
+            .filter_map(|event| match *event {
+                SweepEvent::Close {
+                    left,
+                    right,
+                    top,
+                    bottom,
+                } => Some(Sides(left, right, top, bottom)),
+                _ => None,
+            })
+            .collect();
+        let lettered: Vec<(&Sides<U>, char)> = closed
+            .iter()
+            .zip((b'a'..=b'z').cycle())
+            .map(|(sides, letter)| (sides, letter as char))
+            .collect();
+        render_ascii(bounds, &lettered)
+    }
+}
+
+#[cfg(feature = "trace")]
+impl<U: Copy> SweepObserver<U> for RecordingObserver<U> {
+    fn on_line(&mut self, x: U, opens: bool) {
+        self.events.push(SweepEvent::Line { x, opens });
+    }
+
+    fn on_gap(&mut self, top: U, bottom: U) {
+        self.events.push(SweepEvent::Gap { top, bottom });
+    }
+
+    fn on_open(&mut self, left: U, top: U, bottom: U) {
+        self.events.push(SweepEvent::Open { left, top, bottom });
+    }
+
+    fn on_close(&mut self, left: U, right: U, top: U, bottom: U) {
+        self.events.push(SweepEvent::Close {
+            left,
+            right,
+            top,
+            bottom,
+        });
+    }
+}
+
+/// The sides of a closed rectangle, so they can be drawn without knowing the rectangle type.
+#[cfg(feature = "trace")]
+struct Sides<U>(U, U, U, U);
+
+#[cfg(feature = "trace")]
+impl<U: num::Num + Copy + PartialOrd> RectangleRead for Sides<U> {
+    type Unit = U;
+
+    fn left(&self) -> U {
+        self.0
+    }
+
+    fn right(&self) -> U {
+        self.1
+    }
+
+    fn top(&self) -> U {
+        self.2
+    }
+
+    fn bottom(&self) -> U {
+        self.3
+    }
+}
@@ -0,0 +1,203 @@
+use core::ops::{Add, Deref, Mul, Sub};
+
+use crate::RectangleRead;
+
+/// Declares a newtype around a unit with the escape hatches every typed unit shares.
+macro_rules! typed_unit {
+    ($(#[$meta:meta])* $name:ident) => {
+        $(#[$meta])*
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        pub struct $name<U>(pub U);
+
+        impl<U> $name<U> {
+            /// Returns the raw unit.
+            pub fn into_inner(self) -> U {
+                self.0
+            }
+        }
+
+        impl<U> Deref for $name<U> {
+            type Target = U;
+
+            fn deref(&self) -> &U {
+                &self.0
+            }
+        }
+    };
+}
+
+/// Implements `$lhs ± $rhs -> $lhs` & `$lhs - $lhs -> $rhs`, a coordinate moved by a size & the size between two coordinates.
+macro_rules! typed_offset {
+    ($lhs:ident, $rhs:ident) => {
+        impl<U: Add<Output = U>> Add<$rhs<U>> for $lhs<U> {
+            type Output = $lhs<U>;
+
+            fn add(self, rhs: $rhs<U>) -> $lhs<U> {
+                $lhs(self.0 + rhs.0)
+            }
+        }
+
+        impl<U: Sub<Output = U>> Sub<$rhs<U>> for $lhs<U> {
+            type Output = $lhs<U>;
+
+            fn sub(self, rhs: $rhs<U>) -> $lhs<U> {
+                $lhs(self.0 - rhs.0)
+            }
+        }
+
+        impl<U: Sub<Output = U>> Sub for $lhs<U> {
+            type Output = $rhs<U>;
+
+            fn sub(self, rhs: $lhs<U>) -> $rhs<U> {
+                $rhs(self.0 - rhs.0)
+            }
+        }
+    };
+}
+
+typed_unit!(
+    /// A position along the x axis.
+    XCoord
+);
+typed_unit!(
+    /// A position along the y axis.
+    YCoord
+);
+typed_unit!(
+    /// A distance along the x axis, like [`RectangleRead::width`].
+    Width
+);
+typed_unit!(
+    /// A distance along the y axis, like [`RectangleRead::height`].
+    Height
+);
+typed_unit!(
+    /// A width multiplied by a height.
+    Area
+);
+
+typed_offset!(XCoord, Width);
+typed_offset!(YCoord, Height);
+
+impl<U: Mul<Output = U>> Mul<Height<U>> for Width<U> {
+    type Output = Area<U>;
+
+    fn mul(self, rhs: Height<U>) -> Area<U> {
+        Area(self.0 * rhs.0)
+    }
+}
+
+impl<U: Mul<Output = U>> Mul<Width<U>> for Height<U> {
+    type Output = Area<U>;
+
+    fn mul(self, rhs: Width<U>) -> Area<U> {
+        Area(self.0 * rhs.0)
+    }
+}
+
+/// A view of a rectangle whose accessors return typed units, so an x can't be passed where a y is expected.
+///
+/// Create one with [`TypedRectangle::typed`], the rectangle underneath is still reachable through `Deref` or [`Typed::into_inner`].
+///
+/// # Example
+/// ```
+/// use rect_lib::{BasicRectangle, Rectangle, RectangleRead, TypedRectangle, XCoord, YCoord};
+///
+/// let rect = BasicRectangle::new_from_sides(0, 3, 5, 2);
+/// let typed = rect.typed();
+/// assert!(typed.contains_point(XCoord(1), YCoord(4)));
+/// assert_eq!(typed.left() + typed.width(), typed.right());
+/// assert_eq!((typed.width() * typed.height()).into_inner(), rect.area());
+/// ```
+///
+/// Mixing the axes up doesn't compile:
+/// ```compile_fail
+/// use rect_lib::{BasicRectangle, Rectangle, RectangleRead, TypedRectangle, XCoord, YCoord};
+///
+/// let rect = BasicRectangle::new_from_sides(0, 3, 5, 2);
+/// rect.typed().contains_point(YCoord(4), XCoord(1));
+/// ```
+///
+/// ```compile_fail
+/// use rect_lib::{BasicRectangle, Rectangle, RectangleRead, TypedRectangle};
+///
+/// let rect = BasicRectangle::new_from_sides(0, 3, 5, 2);
+/// let typed = rect.typed();
+/// let _ = typed.left() + typed.height();
+/// ```
+#[derive(Debug)]
+pub struct Typed<'a, R: ?Sized>(&'a R);
+
+// derived impls would require the rectangle itself to be `Copy`
+impl<R: ?Sized> Clone for Typed<'_, R> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<R: ?Sized> Copy for Typed<'_, R> {}
+
+impl<'a, R: RectangleRead + ?Sized> Typed<'a, R> {
+    /// Returns the rectangle underneath.
+    pub fn into_inner(self) -> &'a R {
+        self.0
+    }
+
+    /// The left side, see [`RectangleRead::left`].
+    pub fn left(&self) -> XCoord<R::Unit> {
+        XCoord(self.0.left())
+    }
+
+    /// The right side, see [`RectangleRead::right`].
+    pub fn right(&self) -> XCoord<R::Unit> {
+        XCoord(self.0.right())
+    }
+
+    /// The top side, see [`RectangleRead::top`].
+    pub fn top(&self) -> YCoord<R::Unit> {
+        YCoord(self.0.top())
+    }
+
+    /// The bottom side, see [`RectangleRead::bottom`].
+    pub fn bottom(&self) -> YCoord<R::Unit> {
+        YCoord(self.0.bottom())
+    }
+
+    /// The width, see [`RectangleRead::width`].
+    pub fn width(&self) -> Width<R::Unit> {
+        Width(self.0.width())
+    }
+
+    /// The height, see [`RectangleRead::height`].
+    pub fn height(&self) -> Height<R::Unit> {
+        Height(self.0.height())
+    }
+
+    /// The area, see [`RectangleRead::area`].
+    pub fn area(&self) -> Area<R::Unit> {
+        Area(self.0.area())
+    }
+
+    /// Checks if the rectangle contains the given point, see [`RectangleRead::contains_point`].
+    pub fn contains_point(&self, x: XCoord<R::Unit>, y: YCoord<R::Unit>) -> bool {
+        self.0.contains_point(x.0, y.0)
+    }
+}
+
+impl<R: ?Sized> Deref for Typed<'_, R> {
+    type Target = R;
+
+    fn deref(&self) -> &R {
+        self.0
+    }
+}
+
+/// Adds [`TypedRectangle::typed`] to every rectangle, opting into typed units one call at a time.
+pub trait TypedRectangle: RectangleRead {
+    /// Views the rectangle with typed accessors.
+    fn typed(&self) -> Typed<'_, Self> {
+        Typed(self)
+    }
+}
+
+impl<R: RectangleRead + ?Sized> TypedRectangle for R {}
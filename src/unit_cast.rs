@@ -0,0 +1,140 @@
+use num::NumCast;
+
+use crate::{partial_max, partial_min, Rectangle, RectangleRead};
+
+/// Casts one side into another unit, going through `f64` to round fractions with `round`.
+/// Whole values are cast directly so large integers don't lose precision.
+fn cast_side<A: NumCast, B: NumCast>(side: B, round: fn(f64) -> f64) -> Option<A> {
+    match side.to_f64() {
+        Some(float) if float.fract() != 0.0 => A::from(round(float)),
+        _ => A::from(side),
+    }
+}
+
+/// Casts a side of `b` like `cast_side`, but saturates it when it doesn't fit in `A`'s unit.
+/// A side past the bottom of `A`'s range becomes `below`, & one past the top becomes `above`,
+/// where `None` means the side can't meet `a` at all.
+fn saturate_side<A: NumCast, B: NumCast + Copy>(
+    side: B,
+    round: fn(f64) -> f64,
+    below: Option<A>,
+    above: Option<A>,
+) -> Option<A> {
+    if let Some(cast) = cast_side(side, round) {
+        return Some(cast);
+    }
+    match side.to_f64() {
+        Some(float) if float < 0.0 => below,
+        Some(float) if float > 0.0 => above,
+        _ => None,
+    }
+}
+
+/// Returns the bounding box of two rectangles with different units, in the units of the first.
+///
+/// The sides of `b` are cast with [`NumCast`], rounding fractions outwards so the hull still covers all of `b`.
+/// Returns `None` if a side of `b` doesn't fit in `A`'s unit.
+///
+/// # Example
+/// ```
+/// use rect_lib::{union_hull_cast, BasicRectangle, Rectangle, RectangleRead};
+///
+/// #[derive(Clone, Copy)]
+/// struct WorldRect([i64; 4]);
+///
+/// impl RectangleRead for WorldRect {
+///     type Unit = i64;
+///
+///     fn left(&self) -> i64 {
+///         self.0[0]
+///     }
+///
+///     fn right(&self) -> i64 {
+///         self.0[1]
+///     }
+///
+///     fn top(&self) -> i64 {
+///         self.0[2]
+///     }
+///
+///     fn bottom(&self) -> i64 {
+///         self.0[3]
+///     }
+/// }
+///
+/// let tile = BasicRectangle::new_from_sides(0, 2, 2, 0);
+/// assert_eq!(
+///     union_hull_cast(&tile, &WorldRect([1, 5, 3, 1])),
+///     Some(BasicRectangle::new_from_sides(0, 5, 3, 0))
+/// );
+/// assert_eq!(union_hull_cast(&tile, &WorldRect([0, 1 << 40, 1, 0])), None);
+/// ```
+pub fn union_hull_cast<A, B>(a: &A, b: &B) -> Option<A>
+where
+    A: Rectangle,
+    A::Unit: NumCast,
+    B: RectangleRead,
+    B::Unit: NumCast,
+{
+    Some(A::new_from_sides(
+        partial_min(a.left(), cast_side(b.left(), f64::floor)?),
+        partial_max(a.right(), cast_side(b.right(), f64::ceil)?),
+        partial_max(a.top(), cast_side(b.top(), f64::ceil)?),
+        partial_min(a.bottom(), cast_side(b.bottom(), f64::floor)?),
+    ))
+}
+
+/// Returns the intersection of two rectangles with different units, in the units of the first.
+///
+/// The sides of `b` are cast with [`NumCast`], rounding fractions inwards so the intersection stays inside `b`.
+/// Sides of `b` that don't fit in `A`'s unit are clipped to `a` first, so a huge `b` still intersects a small `a`.
+/// Returns `None` if they don't intersect.
+///
+/// # Example
+/// ```
+/// use rect_lib::{intersection_cast, BasicRectangle, Rectangle, RectangleRead};
+///
+/// #[derive(Clone, Copy)]
+/// struct FloatRect([f64; 4]);
+///
+/// impl RectangleRead for FloatRect {
+///     type Unit = f64;
+///
+///     fn left(&self) -> f64 {
+///         self.0[0]
+///     }
+///
+///     fn right(&self) -> f64 {
+///         self.0[1]
+///     }
+///
+///     fn top(&self) -> f64 {
+///         self.0[2]
+///     }
+///
+///     fn bottom(&self) -> f64 {
+///         self.0[3]
+///     }
+/// }
+///
+/// let tile = BasicRectangle::new_from_sides(0, 9, 9, 0);
+/// assert_eq!(
+///     intersection_cast(&tile, &FloatRect([1.5, 4.5, 3.0, -2.0])),
+///     Some(BasicRectangle::new_from_sides(2, 4, 3, 0))
+/// );
+/// ```
+pub fn intersection_cast<A, B>(a: &A, b: &B) -> Option<A>
+where
+    A: Rectangle,
+    A::Unit: NumCast,
+    B: RectangleRead,
+    B::Unit: NumCast,
+{
+    let cast = A::new_from_sides(
+        saturate_side(b.left(), f64::ceil, Some(a.left()), None)?,
+        saturate_side(b.right(), f64::floor, None, Some(a.right()))?,
+        saturate_side(b.top(), f64::floor, None, Some(a.top()))?,
+        saturate_side(b.bottom(), f64::ceil, Some(a.bottom()), None)?,
+    );
+    a.intersection(&cast)
+}
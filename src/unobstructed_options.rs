@@ -0,0 +1,107 @@
+use crate::{BudgetStrategy, Side};
+
+/// How [`Rectangle::unobstructed_subrectangles_with`](crate::Rectangle::unobstructed_subrectangles_with) splits up the free space.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Decomposition {
+    /// Every maximal rectangle, which overlap one another. The same as a plain sweep.
+    #[default]
+    Maximal,
+    /// A disjoint partition of the free space like [`Rectangle::subtract_all`](crate::Rectangle::subtract_all), in reading order.
+    Partition,
+}
+
+/// Everything [`Rectangle::unobstructed_subrectangles_with`](crate::Rectangle::unobstructed_subrectangles_with) can be asked to do
+/// on top of the plain sweep. The default gives the same rectangles as
+/// [`Rectangle::unobstructed_subrectangles`](crate::Rectangle::unobstructed_subrectangles).
+///
+/// New options may be added, so start from [`UnobstructedOptions::default`] & chain the `with_` methods.
+/// They apply in this order:
+/// 1. `decomposition` picks what is found, the overlapping maximal rectangles or a disjoint partition of the free space.
+/// 2. `min_size` & `touching` drop rectangles as they are found, so dropped ones never count against the budget.
+/// 3. `budget` keeps at most that many of what's left, picked by its [`BudgetStrategy`].
+/// 4. `sorted` puts whatever was kept in the canonical rectangle order, highest first then leftmost,
+///    replacing the largest first order of [`BudgetStrategy::LargestArea`]. It never changes which rectangles are kept.
+///
+/// # Example
+/// ```
+/// use rect_lib::{BasicRectangle, BudgetStrategy, Rectangle, RectangleRead, UnobstructedOptions};
+///
+/// let rect = BasicRectangle::new_from_sides(0, 9, 9, 0);
+/// let obstruction = BasicRectangle::new_from_sides(2, 3, 6, 5);
+/// // the strip left of the obstruction is too thin, then the smaller of the bands above & below goes over budget
+/// let options = UnobstructedOptions::default()
+///     .with_min_size(3, 3)
+///     .with_budget(2, BudgetStrategy::LargestArea)
+///     .with_sorted(true);
+///
+/// let (found, truncated) = rect.unobstructed_subrectangles_with(&[&obstruction], &options);
+/// assert_eq!(found, vec![
+///     BasicRectangle::new_from_sides(4, 9, 9, 0),
+///     BasicRectangle::new_from_sides(0, 9, 4, 0),
+/// ]);
+/// assert!(truncated);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[non_exhaustive]
+pub struct UnobstructedOptions<U> {
+    /// The fewest points wide & high a rectangle must be to be kept, `None` keeps any size.
+    pub min_size: Option<(U, U)>,
+    /// Only keep rectangles lying along this side of the parent, `None` keeps them wherever they are.
+    pub touching: Option<Side>,
+    /// How the free space is split into rectangles, see [`Decomposition`].
+    pub decomposition: Decomposition,
+    /// The most rectangles to keep & how to pick them, `None` keeps them all.
+    pub budget: Option<(usize, BudgetStrategy)>,
+    /// Return the rectangles in the canonical rectangle order instead of the order they were found.
+    pub sorted: bool,
+}
+
+impl<U> Default for UnobstructedOptions<U> {
+    fn default() -> Self {
+        Self {
+            min_size: None,
+            touching: None,
+            decomposition: Decomposition::Maximal,
+            budget: None,
+            sorted: false,
+        }
+    }
+}
+
+impl<U> UnobstructedOptions<U> {
+    /// The default options, the same as a plain sweep.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Drops rectangles fewer than `width` points wide or `height` points high.
+    pub fn with_min_size(mut self, width: U, height: U) -> Self {
+        self.min_size = Some((width, height));
+        self
+    }
+
+    /// Drops rectangles not lying along `side` of the parent.
+    pub fn with_touching(mut self, side: Side) -> Self {
+        self.touching = Some(side);
+        self
+    }
+
+    /// Picks how the free space is split, the overlapping maximal rectangles by default.
+    pub fn with_decomposition(mut self, decomposition: Decomposition) -> Self {
+        self.decomposition = decomposition;
+        self
+    }
+
+    /// Keeps at most `max_results` rectangles, picked by `strategy`.
+    pub fn with_budget(mut self, max_results: usize, strategy: BudgetStrategy) -> Self {
+        self.budget = Some((max_results, strategy));
+        self
+    }
+
+    /// Sorts the kept rectangles into the canonical rectangle order.
+    pub fn with_sorted(mut self, sorted: bool) -> Self {
+        self.sorted = sorted;
+        self
+    }
+}
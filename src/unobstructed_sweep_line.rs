@@ -0,0 +1,555 @@
+use core::cmp::Reverse;
+use core::ops::ControlFlow;
+use num::One;
+use std::collections::BinaryHeap;
+
+use crate::trace::{NoObserver, SweepObserver};
+use crate::{
+    canonical_key, partial_max, partial_min, points_in, scanline, Cached, Decomposition, Rectangle,
+    RectangleRead, Side, UnobstructedOptions,
+};
+
+/// A rectangle that has not been obstructed yet
+#[derive(Clone)]
+struct UnfinishedRect<T: Rectangle> {
+    left: T::Unit,
+    top: T::Unit,
+    bottom: T::Unit,
+}
+
+/// Cleans up obstructions before a sweep, so any rectangles whatsoever can be passed in.
+///
+/// Afterwards:
+/// - every obstruction is clipped to within `parent`, & those not overlapping it are dropped;
+/// - there are no exact duplicates;
+/// - they are sorted by top in descending order, as the sweep expects, then by left, bottom, & right so the order is deterministic.
+///
+/// [`Rectangle::unobstructed_subrectangles`] calls this itself, it is exposed for callers caching obstructions between sweeps.
+///
+/// # Example
+/// ```
+/// use rect_lib::{normalize_obstructions, BasicRectangle, Rectangle, RectangleRead};
+///
+/// let parent = BasicRectangle::new_from_sides(0, 9, 9, 0);
+/// let obstructions = [
+///     BasicRectangle::new_from_sides(2, 3, 3, 2),
+///     BasicRectangle::new_from_sides(8, 12, 12, 8),
+///     BasicRectangle::new_from_sides(20, 21, 1, 0),
+///     BasicRectangle::new_from_sides(2, 3, 3, 2),
+/// ];
+/// assert_eq!(
+///     normalize_obstructions(&parent, obstructions),
+///     vec![
+///         BasicRectangle::new_from_sides(8, 9, 9, 8),
+///         BasicRectangle::new_from_sides(2, 3, 3, 2),
+///     ]
+/// );
+/// ```
+pub fn normalize_obstructions<R: Rectangle>(
+    parent: &R,
+    obstructions: impl IntoIterator<Item = R>,
+) -> Vec<R>
+where
+    R::Unit: Ord,
+{
+    let mut obstructions: Vec<R> = obstructions
+        .into_iter()
+        .filter_map(|rect| parent.intersection(&rect))
+        .collect();
+
+    // every side is in the key, so exact duplicates end up next to each other
+    obstructions.sort_unstable_by_key(canonical_key);
+    obstructions.dedup_by_key(|rect| (rect.left(), rect.right(), rect.top(), rect.bottom()));
+
+    obstructions
+}
+
+/// This algorithm identifies all unique unobstructed sub-rectangles within `parent` by comparing it against a list of obstructions.
+/// See [`Rectangle::unobstructed_subrectangles`] & the explanation in the repository for how it works.
+///
+/// Each finished rectangle is passed to `emit`, which can stop the sweep early by returning `ControlFlow::Break`.
+pub(crate) fn sweep<R: Rectangle>(
+    parent: &R,
+    obstructions: &[&impl RectangleRead<Unit = R::Unit>],
+    emit: impl FnMut(R) -> ControlFlow<()>,
+) where
+    R::Unit: Ord,
+{
+    sweep_observed(parent, obstructions, &mut NoObserver, emit);
+}
+
+/// The same as [`sweep`], telling `observer` about every line, gap, & rectangle along the way.
+pub(crate) fn sweep_observed<R: Rectangle>(
+    parent: &R,
+    obstructions: &[&impl RectangleRead<Unit = R::Unit>],
+    observer: &mut impl SweepObserver<R::Unit>,
+    mut emit: impl FnMut(R) -> ControlFlow<()>,
+) where
+    R::Unit: Ord,
+{
+    let mut state = SweepState::new(*parent);
+    // each obstruction's sides are read exactly once here, the sweep works from the snapshots
+    state.add_obstructions(
+        obstructions.iter().map(|rect| {
+            Cached::new_from_sides(rect.left(), rect.right(), rect.top(), rect.bottom())
+        }),
+    );
+    let _ = state.close(observer, &mut emit);
+
+    // Quod Erat Demonstrandum
+}
+
+/// A sweep that can be paused at any x & resumed later with more obstructions,
+/// for parents too big to hold every obstruction in memory at once.
+///
+/// Feeding the obstructions in slices from left to right finds the same rectangles as
+/// [`Rectangle::unobstructed_subrectangles`] would with all of them, though not necessarily in the same order.
+/// Rectangles are handed to the callback as soon as they are obstructed, the rest come out of [`SweepState::finish`].
+///
+/// # Example
+/// ```
+/// use rect_lib::{BasicRectangle, Rectangle, RectangleRead, SweepState};
+///
+/// let parent = BasicRectangle::new_from_sides(0, 9, 9, 0);
+/// let mut state = SweepState::new(parent);
+/// let mut found = Vec::new();
+///
+/// state.advance_to(5, &[BasicRectangle::new_from_sides(2, 3, 9, 5)], |rect| found.push(rect));
+/// state.advance_to(10, &[BasicRectangle::new_from_sides(7, 8, 4, 0)], |rect| found.push(rect));
+/// found.extend(state.finish());
+///
+/// assert_eq!(found.len(), parent.unobstructed_subrectangles(&[
+///     &BasicRectangle::new_from_sides(2, 3, 9, 5),
+///     &BasicRectangle::new_from_sides(7, 8, 4, 0),
+/// ]).len());
+/// ```
+#[derive(Clone)]
+pub struct SweepState<R: Rectangle> {
+    parent: Cached<R>,
+    /// Normalized obstructions that can still affect lines right of `swept_to`, with their sides snapshotted.
+    obstructions: Vec<Cached<R>>,
+    /// Rectangles that have not been obstructed yet.
+    active: Vec<UnfinishedRect<R>>,
+    /// Every line left of this has been swept.
+    swept_to: R::Unit,
+}
+
+impl<R: Rectangle> SweepState<R> {
+    /// Starts a sweep over `parent` with no obstructions yet.
+    pub fn new(parent: R) -> Self {
+        let parent = Cached::new(parent);
+        Self {
+            parent,
+            obstructions: Vec::new(),
+            active: Vec::new(),
+            swept_to: parent.left(),
+        }
+    }
+
+    /// Adds obstructions & sweeps every line left of `x`, passing each rectangle that is finished to `emit`.
+    ///
+    /// The part of any obstruction left of where the last call stopped has already been swept past & is ignored,
+    /// so obstructions should be added no later than the call reaching their left side.
+    pub fn advance_to(&mut self, x: R::Unit, new_obstructions: &[R], mut emit: impl FnMut(R))
+    where
+        R::Unit: Ord,
+    {
+        self.add_obstructions(new_obstructions.iter().copied().map(Cached::new));
+        let _ = self.sweep_until(x, &mut NoObserver, &mut |rect| {
+            emit(rect);
+            ControlFlow::Continue(())
+        });
+    }
+
+    /// Sweeps the rest of the parent, returning every rectangle not already emitted.
+    pub fn finish(mut self) -> Vec<R>
+    where
+        R::Unit: Ord,
+    {
+        let mut finished = Vec::new();
+        let _ = self.close(&mut NoObserver, &mut |rect| {
+            finished.push(rect);
+            ControlFlow::Continue(())
+        });
+        finished
+    }
+
+    /// Clips the new obstructions to the part not swept yet & merges them in.
+    fn add_obstructions(&mut self, new_obstructions: impl IntoIterator<Item = Cached<R>>)
+    where
+        R::Unit: Ord,
+    {
+        let swept_to = self.swept_to;
+        let unswept = new_obstructions
+            .into_iter()
+            .filter(|rect| rect.right() >= swept_to)
+            .map(|rect| {
+                Cached::new_from_sides(
+                    partial_max(rect.left(), swept_to),
+                    rect.right(),
+                    rect.top(),
+                    rect.bottom(),
+                )
+            });
+
+        // clip, dedup, & sort the obstructions by top position
+        let obstructions: Vec<Cached<R>> = self.obstructions.drain(..).chain(unswept).collect();
+        self.obstructions = normalize_obstructions(&self.parent, obstructions);
+    }
+
+    /// Sweeps every line left of `x`.
+    fn sweep_until(
+        &mut self,
+        x: R::Unit,
+        observer: &mut impl SweepObserver<R::Unit>,
+        emit: &mut impl FnMut(R) -> ControlFlow<()>,
+    ) -> ControlFlow<()>
+    where
+        R::Unit: Ord,
+    {
+        // Section 1: collect all lines that need to be checked for gaps
+        let lines = scanline::sweep_lines(
+            &self.parent,
+            self.obstructions
+                .iter()
+                .map(|rect| (rect.left(), rect.right())),
+        );
+        for line in lines {
+            if self.swept_to <= line.x && line.x < x {
+                self.sweep_line(&line, observer, emit)?;
+            }
+        }
+
+        // obstructions ending before the next line can't matter any more
+        self.swept_to = partial_max(self.swept_to, x);
+        let swept_to = self.swept_to;
+        self.obstructions
+            .retain(|rect| rect.right() + R::Unit::one() >= swept_to);
+        ControlFlow::Continue(())
+    }
+
+    /// Sweeps the rest of the parent & closes the remaining rectangles.
+    fn close(
+        &mut self,
+        observer: &mut impl SweepObserver<R::Unit>,
+        emit: &mut impl FnMut(R) -> ControlFlow<()>,
+    ) -> ControlFlow<()>
+    where
+        R::Unit: Ord,
+    {
+        self.sweep_until(self.parent.right() + R::Unit::one(), observer, emit)?;
+
+        // Section 4: now that we have checked all lines we can close any remaining rectangles
+        for rect in self.active.drain(..) {
+            observer.on_close(rect.left, self.parent.right(), rect.top, rect.bottom);
+            emit(R::new_from_sides(
+                rect.left,
+                self.parent.right(),
+                rect.top,
+                rect.bottom,
+            ))?;
+        }
+        ControlFlow::Continue(())
+    }
+
+    fn sweep_line(
+        &mut self,
+        line: &scanline::Line<R::Unit>,
+        observer: &mut impl SweepObserver<R::Unit>,
+        emit: &mut impl FnMut(R) -> ControlFlow<()>,
+    ) -> ControlFlow<()>
+    where
+        R::Unit: Ord,
+    {
+        observer.on_line(line.x, line.opens);
+
+        // Section 2: collect all gaps between obstructions
+        // filter out obstructions that don't intersect the current line
+        let gaps = scanline::gaps_between(
+            &self.parent,
+            self.obstructions
+                .iter()
+                .filter(|rect| rect.left() <= line.x && line.x <= rect.right())
+                .map(|rect| (rect.top(), rect.bottom())),
+        );
+        // alright, we have all the gaps
+        for gap in &gaps {
+            observer.on_gap(gap.top, gap.bottom);
+        }
+
+        let active_rectangles = &mut self.active;
+
+        // the widest rectangles go first so their sub rectangles win the uniqueness checks
+        active_rectangles.sort_unstable_by_key(|rect| rect.left);
+
+        // once the caller has seen enough we stop emitting
+        let mut stopped = false;
+
+        // Section 3: if the current line closes we finish rectangles
+        if !line.opens {
+            let mut new_active_rectangles: Vec<UnfinishedRect<R>> = Vec::new();
+
+            *active_rectangles = active_rectangles
+                .iter()
+                .filter(|rect| {
+                    // if the current rect fits within a gap we can keep it
+                    if gaps
+                        .iter()
+                        .any(|gap| gap.top >= rect.top && rect.bottom >= gap.bottom)
+                    {
+                        // on to the next active rect
+                        return true;
+                    }
+
+                    // if it is obstructed we can close it
+                    if !stopped {
+                        observer.on_close(
+                            rect.left,
+                            line.x - R::Unit::one(),
+                            rect.top,
+                            rect.bottom,
+                        );
+                        stopped = emit(R::new_from_sides(
+                            rect.left,               // left
+                            line.x - R::Unit::one(), // right
+                            rect.top,                // top
+                            rect.bottom,             // bottom
+                        ))
+                        .is_break();
+                    }
+
+                    // check if there are any gaps within the current rect
+                    for gap in gaps
+                        .iter()
+                        .filter(|gap| gap.bottom <= rect.top && rect.bottom <= gap.top)
+                    {
+                        let top_limit = partial_min(rect.top, gap.top);
+                        let bottom_limit = partial_max(rect.bottom, gap.bottom);
+
+                        // make sure its unique
+                        if !active_rectangles
+                            .iter()
+                            .chain(new_active_rectangles.iter())
+                            .any(|rect| top_limit == rect.top && bottom_limit == rect.bottom)
+                        {
+                            observer.on_open(rect.left, top_limit, bottom_limit);
+                            new_active_rectangles.push(UnfinishedRect {
+                                left: rect.left,
+                                top: top_limit,
+                                bottom: bottom_limit,
+                            });
+                        }
+                    }
+
+                    // make sure to remove it from active
+                    false
+                })
+                .cloned()
+                .collect();
+
+            // add any new sub rectangles
+            active_rectangles.append(&mut new_active_rectangles);
+
+            if stopped {
+                return ControlFlow::Break(());
+            }
+        }
+
+        // Section 3 & 1/2: create a new rect for each gap that doesn't have one yet
+        for gap in gaps {
+            // make sure its unique
+            if !active_rectangles
+                .iter()
+                .any(|rect| gap.top == rect.top && gap.bottom == rect.bottom)
+            {
+                observer.on_open(line.x, gap.top, gap.bottom);
+                active_rectangles.push(UnfinishedRect {
+                    left: line.x,
+                    top: gap.top,
+                    bottom: gap.bottom,
+                });
+            }
+        }
+
+        ControlFlow::Continue(())
+    }
+}
+
+/// Which rectangles to keep when `unobstructed_subrectangles_budgeted` runs out of budget.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BudgetStrategy {
+    /// Keep the rectangles covering the most points, returned largest first.
+    LargestArea,
+    /// Keep the first rectangles the sweep finds & stop as soon as the budget is spent.
+    FirstFound,
+}
+
+/// A rectangle competing for a place in the budget, ordered by size then by how early it was found.
+struct Candidate<R: Rectangle> {
+    points: R::Unit,
+    found: usize,
+    rect: R,
+}
+
+impl<R: Rectangle> Candidate<R> {
+    fn key(&self) -> (R::Unit, Reverse<usize>) {
+        (self.points, Reverse(self.found))
+    }
+}
+
+impl<R: Rectangle> PartialEq for Candidate<R> {
+    fn eq(&self, other: &Self) -> bool {
+        self.key() == other.key()
+    }
+}
+
+impl<R: Rectangle> Eq for Candidate<R> {}
+
+impl<R: Rectangle> PartialOrd for Candidate<R>
+where
+    R::Unit: Ord,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<R: Rectangle> Ord for Candidate<R>
+where
+    R::Unit: Ord,
+{
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.key().cmp(&other.key())
+    }
+}
+
+/// Collects the rectangles as they are found, keeping at most the budget if there is one.
+struct Collector<R: Rectangle> {
+    budget: Option<(usize, BudgetStrategy)>,
+    kept: Vec<R>,
+    /// a min heap of the best candidates so far with `LargestArea`, the smallest is evicted first
+    best: BinaryHeap<Reverse<Candidate<R>>>,
+    found: usize,
+    truncated: bool,
+}
+
+impl<R: Rectangle> Collector<R>
+where
+    R::Unit: Ord,
+{
+    fn new(budget: Option<(usize, BudgetStrategy)>) -> Self {
+        Self {
+            budget,
+            kept: Vec::new(),
+            best: BinaryHeap::new(),
+            found: 0,
+            truncated: false,
+        }
+    }
+
+    /// Takes the next rectangle found, breaking once nothing more can be kept.
+    fn offer(&mut self, rect: R) -> ControlFlow<()> {
+        match self.budget {
+            None => self.kept.push(rect),
+            Some((max_results, BudgetStrategy::FirstFound)) => {
+                if self.kept.len() == max_results {
+                    self.truncated = true;
+                    return ControlFlow::Break(());
+                }
+                self.kept.push(rect);
+            }
+            Some((max_results, BudgetStrategy::LargestArea)) => {
+                let candidate = Candidate {
+                    points: points_in(&rect),
+                    found: self.found,
+                    rect,
+                };
+                self.found += 1;
+
+                if self.best.len() < max_results {
+                    self.best.push(Reverse(candidate));
+                } else {
+                    self.truncated = true;
+                    if self
+                        .best
+                        .peek()
+                        .is_some_and(|smallest| candidate > smallest.0)
+                    {
+                        self.best.pop();
+                        self.best.push(Reverse(candidate));
+                    }
+                }
+            }
+        }
+        ControlFlow::Continue(())
+    }
+
+    /// The kept rectangles & whether any were dropped for the budget.
+    fn finish(mut self) -> (Vec<R>, bool) {
+        // the smallest come out of the min heap first, so reverse for largest first
+        self.kept.extend(
+            self.best
+                .into_sorted_vec()
+                .into_iter()
+                .map(|candidate| candidate.0.rect),
+        );
+        (self.kept, self.truncated)
+    }
+}
+
+/// Finds the rectangles with every option applied, returning them & whether any were dropped for the budget.
+/// See [`UnobstructedOptions`] for the order the options apply in.
+pub(crate) fn with_options<R: Rectangle>(
+    parent: &R,
+    obstructions: &[&impl RectangleRead<Unit = R::Unit>],
+    options: &UnobstructedOptions<R::Unit>,
+) -> (Vec<R>, bool)
+where
+    R::Unit: Ord,
+{
+    let one = R::Unit::one();
+    // the parent is only read again if a rectangle has to lie along it
+    let touching = options.touching.map(|side| match side {
+        Side::Left => (side, parent.left()),
+        Side::Right => (side, parent.right()),
+        Side::Top => (side, parent.top()),
+        Side::Bottom => (side, parent.bottom()),
+    });
+    let keep = |rect: &R| {
+        let big_enough = options.min_size.is_none_or(|(width, height)| {
+            rect.width() + one >= width && rect.height() + one >= height
+        });
+        let along = touching.is_none_or(|(side, line)| match side {
+            Side::Left => rect.left() == line,
+            Side::Right => rect.right() == line,
+            Side::Top => rect.top() == line,
+            Side::Bottom => rect.bottom() == line,
+        });
+        big_enough && along
+    };
+
+    let mut collector = Collector::new(options.budget);
+    let mut offer = |rect: R| {
+        if keep(&rect) {
+            collector.offer(rect)
+        } else {
+            ControlFlow::Continue(())
+        }
+    };
+    match options.decomposition {
+        Decomposition::Maximal => sweep(parent, obstructions, &mut offer),
+        Decomposition::Partition => {
+            for piece in parent.subtract_all(obstructions) {
+                if offer(piece).is_break() {
+                    break;
+                }
+            }
+        }
+    }
+
+    let (mut kept, truncated) = collector.finish();
+    if options.sorted {
+        kept.sort_by_key(canonical_key);
+    }
+    (kept, truncated)
+}
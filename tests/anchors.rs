@@ -0,0 +1,44 @@
+use rect_lib::{BasicRectangle, Rectangle};
+
+#[test]
+fn test_anchor_points_single_obstruction() {
+    let bin = BasicRectangle::new_from_sides(0, 9, 9, 0);
+    let placed = BasicRectangle::new_from_sides(2, 4, 5, 3);
+
+    assert_eq!(bin.anchor_points(&[&placed]), vec![(0, 0), (5, 3), (2, 6)]);
+
+    // anchors outside the bin are dropped
+    let corner = BasicRectangle::new_from_sides(7, 9, 9, 7);
+    assert_eq!(bin.anchor_points(&[&corner]), vec![(0, 0)]);
+}
+
+#[test]
+fn test_max_extent_at_is_free_and_maximal() {
+    let bin = BasicRectangle::new_from_sides(0, 9, 9, 0);
+    let obstructions = [
+        BasicRectangle::new_from_sides(2, 4, 5, 3),
+        BasicRectangle::new_from_sides(7, 9, 1, 0),
+    ];
+    let obstructions: Vec<&BasicRectangle> = obstructions.iter().collect();
+
+    for anchor in bin.anchor_points(&obstructions) {
+        let extent = bin.max_extent_at(&obstructions, anchor).unwrap();
+        assert_eq!((extent.left(), extent.bottom()), anchor);
+        assert!(bin.contains_rectangle(&extent));
+        assert!(obstructions.iter().all(|o| !extent.overlaps(*o)));
+
+        // growing right or up hits an obstruction or leaves the bin
+        let wider =
+            BasicRectangle::new_from_sides(anchor.0, extent.right() + 1, extent.top(), anchor.1);
+        let taller =
+            BasicRectangle::new_from_sides(anchor.0, extent.right(), extent.top() + 1, anchor.1);
+        for grown in [wider, taller] {
+            assert!(
+                !bin.contains_rectangle(&grown) || obstructions.iter().any(|o| grown.overlaps(*o))
+            );
+        }
+    }
+
+    assert_eq!(bin.max_extent_at(&obstructions, (3, 4)), None);
+    assert_eq!(bin.max_extent_at(&obstructions, (10, 4)), None);
+}
@@ -0,0 +1,127 @@
+use rect_lib::{distribute, stack, Axis, BasicRectangle, Justify, Rectangle, RectangleRead};
+
+#[test]
+fn test_axis_accessors() {
+    let rect = BasicRectangle::new_from_sides(-2, 5, 7, 3);
+
+    assert_eq!(rect.min_along(Axis::X), rect.left());
+    assert_eq!(rect.max_along(Axis::X), rect.right());
+    assert_eq!(rect.extent_along(Axis::X), rect.width());
+
+    assert_eq!(rect.min_along(Axis::Y), rect.bottom());
+    assert_eq!(rect.max_along(Axis::Y), rect.top());
+    assert_eq!(rect.extent_along(Axis::Y), rect.height());
+}
+
+#[test]
+fn test_translate_along() {
+    let rect = BasicRectangle::new_from_sides(-2, 5, 7, 3);
+    assert_eq!(rect.translate_along(Axis::X, 3), rect.translate(3, 0));
+    assert_eq!(rect.translate_along(Axis::Y, -4), rect.translate(0, -4));
+}
+
+#[test]
+fn test_split_along() {
+    let rect = BasicRectangle::new_from_sides(-2, 5, 7, 3);
+    for at in -3..=8 {
+        assert_eq!(rect.split_along(Axis::X, at), rect.split_x(at));
+        assert_eq!(rect.split_along(Axis::Y, at), rect.split_y(at));
+    }
+}
+
+#[test]
+fn test_split_x() {
+    let rect = BasicRectangle::new_from_sides(0, 4, 1, 0);
+    let (left, right) = rect.split_x(4).expect("4 is within the rectangle");
+    assert_eq!(left, BasicRectangle::new_from_sides(0, 3, 1, 0));
+    assert_eq!(right, BasicRectangle::new_from_sides(4, 4, 1, 0));
+
+    assert!(rect.split_x(0).is_none());
+    assert!(rect.split_x(5).is_none());
+}
+
+#[test]
+fn test_split_y() {
+    let rect = BasicRectangle::new_from_sides(0, 1, 4, 0);
+    let (bottom, top) = rect.split_y(1).expect("1 is within the rectangle");
+    assert_eq!(bottom, BasicRectangle::new_from_sides(0, 1, 0, 0));
+    assert_eq!(top, BasicRectangle::new_from_sides(0, 1, 4, 1));
+
+    assert!(rect.split_y(0).is_none());
+    assert!(rect.split_y(5).is_none());
+}
+
+#[test]
+fn test_stack_horizontally() {
+    let start = BasicRectangle::new_from_sides(5, 20, 10, 0);
+    let row = stack(&start, &[(2, 3), (0, 1), (4, 4)], Axis::X, 2);
+    assert_eq!(
+        row,
+        vec![
+            BasicRectangle::new_from_sides(5, 7, 10, 7),
+            BasicRectangle::new_from_sides(10, 10, 10, 9),
+            BasicRectangle::new_from_sides(13, 17, 10, 6),
+        ]
+    );
+
+    // two empty units between each
+    for pair in row.windows(2) {
+        assert_eq!(pair[1].left() - pair[0].right() - 1, 2);
+    }
+    assert!(stack(&start, &[], Axis::X, 2).is_empty());
+}
+
+fn three_items() -> [BasicRectangle; 3] {
+    [
+        BasicRectangle::new_from_sides(0, 1, 3, 2),
+        BasicRectangle::new_from_sides(0, 3, 1, 0),
+        BasicRectangle::new_from_sides(0, 0, 2, 0),
+    ]
+}
+
+#[test]
+fn test_distribute_space_between() {
+    // 20 points wide, the items take 2 + 4 + 1 leaving 13 free
+    let container = BasicRectangle::new_from_sides(10, 29, 5, 0);
+    let mut items = three_items();
+    distribute(&container, &mut items, Axis::X, Justify::SpaceBetween);
+
+    let lefts: Vec<i32> = items.iter().map(|item| item.left()).collect();
+    assert_eq!(lefts, vec![10, 18, 29]);
+    assert_eq!(items[2].right(), container.right());
+    // sizes & vertical positions are untouched
+    for (item, original) in items.iter().zip(three_items()) {
+        assert_eq!(item.width(), original.width());
+        assert_eq!(
+            (item.top(), item.bottom()),
+            (original.top(), original.bottom())
+        );
+    }
+}
+
+#[test]
+fn test_distribute_center() {
+    let container = BasicRectangle::new_from_sides(0, 5, 20, 1);
+    let mut items = three_items();
+    distribute(&container, &mut items, Axis::Y, Justify::Center);
+
+    // 20 points tall, the items take 2 + 2 + 3 leaving 13 free, the odd one rounds to the end
+    let sides: Vec<(i32, i32)> = items
+        .iter()
+        .map(|item| (item.top(), item.bottom()))
+        .collect();
+    assert_eq!(sides, vec![(14, 13), (12, 11), (10, 8)]);
+    assert_eq!(
+        (
+            container.top() - items[0].top(),
+            items[2].bottom() - container.bottom()
+        ),
+        (6, 7)
+    );
+
+    let mut items = three_items();
+    distribute(&container, &mut items, Axis::Y, Justify::Start);
+    assert_eq!(items[0].top(), 20);
+    distribute(&container, &mut items, Axis::Y, Justify::End);
+    assert_eq!(items[2].bottom(), 1);
+}
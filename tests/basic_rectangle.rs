@@ -1,4 +1,9 @@
-use rect_lib::{BasicRectangle, Rectangle};
+use rect_lib::{
+    BasicRectangle, FillOrder, FrameOrder, OverlapKind, RectError, Rectangle, RectangleRead,
+};
+
+mod common;
+use common::UnsignedRect;
 
 #[test]
 fn test_basic_rectangle() {
@@ -99,3 +104,1085 @@ fn test_contains_rectangle() {
     assert!(rect1.contains_rectangle(&rect2));
     assert!(!rect2.contains_rectangle(&rect1));
 }
+
+#[test]
+fn test_from_corners() {
+    let expected = BasicRectangle::new_from_sides(0, 4, 3, 0);
+    // drag from each corner to the opposite one
+    assert_eq!(BasicRectangle::from_corners((0, 3), (4, 0)), expected);
+    assert_eq!(BasicRectangle::from_corners((4, 3), (0, 0)), expected);
+    assert_eq!(BasicRectangle::from_corners((0, 0), (4, 3)), expected);
+    assert_eq!(BasicRectangle::from_corners((4, 0), (0, 3)), expected);
+}
+
+#[test]
+fn test_unit_at() {
+    let rect = BasicRectangle::unit_at(1, 2);
+    assert_eq!(rect.left(), 1);
+    assert_eq!(rect.right(), 1);
+    assert_eq!(rect.top(), 2);
+    assert_eq!(rect.bottom(), 2);
+    assert_eq!(rect.area(), 0);
+}
+
+#[test]
+fn test_spanning_points() {
+    let rect = BasicRectangle::spanning_points(vec![(0, 0), (5, -1), (2, 3)]);
+    assert_eq!(rect, Some(BasicRectangle::new_from_sides(0, 5, 3, -1)));
+
+    let single = BasicRectangle::spanning_points([(4, 4)]);
+    assert_eq!(single, Some(BasicRectangle::unit_at(4, 4)));
+
+    assert_eq!(BasicRectangle::spanning_points(Vec::new()), None);
+}
+
+#[test]
+fn test_from_row_and_column() {
+    let row = BasicRectangle::from_row(3, 1, 4);
+    assert_eq!(row, BasicRectangle::new_from_sides(1, 4, 3, 3));
+    assert_eq!(row.height(), 0);
+
+    let column = BasicRectangle::from_column(3, 4, 1);
+    assert_eq!(column, BasicRectangle::new_from_sides(3, 3, 4, 1));
+    assert_eq!(column.width(), 0);
+}
+
+#[test]
+fn test_project_onto_axis_f64() {
+    let rect = BasicRectangle::new_from_sides(1, 3, 4, 2);
+
+    // the x-axis recovers left & right
+    assert_eq!(rect.project_onto_axis_f64((1.0, 0.0)), (1.0, 3.0));
+    // the y-axis recovers bottom & top
+    assert_eq!(rect.project_onto_axis_f64((0.0, 1.0)), (2.0, 4.0));
+
+    // 45 degrees: x + y scaled by 1/sqrt(2)
+    let diagonal = std::f64::consts::FRAC_1_SQRT_2;
+    let (min, max) = rect.project_onto_axis_f64((diagonal, diagonal));
+    assert!((min - 3.0 * diagonal).abs() < 1e-9);
+    assert!((max - 7.0 * diagonal).abs() < 1e-9);
+}
+
+#[test]
+fn test_overlap_with_depth() {
+    let rect1 = BasicRectangle::new_from_sides(0, 4, 4, 0);
+    let rect2 = BasicRectangle::new_from_sides(3, 6, 2, -1);
+    let (region, depth_x, depth_y) = rect1
+        .overlap_with_depth(&rect2)
+        .expect("Rectangles do not overlap");
+    assert_eq!(region, BasicRectangle::new_from_sides(3, 4, 2, 0));
+    assert_eq!(depth_x, 2);
+    assert_eq!(depth_y, 3);
+
+    // moving by the depth separates them
+    assert!(!rect1.overlaps(&rect2.translate(depth_x, 0)));
+    assert!(!rect1.overlaps(&rect2.translate(0, -depth_y)));
+
+    let rect3 = BasicRectangle::new_from_sides(5, 6, 6, 5);
+    assert!(rect1.overlap_with_depth(&rect3).is_none());
+}
+
+#[test]
+fn test_try_from_xywh_valid() {
+    let rect = BasicRectangle::try_from_xywh(-1, 2, 3, 3)
+        .expect("valid size")
+        .expect("not empty");
+    assert_eq!(rect.left(), -1);
+    assert_eq!(rect.right(), 1);
+    assert_eq!(rect.top(), 2);
+    assert_eq!(rect.bottom(), 0);
+}
+
+#[test]
+fn test_try_from_xywh_empty() {
+    assert_eq!(BasicRectangle::try_from_xywh(0, 0, 0, 5), Ok(None));
+    assert_eq!(BasicRectangle::try_from_xywh(0, 0, 5, 0), Ok(None));
+}
+
+#[test]
+fn test_try_from_xywh_negative() {
+    assert_eq!(
+        BasicRectangle::try_from_xywh(0, 0, -1, 5),
+        Err(RectError::NegativeSize)
+    );
+    assert_eq!(
+        BasicRectangle::try_from_xywh(0, 0, 5, -1),
+        Err(RectError::NegativeSize)
+    );
+}
+
+#[test]
+fn test_try_from_xywh_overflow() {
+    // the largest rectangles that still fit
+    let rect = BasicRectangle::try_from_xywh(i32::MAX - 1, 0, 1, 1)
+        .expect("fits")
+        .expect("not empty");
+    assert_eq!(rect.right(), i32::MAX - 1);
+    let rect = BasicRectangle::try_from_xywh(0, i32::MIN + 1, 1, 1)
+        .expect("fits")
+        .expect("not empty");
+    assert_eq!(rect.bottom(), i32::MIN + 1);
+
+    assert_eq!(
+        BasicRectangle::try_from_xywh(i32::MAX, 0, 1, 1),
+        Err(RectError::Overflow)
+    );
+    assert_eq!(
+        BasicRectangle::try_from_xywh(0, i32::MIN, 1, 1),
+        Err(RectError::Overflow)
+    );
+    assert_eq!(
+        BasicRectangle::try_from_xywh(1, 0, i32::MAX, 1),
+        Err(RectError::Overflow)
+    );
+}
+
+#[test]
+fn test_contains_sides() {
+    let rect = BasicRectangle::new_from_sides(0, 2, 2, 0);
+    assert!(rect.contains_sides(1, 2, 2, 1));
+    assert!(rect.contains_sides(0, 2, 2, 0));
+    assert!(!rect.contains_sides(-1, 2, 2, 0));
+    assert!(!rect.contains_sides(0, 2, 3, 0));
+}
+
+#[test]
+fn test_overlaps_sides() {
+    let rect = BasicRectangle::new_from_sides(0, 1, 1, 0);
+    assert!(rect.overlaps_sides(1, 2, 2, 1));
+    assert!(!rect.overlaps_sides(2, 3, 3, 2));
+    assert!(!rect.overlaps_sides(0, 1, 3, 2));
+}
+
+#[test]
+fn test_snap_position_to_grid_keeps_size() {
+    for (x, y) in [(3, 9), (-3, -9), (0, 0), (5, 5), (-6, 7)] {
+        let rect = BasicRectangle::new_from_sides(x, x + 5, y, y - 3);
+        let snapped = rect.snap_position_to_grid(4, 3);
+
+        assert_eq!(snapped.width(), rect.width());
+        assert_eq!(snapped.height(), rect.height());
+        assert_eq!(snapped.left() % 4, 0);
+        assert_eq!(snapped.top() % 3, 0);
+        // the nearest grid point is never more than half a cell away
+        assert!((snapped.left() - rect.left()).abs() <= 2);
+        assert!((snapped.top() - rect.top()).abs() <= 1);
+    }
+}
+
+#[test]
+fn test_connection_points_horizontal() {
+    let rect = BasicRectangle::new_from_sides(0, 3, 5, 0);
+    let other = BasicRectangle::new_from_sides(8, 10, 9, 3);
+    // the rows 3..=5 are shared, so both points sit on row 4
+    assert_eq!(rect.connection_points(&other), ((3, 4), (8, 4)));
+    assert_eq!(other.connection_points(&rect), ((8, 4), (3, 4)));
+}
+
+#[test]
+fn test_connection_points_diagonal() {
+    let rect = BasicRectangle::new_from_sides(0, 3, 3, 0);
+    let other = BasicRectangle::new_from_sides(6, 8, 9, 7);
+    // nothing is shared, so the nearest corners are used
+    assert_eq!(rect.connection_points(&other), ((3, 3), (6, 7)));
+    assert_eq!(other.connection_points(&rect), ((6, 7), (3, 3)));
+
+    let below_left = BasicRectangle::new_from_sides(-5, -2, -3, -6);
+    assert_eq!(rect.connection_points(&below_left), ((0, 0), (-2, -3)));
+}
+
+#[test]
+fn test_connection_points_overlapping() {
+    let rect = BasicRectangle::new_from_sides(0, 9, 9, 0);
+    let on_boundary = |rect: &BasicRectangle, (x, y): (i32, i32)| {
+        rect.contains_point(x, y)
+            && (x == rect.left() || x == rect.right() || y == rect.top() || y == rect.bottom())
+    };
+
+    // inside, the right sides are the closest
+    let inner = BasicRectangle::new_from_sides(3, 8, 6, 2);
+    assert_eq!(rect.connection_points(&inner), ((9, 4), (8, 4)));
+    assert_eq!(inner.connection_points(&rect), ((8, 4), (9, 4)));
+
+    // crossing, the left side of `rect` is one past the left side of `crossing`
+    let crossing = BasicRectangle::new_from_sides(-1, 4, 12, 5);
+    assert_eq!(rect.connection_points(&crossing), ((0, 7), (-1, 7)));
+
+    // sharing a side, both points are the same point on it
+    let flush = BasicRectangle::new_from_sides(4, 9, 5, 2);
+    assert_eq!(rect.connection_points(&flush), ((9, 3), (9, 3)));
+    assert_eq!(rect.connection_points(&rect), ((0, 4), (0, 4)));
+
+    for other in [inner, crossing, flush, rect] {
+        let (on_self, on_other) = rect.connection_points(&other);
+        assert!(on_boundary(&rect, on_self));
+        assert!(on_boundary(&other, on_other));
+    }
+}
+
+#[test]
+fn test_lerp_size_centered_ends() {
+    let rect = BasicRectangle::new_from_sides(10, 14, 10, 6);
+    let target = BasicRectangle::new_from_sides(-50, -42, 3, 1);
+    let center = |r: &BasicRectangle| (r.left() + r.right(), r.top() + r.bottom());
+
+    // nothing changes at the start
+    assert_eq!(rect.lerp_size_centered(&target, 0.0), rect);
+
+    // the target's size at the end, but still around our center
+    let end = rect.lerp_size_centered(&target, 1.0);
+    assert_eq!(
+        (end.width(), end.height()),
+        (target.width(), target.height())
+    );
+    assert_eq!(center(&end), center(&rect));
+
+    // t is clamped
+    assert_eq!(rect.lerp_size_centered(&target, 2.0), end);
+}
+
+#[test]
+fn test_lerp_size_centered_unsigned_growth() {
+    // growing would go below zero if the change were worked out as a negative first
+    let rect = UnsignedRect::new_from_sides(10, 12, 12, 10);
+    let target = UnsignedRect::new_from_sides(0, 7, 9, 0);
+    assert_eq!(
+        rect.lerp_size_centered(&target, 1.0),
+        UnsignedRect::new_from_sides(8, 15, 15, 6)
+    );
+    assert_eq!(rect.lerp_size_centered(&target, 0.0), rect);
+
+    // & it still matches a signed rectangle
+    let signed = BasicRectangle::new_from_sides(10, 12, 12, 10);
+    let signed_target = BasicRectangle::new_from_sides(0, 7, 9, 0);
+    assert_eq!(
+        signed.lerp_size_centered(&signed_target, 1.0),
+        BasicRectangle::new_from_sides(8, 15, 15, 6)
+    );
+}
+
+#[test]
+fn test_layout_into_reuses_buffer() {
+    let rect = BasicRectangle::new_from_sides(0, 11, 7, 0);
+    let weights = [3.0, 1.0, 2.0, 2.0];
+    // a buffer left over from something else is cleared first
+    let mut out = vec![rect; 10];
+
+    rect.columns_weighted_into(&weights, &mut out);
+    assert_eq!(out, rect.columns_weighted(&weights));
+    rect.rows_weighted_into(&weights, &mut out);
+    assert_eq!(out, rect.rows_weighted(&weights));
+    rect.partition_into_at_most_into(6, &mut out);
+    assert_eq!(out, rect.partition_into_at_most(6));
+    rect.squarified_treemap_into(&weights, &mut out);
+    assert_eq!(out, rect.squarified_treemap(&weights));
+}
+
+#[test]
+fn test_columns_weighted() {
+    let rect = BasicRectangle::new_from_sides(10, 49, 5, 0);
+    let columns = rect.columns_weighted(&[1.0, 2.0, 1.0]);
+    assert_eq!(columns.len(), 3);
+
+    // the columns tile the rectangle exactly
+    assert_eq!(columns[0].left(), rect.left());
+    assert_eq!(columns[2].right(), rect.right());
+    for pair in columns.windows(2) {
+        assert_eq!(pair[0].right() + 1, pair[1].left());
+    }
+    for column in &columns {
+        assert_eq!((column.top(), column.bottom()), (rect.top(), rect.bottom()));
+    }
+
+    // the middle is twice as wide as the sides
+    let units: Vec<i32> = columns.iter().map(|column| column.width() + 1).collect();
+    assert_eq!(units, vec![10, 20, 10]);
+
+    // uneven splits stay within a unit of their share
+    let columns = BasicRectangle::new_from_sides(0, 9, 0, 0).columns_weighted(&[1.0, 2.0, 1.0]);
+    let units: Vec<i32> = columns.iter().map(|column| column.width() + 1).collect();
+    assert_eq!(units.iter().sum::<i32>(), 10);
+    assert!((units[1] - 2 * units[0]).abs() <= 2);
+
+    assert!(rect.columns_weighted(&[]).is_empty());
+}
+
+#[test]
+fn test_rows_weighted() {
+    let rect = BasicRectangle::new_from_sides(0, 5, 29, -30);
+    let weights = [1.0, 3.0, 2.0];
+    let rows = rect.rows_weighted(&weights);
+    assert_eq!(rows.len(), 3);
+
+    // the rows tile the rectangle exactly, from top to bottom
+    assert_eq!(rows[0].top(), rect.top());
+    assert_eq!(rows[2].bottom(), rect.bottom());
+    for pair in rows.windows(2) {
+        assert_eq!(pair[0].bottom() - 1, pair[1].top());
+    }
+    for row in &rows {
+        assert_eq!((row.left(), row.right()), (rect.left(), rect.right()));
+    }
+
+    // 60 units split 1 : 3 : 2
+    let units: Vec<i32> = rows.iter().map(|row| row.height() + 1).collect();
+    assert_eq!(units, vec![10, 30, 20]);
+
+    // uneven splits stay within a unit of their share
+    let rows = BasicRectangle::new_from_sides(0, 0, 6, 0).rows_weighted(&weights);
+    let units: Vec<i32> = rows.iter().map(|row| row.height() + 1).collect();
+    assert_eq!(units.iter().sum::<i32>(), 7);
+    for (units, weight) in units.iter().zip(weights) {
+        assert!((*units as f64 - 7.0 * weight / 6.0).abs() <= 1.0);
+    }
+}
+
+#[test]
+fn test_fit_to_cell_grid() {
+    // 100 x 50 units holds 12 x 3 cells of 8 x 16
+    let rect = BasicRectangle::new_from_sides(20, 119, 70, 21);
+    let fitted = rect.fit_to_cell_grid(8, 16);
+
+    assert_eq!((fitted.left(), fitted.top()), (rect.left(), rect.top()));
+    assert_eq!(fitted.width() + 1, 12 * 8);
+    assert_eq!(fitted.height() + 1, 3 * 16);
+    assert!(rect.contains_rectangle(&fitted));
+
+    // already a whole number of cells
+    assert_eq!(fitted.fit_to_cell_grid(8, 16), fitted);
+}
+
+#[test]
+fn test_transformed_aabb() {
+    let rect = BasicRectangle::new_from_sides(1, 4, 3, -2);
+
+    let identity = [1.0, 0.0, 0.0, 1.0, 0.0, 0.0];
+    assert_eq!(rect.transformed_aabb(identity), rect);
+
+    // 90 degrees clockwise maps (x, y) to (y, -x)
+    let (sin, cos) = (-90f64).to_radians().sin_cos();
+    let rotated = rect.transformed_aabb([cos, sin, -sin, cos, 0.0, 0.0]);
+    assert_eq!(rotated, BasicRectangle::new_from_sides(-2, 3, -1, -4));
+    assert_eq!(
+        (rotated.width(), rotated.height()),
+        (rect.height(), rect.width())
+    );
+
+    // scaling by a fraction rounds outwards
+    let scaled = rect.transformed_aabb([2.5, 0.0, 0.0, 0.5, 1.0, 0.0]);
+    assert_eq!(scaled, BasicRectangle::new_from_sides(3, 11, 2, -1));
+}
+
+#[test]
+fn test_aabb_of_transformed_set() {
+    assert_eq!(BasicRectangle::aabb_of_transformed_set(&[]), None);
+
+    // one sprite turned 90 degrees about the origin, another 45 degrees & moved up
+    let (sin, cos) = 90f64.to_radians().sin_cos();
+    let quarter = [cos, sin, -sin, cos, 0.0, 0.0];
+    let (sin, cos) = 45f64.to_radians().sin_cos();
+    let eighth = [cos, sin, -sin, cos, 0.0, 20.0];
+    let items = [
+        (BasicRectangle::new_from_sides(0, 4, 2, 0), quarter),
+        (BasicRectangle::new_from_sides(0, 2, 2, 0), eighth),
+    ];
+
+    let turned = items[0].0.transformed_aabb(quarter);
+    assert_eq!(turned, BasicRectangle::new_from_sides(-2, 0, 4, 0));
+    // the diagonal of the second reaches sqrt(8) up, rounded outwards
+    let tilted = items[1].0.transformed_aabb(eighth);
+    assert_eq!(tilted, BasicRectangle::new_from_sides(-2, 2, 23, 20));
+
+    assert_eq!(
+        BasicRectangle::aabb_of_transformed_set(&items),
+        Some(BasicRectangle::new_from_sides(-2, 2, 23, 0))
+    );
+    assert_eq!(
+        BasicRectangle::aabb_of_transformed_set(&items[..1]),
+        Some(turned)
+    );
+}
+
+#[test]
+fn test_total_overlap_points_overlapping_occluders() {
+    let rect = BasicRectangle::new_from_sides(0, 9, 9, 0);
+    // the occluders share a 3x3 corner, which only counts once
+    let occluders = [
+        BasicRectangle::new_from_sides(-2, 4, 4, -2),
+        BasicRectangle::new_from_sides(2, 12, 4, 2),
+    ];
+    assert_eq!(rect.total_overlap_points(&occluders), 25 + 24 - 9);
+
+    // together with the uncovered points it makes up the whole rectangle, which is more points than its area
+    let obstructions: Vec<_> = occluders.iter().collect();
+    assert_eq!(rect.unobstructed_area(&obstructions), 100 - 40);
+    assert_eq!(rect.area(), 81);
+
+    // a count too large for the unit type still comes out right
+    let huge = BasicRectangle::new_from_sides(0, 99_999, 99_999, 0);
+    assert_eq!(huge.total_overlap_points(&[huge]), 10_000_000_000);
+}
+
+#[test]
+fn test_try_from_f64_sides() {
+    assert_eq!(
+        BasicRectangle::try_from_f64_sides(-1.0, 4.0, 3.0, -2.0),
+        Ok(BasicRectangle::new_from_sides(-1, 4, 3, -2))
+    );
+    // a single point is still a rectangle
+    assert_eq!(
+        BasicRectangle::try_from_f64_sides(1.0, 1.0, 1.0, 1.0),
+        Ok(BasicRectangle::new_from_sides(1, 1, 1, 1))
+    );
+
+    for bad in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+        assert_eq!(
+            BasicRectangle::try_from_f64_sides(0.0, 1.0, bad, 0.0),
+            Err(RectError::NonFinite)
+        );
+        assert_eq!(
+            BasicRectangle::try_from_f64_sides(bad, 1.0, 1.0, 0.0),
+            Err(RectError::NonFinite)
+        );
+    }
+
+    assert_eq!(
+        BasicRectangle::try_from_f64_sides(2.0, 1.0, 1.0, 0.0),
+        Err(RectError::NegativeSize)
+    );
+    assert_eq!(
+        BasicRectangle::try_from_f64_sides(0.0, 1e12, 1.0, 0.0),
+        Err(RectError::Overflow)
+    );
+}
+
+#[test]
+fn test_cells_along_line_horizontal() {
+    let rect = BasicRectangle::new_from_sides(0, 9, 9, 0);
+    assert_eq!(
+        rect.cells_along_line((5, 3), (2, 3)),
+        vec![(5, 3), (4, 3), (3, 3), (2, 3)]
+    );
+    assert_eq!(rect.cells_along_line((4, 4), (4, 4)), vec![(4, 4)]);
+}
+
+#[test]
+fn test_cells_along_line_diagonal() {
+    let rect = BasicRectangle::new_from_sides(0, 9, 9, 0);
+    // every corner is passed exactly, so both neighbours are touched
+    assert_eq!(
+        rect.cells_along_line((0, 2), (2, 0)),
+        vec![(0, 2), (1, 2), (0, 1), (1, 1), (2, 1), (1, 0), (2, 0)]
+    );
+
+    // a shallow line steps up once it crosses the middle row boundary
+    assert_eq!(
+        rect.cells_along_line((0, 0), (4, 1)),
+        vec![(0, 0), (1, 0), (2, 0), (2, 1), (3, 1), (4, 1)]
+    );
+}
+
+#[test]
+fn test_cells_along_line_partly_outside() {
+    let rect = BasicRectangle::new_from_sides(0, 3, 3, 0);
+    assert_eq!(
+        rect.cells_along_line((-2, 1), (5, 1)),
+        vec![(0, 1), (1, 1), (2, 1), (3, 1)]
+    );
+    assert!(rect.cells_along_line((-5, -5), (-1, 10)).is_empty());
+}
+
+#[test]
+fn test_cells_along_line_matches_unclipped_walk() {
+    let everywhere = BasicRectangle::new_from_sides(-20, 20, 20, -20);
+    let lines = [
+        ((-15, -4), (17, 9)),
+        ((12, 15), (-13, -2)),
+        ((-9, 9), (9, -9)),
+        ((3, -15), (5, 18)),
+        ((-18, 2), (18, 2)),
+    ];
+    let windows = [
+        BasicRectangle::new_from_sides(0, 5, 5, 0),
+        BasicRectangle::new_from_sides(-10, -3, 8, -1),
+        BasicRectangle::new_from_sides(4, 4, 20, -20),
+        BasicRectangle::new_from_sides(-20, 20, 2, 2),
+    ];
+
+    // clipping has to skip to the same cells the whole walk passes through
+    for (p0, p1) in lines {
+        let whole = everywhere.cells_along_line(p0, p1);
+        for window in windows {
+            let expected: Vec<(i32, i32)> = whole
+                .iter()
+                .copied()
+                .filter(|&(x, y)| window.contains_point(x, y))
+                .collect();
+            assert_eq!(
+                window.cells_along_line(p0, p1),
+                expected,
+                "{p0:?} {p1:?} {window:?}"
+            );
+        }
+    }
+}
+
+#[test]
+fn test_cells_along_line_far_ends() {
+    // the ends are far outside, only the cells inside are walked & nothing overflows
+    let rect = BasicRectangle::new_from_sides(0, 3, 3, 0);
+    assert_eq!(
+        rect.cells_along_line((0, 0), (50000, 50000)),
+        vec![
+            (0, 0),
+            (1, 0),
+            (0, 1),
+            (1, 1),
+            (2, 1),
+            (1, 2),
+            (2, 2),
+            (3, 2),
+            (2, 3),
+            (3, 3)
+        ]
+    );
+    let cells = rect.cells_along_line((i32::MIN, 2), (i32::MAX, 2));
+    assert_eq!(cells, vec![(0, 2), (1, 2), (2, 2), (3, 2)]);
+}
+
+#[test]
+fn test_cells_along_line_unsigned_backwards() {
+    // walking towards zero never needs a negative step
+    let rect = UnsignedRect::new_from_sides(0, 9, 9, 0);
+    assert_eq!(
+        rect.cells_along_line((2, 1), (0, 0)),
+        vec![(2, 1), (1, 1), (1, 0), (0, 0)]
+    );
+    assert_eq!(
+        rect.cells_along_line((3, 0), (0, 0)),
+        vec![(3, 0), (2, 0), (1, 0), (0, 0)]
+    );
+}
+
+#[test]
+fn test_snap_to_common_aspect() {
+    // 170:100 is closest to 16:9, so the height shrinks by 4 around the center
+    let rect = BasicRectangle::new_from_sides(0, 170, 100, 0);
+    let snapped = rect.snap_to_common_aspect();
+    assert_eq!(snapped, BasicRectangle::new_from_sides(0, 170, 98, 2));
+    assert_eq!((snapped.width(), snapped.height()), (170, 96));
+
+    // portrait snaps to the same ratios on their side
+    let rect = BasicRectangle::new_from_sides(0, 100, 170, 0);
+    assert_eq!(
+        rect.snap_to_common_aspect(),
+        BasicRectangle::new_from_sides(2, 98, 170, 0)
+    );
+
+    // already square
+    let rect = BasicRectangle::new_from_sides(0, 10, 10, 0);
+    assert_eq!(rect.snap_to_common_aspect(), rect);
+
+    let line = BasicRectangle::new_from_sides(0, 10, 0, 0);
+    assert_eq!(line.snap_to_common_aspect(), line);
+}
+
+#[test]
+fn test_snap_to_common_aspect_unsigned_growth() {
+    // 1:7 is closest to 9:21, so the width grows by 2, which would go below zero if it were worked out as a negative
+    let rect = UnsignedRect::new_from_sides(5, 6, 7, 0);
+    assert_eq!(
+        rect.snap_to_common_aspect(),
+        UnsignedRect::new_from_sides(4, 7, 7, 0)
+    );
+    let signed = BasicRectangle::new_from_sides(5, 6, 7, 0);
+    assert_eq!(
+        signed.snap_to_common_aspect(),
+        BasicRectangle::new_from_sides(4, 7, 7, 0)
+    );
+}
+
+#[test]
+fn test_intersects_circle_f64() {
+    let rect = BasicRectangle::new_from_sides(0, 10, 10, 0);
+
+    // inside, even when smaller than the rectangle
+    assert!(rect.intersects_circle_f64((5.0, 5.0), 1.0));
+    // straddling the right edge
+    assert!(rect.intersects_circle_f64((11.0, 5.0), 1.5));
+    // near the top right corner, the corner is sqrt(2) away
+    assert!(rect.intersects_circle_f64((11.0, 11.0), 1.5));
+    assert!(!rect.intersects_circle_f64((11.0, 11.0), 1.4));
+    // clearly outside
+    assert!(!rect.intersects_circle_f64((-20.0, 5.0), 3.0));
+}
+
+#[test]
+fn test_shrink_to_avoid_corner() {
+    let rect = BasicRectangle::new_from_sides(0, 10, 10, 0);
+    let corner = BasicRectangle::new_from_sides(9, 15, 15, 9);
+
+    // the corner decides how far both axes shrink
+    let shrunk = rect.shrink_to_avoid(&[&corner], true).unwrap();
+    assert_eq!(shrunk, BasicRectangle::new_from_sides(2, 8, 8, 2));
+
+    // without keeping the aspect only one axis needs to shrink
+    let shrunk = rect.shrink_to_avoid(&[&corner], false).unwrap();
+    assert_eq!(shrunk, BasicRectangle::new_from_sides(0, 10, 8, 2));
+    assert!(!shrunk.overlaps(&corner));
+}
+
+#[test]
+fn test_shrink_to_avoid_center_obstructed() {
+    let rect = BasicRectangle::new_from_sides(0, 10, 10, 0);
+    let center = BasicRectangle::new_from_sides(5, 5, 5, 5);
+    assert_eq!(rect.shrink_to_avoid(&[&center], true), None);
+    assert_eq!(rect.shrink_to_avoid(&[&center], false), None);
+}
+
+#[test]
+fn test_shrink_to_avoid_unobstructed() {
+    let rect = BasicRectangle::new_from_sides(0, 10, 10, 0);
+    let far = BasicRectangle::new_from_sides(20, 25, 25, 20);
+    assert_eq!(rect.shrink_to_avoid(&[&far], true), Some(rect));
+    let none: [&BasicRectangle; 0] = [];
+    assert_eq!(rect.shrink_to_avoid(&none, false), Some(rect));
+}
+
+#[test]
+fn test_shrink_to_avoid_matches_every_inset() {
+    let rect = BasicRectangle::new_from_sides(0, 16, 12, 0);
+    let points = |rect: &BasicRectangle| (rect.width() + 1) * (rect.height() + 1);
+    let layouts = [
+        vec![BasicRectangle::new_from_sides(-3, 2, 14, 9)],
+        vec![
+            BasicRectangle::new_from_sides(14, 20, 3, -2),
+            BasicRectangle::new_from_sides(-5, 1, 5, 4),
+        ],
+        vec![
+            BasicRectangle::new_from_sides(3, 3, 12, 11),
+            BasicRectangle::new_from_sides(12, 14, 1, 0),
+            BasicRectangle::new_from_sides(0, 0, 7, 7),
+        ],
+    ];
+
+    for obstructions in layouts {
+        let refs: Vec<&BasicRectangle> = obstructions.iter().collect();
+        // try every pair of insets
+        let mut best = 0;
+        for inset_x in 0..=rect.width() / 2 {
+            for inset_y in 0..=rect.height() / 2 {
+                let shrunk =
+                    BasicRectangle::new_from_sides(inset_x, 16 - inset_x, 12 - inset_y, inset_y);
+                if refs
+                    .iter()
+                    .all(|obstruction| !shrunk.overlaps(*obstruction))
+                {
+                    best = best.max(points(&shrunk));
+                }
+            }
+        }
+
+        let shrunk = rect.shrink_to_avoid(&refs, false).unwrap();
+        assert_eq!(points(&shrunk), best, "{obstructions:?}");
+        assert!(refs
+            .iter()
+            .all(|obstruction| !shrunk.overlaps(*obstruction)));
+    }
+}
+
+#[test]
+fn test_shrink_to_avoid_large() {
+    // the aspect fractions & areas are past i32
+    let rect = BasicRectangle::new_from_sides(0, 100_000, 100_000, 0);
+    let corner = BasicRectangle::new_from_sides(90_000, 100_000, 100_000, 90_000);
+
+    let shrunk = rect.shrink_to_avoid(&[&corner], true).unwrap();
+    assert_eq!(
+        shrunk,
+        BasicRectangle::new_from_sides(10_001, 89_999, 89_999, 10_001)
+    );
+    let shrunk = rect.shrink_to_avoid(&[&corner], false).unwrap();
+    assert_eq!(
+        shrunk,
+        BasicRectangle::new_from_sides(0, 100_000, 89_999, 10_001)
+    );
+}
+
+#[test]
+fn test_cells_in_order() {
+    // a 2x2 grid of 2x2 cells, with a partial column & row left over
+    let rect = BasicRectangle::new_from_sides(0, 4, 4, 0);
+    let top_left = BasicRectangle::new_from_sides(0, 1, 4, 3);
+    let top_right = BasicRectangle::new_from_sides(2, 3, 4, 3);
+    let bottom_left = BasicRectangle::new_from_sides(0, 1, 2, 1);
+    let bottom_right = BasicRectangle::new_from_sides(2, 3, 2, 1);
+
+    assert_eq!(
+        rect.cells_in_order(2, 2, FillOrder::RowMajor),
+        vec![top_left, top_right, bottom_left, bottom_right]
+    );
+    assert_eq!(
+        rect.cells_in_order(2, 2, FillOrder::ColumnMajor),
+        vec![top_left, bottom_left, top_right, bottom_right]
+    );
+    assert_eq!(
+        rect.cells_in_order(2, 2, FillOrder::Boustrophedon),
+        vec![top_left, top_right, bottom_right, bottom_left]
+    );
+
+    assert!(rect.cells_in_order(6, 1, FillOrder::RowMajor).is_empty());
+}
+
+#[test]
+fn test_overlap_kind() {
+    let rect = BasicRectangle::new_from_sides(0, 3, 3, 0);
+    let kind = |left, right, top, bottom| {
+        rect.overlap_kind(&BasicRectangle::new_from_sides(left, right, top, bottom))
+    };
+
+    assert_eq!(kind(10, 12, 3, 0), OverlapKind::Disjoint);
+    // diagonal corners don't count as touching
+    assert_eq!(kind(4, 5, 5, 4), OverlapKind::Disjoint);
+    assert_eq!(kind(4, 5, 3, 3), OverlapKind::Touching);
+    assert_eq!(kind(-2, 0, -1, -3), OverlapKind::Touching);
+    assert_eq!(kind(3, 5, 5, 3), OverlapKind::Overlapping);
+    assert_eq!(kind(1, 2, 3, 0), OverlapKind::Contains);
+    assert_eq!(kind(-1, 3, 3, 0), OverlapKind::ContainedBy);
+    assert_eq!(kind(0, 3, 3, 0), OverlapKind::Equal);
+}
+
+#[test]
+fn test_frame_all() {
+    let view = BasicRectangle::new_from_sides(0, 9, 9, 0);
+    let targets = [
+        BasicRectangle::new_from_sides(-6, -4, 3, 1),
+        BasicRectangle::new_from_sides(2, 3, 4, 3),
+        BasicRectangle::new_from_sides(7, 20, 15, 12),
+        BasicRectangle::new_from_sides(5, 6, -1, -2),
+    ];
+
+    let framed = view.frame_all(&targets);
+    assert_eq!(framed, BasicRectangle::new_from_sides(-6, 20, 15, -2));
+    assert!(framed.contains_rectangle(&view));
+    assert!(targets
+        .iter()
+        .all(|target| framed.contains_rectangle(target)));
+
+    // targets already in view change nothing
+    assert_eq!(view.frame_all(&targets[1..2]), view);
+}
+
+#[test]
+fn test_split_around_interior() {
+    let rect = BasicRectangle::new_from_sides(0, 9, 9, 0);
+    let hole = BasicRectangle::new_from_sides(3, 5, 6, 4);
+
+    assert_eq!(
+        rect.split_around(&hole, FrameOrder::VerticalFirst),
+        Some([
+            Some(BasicRectangle::new_from_sides(0, 2, 9, 0)),
+            Some(BasicRectangle::new_from_sides(6, 9, 9, 0)),
+            Some(BasicRectangle::new_from_sides(3, 5, 9, 7)),
+            Some(BasicRectangle::new_from_sides(3, 5, 3, 0)),
+        ])
+    );
+
+    // the frame plus the hole covers every point exactly once
+    let pieces = rect
+        .split_around(&hole, FrameOrder::HorizontalFirst)
+        .unwrap();
+    let points: i32 = pieces
+        .iter()
+        .flatten()
+        .chain([&hole])
+        .map(|piece| (piece.width() + 1) * (piece.height() + 1))
+        .sum();
+    assert_eq!(points, 100);
+}
+
+#[test]
+fn test_split_around_touching_edges() {
+    let rect = BasicRectangle::new_from_sides(0, 9, 9, 0);
+
+    let left = BasicRectangle::new_from_sides(-5, 3, 6, 4);
+    assert_eq!(
+        rect.split_around(&left, FrameOrder::HorizontalFirst)
+            .unwrap()[2],
+        None
+    );
+    assert_eq!(
+        rect.split_around(&left, FrameOrder::VerticalFirst).unwrap()[0],
+        None
+    );
+
+    let right = BasicRectangle::new_from_sides(6, 9, 6, 4);
+    assert_eq!(
+        rect.split_around(&right, FrameOrder::HorizontalFirst)
+            .unwrap()[3],
+        None
+    );
+    assert_eq!(
+        rect.split_around(&right, FrameOrder::VerticalFirst)
+            .unwrap()[1],
+        None
+    );
+
+    let top = BasicRectangle::new_from_sides(3, 5, 12, 4);
+    assert_eq!(
+        rect.split_around(&top, FrameOrder::HorizontalFirst)
+            .unwrap()[0],
+        None
+    );
+    assert_eq!(
+        rect.split_around(&top, FrameOrder::VerticalFirst).unwrap()[2],
+        None
+    );
+
+    let bottom = BasicRectangle::new_from_sides(3, 5, 6, 0);
+    assert_eq!(
+        rect.split_around(&bottom, FrameOrder::HorizontalFirst)
+            .unwrap()[1],
+        None
+    );
+    assert_eq!(
+        rect.split_around(&bottom, FrameOrder::VerticalFirst)
+            .unwrap()[3],
+        None
+    );
+}
+
+#[test]
+fn test_split_around_whole() {
+    let rect = BasicRectangle::new_from_sides(0, 9, 9, 0);
+    assert_eq!(
+        rect.split_around(&rect, FrameOrder::HorizontalFirst),
+        Some([None; 4])
+    );
+    assert_eq!(
+        rect.split_around(&rect, FrameOrder::VerticalFirst),
+        Some([None; 4])
+    );
+
+    // a hole missing the rectangle leaves no frame, & carving it out leaves the rectangle whole
+    let outside = BasicRectangle::new_from_sides(20, 25, 5, 0);
+    assert_eq!(rect.split_around(&outside, FrameOrder::VerticalFirst), None);
+    assert_eq!(rect.carve_out(&outside), vec![rect]);
+}
+
+#[test]
+fn test_split_around_unsigned_at_zero() {
+    // a hole at the origin has nothing left of or below it, & nothing may be subtracted from zero to find out
+    let rect = UnsignedRect::new_from_sides(0, 9, 9, 0);
+    let hole = UnsignedRect::new_from_sides(0, 3, 3, 0);
+    assert_eq!(
+        rect.split_around(&hole, FrameOrder::HorizontalFirst),
+        Some([
+            Some(UnsignedRect::new_from_sides(0, 9, 9, 4)),
+            None,
+            None,
+            Some(UnsignedRect::new_from_sides(4, 9, 3, 0)),
+        ])
+    );
+    assert_eq!(
+        rect.carve_out(&hole),
+        vec![
+            UnsignedRect::new_from_sides(0, 9, 9, 4),
+            UnsignedRect::new_from_sides(4, 9, 3, 0),
+        ]
+    );
+
+    // & one at the largest value has nothing right of or above it
+    let rect = UnsignedRect::new_from_sides(u32::MAX - 9, u32::MAX, u32::MAX, u32::MAX - 9);
+    assert_eq!(
+        rect.split_around(&rect, FrameOrder::VerticalFirst),
+        Some([None; 4])
+    );
+}
+
+#[test]
+fn test_camera_fit() {
+    let targets = [
+        BasicRectangle::new_from_sides(0, 10, 10, 0),
+        BasicRectangle::new_from_sides(30, 40, 60, 50),
+    ];
+
+    // the padded hull is 50 wide & 70 tall, so it widens to 125
+    let frame = BasicRectangle::camera_fit(&targets, 16, 9, 5).unwrap();
+    assert_eq!(frame, BasicRectangle::new_from_sides(-42, 83, 65, -5));
+    assert_eq!((frame.width(), frame.height()), (125, 70));
+    assert!(targets
+        .iter()
+        .all(|target| frame.contains_rectangle(target)));
+
+    let none: [BasicRectangle; 0] = [];
+    assert_eq!(BasicRectangle::camera_fit(&none, 16, 9, 5), None);
+
+    // there is no frame with a zero or negative aspect
+    assert_eq!(BasicRectangle::camera_fit(&targets, 0, 9, 5), None);
+    assert_eq!(BasicRectangle::camera_fit(&targets, 16, 0, 5), None);
+    assert_eq!(BasicRectangle::camera_fit(&targets, -16, 9, 5), None);
+}
+
+#[test]
+fn test_carve_out_center() {
+    let rect = BasicRectangle::new_from_sides(0, 9, 9, 0);
+    let hole = BasicRectangle::new_from_sides(3, 6, 6, 3);
+
+    let pieces = rect.carve_out(&hole);
+    assert_eq!(
+        pieces,
+        vec![
+            BasicRectangle::new_from_sides(0, 9, 9, 7),
+            BasicRectangle::new_from_sides(0, 9, 2, 0),
+            BasicRectangle::new_from_sides(0, 2, 6, 3),
+            BasicRectangle::new_from_sides(7, 9, 6, 3),
+        ]
+    );
+    for (i, piece) in pieces.iter().enumerate() {
+        assert!(!piece.overlaps(&hole));
+        assert!(pieces[i + 1..].iter().all(|other| !piece.overlaps(other)));
+    }
+
+    assert!(rect.carve_out(&rect).is_empty());
+}
+
+#[test]
+fn test_is_sliver() {
+    let square = BasicRectangle::new_from_sides(0, 10, 10, 0);
+    assert!(!square.is_sliver(1.0));
+
+    let thin = BasicRectangle::new_from_sides(0, 30, 5, 0);
+    assert!(thin.is_sliver(5.0));
+    assert!(!thin.is_sliver(6.0));
+    // the orientation doesn't matter
+    assert!(BasicRectangle::new_from_sides(0, 5, 30, 0).is_sliver(5.0));
+
+    let line = BasicRectangle::new_from_sides(0, 30, 3, 3);
+    assert!(line.is_sliver(1000.0));
+    assert!(BasicRectangle::new_from_sides(3, 3, 3, 3).is_sliver(1000.0));
+}
+
+/// Asserts the pieces are disjoint & cover every point of the parent.
+fn assert_tiles(parent: &BasicRectangle, pieces: &[BasicRectangle]) {
+    let points = |rect: &BasicRectangle| (rect.width() + 1) * (rect.height() + 1);
+    for (i, piece) in pieces.iter().enumerate() {
+        assert!(parent.contains_rectangle(piece));
+        assert!(pieces[i + 1..].iter().all(|other| !piece.overlaps(other)));
+    }
+    assert_eq!(pieces.iter().map(points).sum::<i32>(), points(parent));
+}
+
+#[test]
+fn test_partition_into_at_most() {
+    let rect = BasicRectangle::new_from_sides(0, 99, 59, 0);
+
+    assert_eq!(rect.partition_into_at_most(1), vec![rect]);
+    assert_eq!(rect.partition_into_at_most(0), vec![rect]);
+
+    let four = rect.partition_into_at_most(4);
+    assert_eq!(four.len(), 4);
+    assert_tiles(&rect, &four);
+    assert!(four
+        .iter()
+        .all(|piece| (piece.width(), piece.height()) == (49, 29)));
+
+    // 7 is prime, so it falls back to a 3 by 2 grid, wider than tall like the parent
+    let seven = rect.partition_into_at_most(7);
+    assert_eq!(seven.len(), 6);
+    assert_tiles(&rect, &seven);
+    assert_eq!(seven[0], BasicRectangle::new_from_sides(0, 32, 59, 30));
+
+    // a thin rectangle can't hold more columns than points
+    let thin = BasicRectangle::new_from_sides(0, 2, 0, 0);
+    assert_eq!(thin.partition_into_at_most(9).len(), 3);
+}
+
+#[test]
+fn test_grid_cells_unobstructed() {
+    let rect = BasicRectangle::new_from_sides(0, 11, 11, 0);
+    // covers the middle 2 by 2 cells of a 4 by 4 grid of 3 by 3 cells, & clips into a third row
+    let obstruction = BasicRectangle::new_from_sides(3, 8, 8, 2);
+    let cells = rect.grid_cells_unobstructed(4, 4, &[&obstruction]);
+
+    assert_eq!(cells.len(), 10);
+    assert!(cells.iter().all(|cell| !cell.overlaps(&obstruction)));
+    assert!(!cells.contains(&BasicRectangle::new_from_sides(3, 5, 8, 6)));
+    assert!(!cells.contains(&BasicRectangle::new_from_sides(6, 8, 2, 0)));
+    assert!(cells.contains(&BasicRectangle::new_from_sides(0, 2, 8, 6)));
+
+    // without obstructions every cell is kept, & empty columns are dropped
+    let none: [&BasicRectangle; 0] = [];
+    assert_eq!(rect.grid_cells_unobstructed(4, 4, &none).len(), 16);
+    assert_eq!(rect.grid_cells_unobstructed(20, 1, &none).len(), 12);
+    assert!(rect.grid_cells_unobstructed(0, 4, &none).is_empty());
+}
+
+#[test]
+fn test_squarified_treemap() {
+    let rect = BasicRectangle::new_from_sides(0, 99, 59, 0);
+    let weights = [1.0, 5.0, 2.0, 8.0, 3.0, 1.0];
+    let pieces = rect.squarified_treemap(&weights);
+    assert_eq!(pieces.len(), weights.len());
+    assert_tiles(&rect, &pieces);
+
+    // each piece is within rounding of its share of the 6000 points, in the order of the weights
+    let total: f64 = weights.iter().sum();
+    for (piece, weight) in pieces.iter().zip(weights) {
+        let points = ((piece.width() + 1) * (piece.height() + 1)) as f64;
+        let share = 6000.0 * weight / total;
+        assert!((points - share).abs() / share < 0.05, "{points} vs {share}");
+        // & none of them are thin strips
+        let (w, h) = ((piece.width() + 1) as f64, (piece.height() + 1) as f64);
+        assert!(w.max(h) / w.min(h) < 3.0);
+    }
+
+    assert!(rect.squarified_treemap(&[]).is_empty());
+    assert_eq!(rect.squarified_treemap(&[0.0]), vec![rect]);
+}
+
+#[test]
+fn test_content_hash_known_values() {
+    // FNV-1a over each side as 16 little endian bytes, these must never change
+    assert_eq!(
+        BasicRectangle::new_from_sides(0, 1, 1, 0).content_hash(),
+        0x7611_4785_95cb_cfe5
+    );
+    assert_eq!(
+        BasicRectangle::new_from_sides(-5, 10, 7, -3).content_hash(),
+        0x53ba_ab27_2131_8c8e
+    );
+}
+
+#[test]
+fn test_quantize_step_4() {
+    let rect = BasicRectangle::new_from_sides(5, 13, 23, 17);
+    // 5 -> 4, 13 -> 12, 23 -> 24, & 17 -> 16
+    assert_eq!(
+        rect.quantize(4),
+        BasicRectangle::new_from_sides(4, 12, 24, 16)
+    );
+
+    // halfway between two multiples rounds up, negative sides included
+    assert_eq!(
+        BasicRectangle::new_from_sides(-6, 6, 2, -2).quantize(4),
+        BasicRectangle::new_from_sides(-4, 8, 4, 0)
+    );
+    // already on the lattice
+    let aligned = BasicRectangle::new_from_sides(-8, 4, 16, 0);
+    assert_eq!(aligned.quantize(4), aligned);
+
+    // moving by whole steps moves the result by the same amount
+    for dx in -3..=3 {
+        assert_eq!(
+            rect.translate(dx * 4, -dx * 4).quantize(4),
+            rect.quantize(4).translate(dx * 4, -dx * 4)
+        );
+    }
+}
+
+#[test]
+#[should_panic(expected = "the step must be positive")]
+fn test_quantize_zero_step() {
+    BasicRectangle::new_from_sides(0, 1, 1, 0).quantize(0);
+}
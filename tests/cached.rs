@@ -0,0 +1,61 @@
+use rect_lib::{BasicRectangle, Cached, Rectangle, RectangleRead};
+
+mod common;
+use common::{reads, CountingRect};
+
+#[test]
+fn test_cached_reads_sides_once() {
+    let rect = CountingRect::new_from_sides(0, 9, 9, 0);
+    let other = BasicRectangle::new_from_sides(5, 12, 12, 5);
+
+    let before = reads();
+    rect.intersection(&other);
+    rect.contains_point(3, 3);
+    let uncached = reads() - before;
+    assert!(uncached > 4);
+
+    let before = reads();
+    let cached = Cached::new(rect);
+    assert_eq!(reads() - before, 4);
+
+    let before = reads();
+    assert_eq!(
+        cached.intersection(&other).map(Cached::into_inner),
+        Some(CountingRect::new_from_sides(5, 9, 9, 5))
+    );
+    assert!(cached.contains_point(3, 3));
+    assert_eq!(cached.inner(), &rect);
+    assert_eq!(reads() - before, 0);
+}
+
+#[test]
+fn test_sweep_reads_each_obstruction_once() {
+    let parent = BasicRectangle::new_from_sides(0, 29, 29, 0);
+    let obstructions: Vec<CountingRect> = (0..10)
+        .map(|i| CountingRect::new_from_sides(i * 3, i * 3 + 1, 29 - i * 2, 20 - i * 2))
+        .collect();
+    let refs: Vec<&CountingRect> = obstructions.iter().collect();
+
+    let before = reads();
+    let found = parent.unobstructed_subrectangles(&refs);
+    assert_eq!(reads() - before, 4 * obstructions.len());
+
+    let expected = parent
+        .unobstructed_subrectangles(&obstructions.iter().map(|rect| &rect.0).collect::<Vec<_>>());
+    assert_eq!(found, expected);
+}
+
+#[test]
+fn test_sweep_reads_counting_parent_once() {
+    let parent = CountingRect::new_from_sides(0, 19, 19, 0);
+    let obstructions = [
+        BasicRectangle::new_from_sides(4, 6, 19, 10),
+        BasicRectangle::new_from_sides(12, 15, 8, 2),
+    ];
+
+    let before = reads();
+    let found = parent.unobstructed_subrectangles(&[&obstructions[0], &obstructions[1]]);
+    // four for the snapshot of the parent, the rest is read from the snapshot
+    assert_eq!(reads() - before, 4);
+    assert!(!found.is_empty());
+}
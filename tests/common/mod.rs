@@ -0,0 +1,126 @@
+//! Helpers shared between the integration tests, each test crate only uses some of them.
+#![allow(dead_code)]
+
+use std::{cell::RefCell, collections::HashMap};
+
+use rect_lib::{BasicRectangle, Rectangle, RectangleRead};
+
+/// A rectangle with unsigned sides, so anything subtracting past zero would underflow.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct UnsignedRect {
+    pub sides: [u32; 4],
+}
+
+impl RectangleRead for UnsignedRect {
+    type Unit = u32;
+
+    fn left(&self) -> u32 {
+        self.sides[0]
+    }
+
+    fn right(&self) -> u32 {
+        self.sides[1]
+    }
+
+    fn top(&self) -> u32 {
+        self.sides[2]
+    }
+
+    fn bottom(&self) -> u32 {
+        self.sides[3]
+    }
+}
+
+impl Rectangle for UnsignedRect {
+    fn new_from_sides(left: u32, right: u32, top: u32, bottom: u32) -> Self {
+        Self {
+            sides: [left, right, top, bottom],
+        }
+    }
+}
+
+/// A tiny xorshift so the tests are reproducible without extra dependencies.
+pub struct Rng(pub u64);
+
+impl Rng {
+    /// A number in `0..max`.
+    pub fn next(&mut self, max: i32) -> i32 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        (self.0 % max as u64) as i32
+    }
+
+    /// A rectangle with its corners in `0..max`.
+    pub fn rect(&mut self, max: i32) -> BasicRectangle {
+        BasicRectangle::from_corners(
+            (self.next(max), self.next(max)),
+            (self.next(max), self.next(max)),
+        )
+    }
+
+    /// `count` rectangles with their corners in `0..max`.
+    pub fn rects(&mut self, count: usize, max: i32) -> Vec<BasicRectangle> {
+        (0..count).map(|_| self.rect(max)).collect()
+    }
+}
+
+thread_local! {
+    /// How many times the sides of each `CountingRect` were read on this thread, by its sides.
+    static READS: RefCell<HashMap<[i32; 4], usize>> = RefCell::new(HashMap::new());
+}
+
+/// How many times the sides of any `CountingRect` were read on this thread.
+pub fn reads() -> usize {
+    READS.with(|reads| reads.borrow().values().sum())
+}
+
+/// A rectangle that counts every side read, standing in for one with expensive accessors.
+/// The counts are kept by sides, so equal rectangles share one.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CountingRect(pub BasicRectangle);
+
+impl CountingRect {
+    /// How many times the sides of this rectangle were read on this thread.
+    pub fn reads(&self) -> usize {
+        READS.with(|reads| reads.borrow().get(&self.key()).copied().unwrap_or(0))
+    }
+
+    fn key(&self) -> [i32; 4] {
+        [self.0.left(), self.0.right(), self.0.top(), self.0.bottom()]
+    }
+
+    fn count_read(&self) {
+        READS.with(|reads| *reads.borrow_mut().entry(self.key()).or_insert(0) += 1);
+    }
+}
+
+impl RectangleRead for CountingRect {
+    type Unit = i32;
+
+    fn left(&self) -> i32 {
+        self.count_read();
+        self.0.left()
+    }
+
+    fn right(&self) -> i32 {
+        self.count_read();
+        self.0.right()
+    }
+
+    fn top(&self) -> i32 {
+        self.count_read();
+        self.0.top()
+    }
+
+    fn bottom(&self) -> i32 {
+        self.count_read();
+        self.0.bottom()
+    }
+}
+
+impl Rectangle for CountingRect {
+    fn new_from_sides(left: i32, right: i32, top: i32, bottom: i32) -> Self {
+        Self(BasicRectangle::new_from_sides(left, right, top, bottom))
+    }
+}
@@ -0,0 +1,110 @@
+use rect_lib::{BasicRectangle, ContainmentIndex, Rectangle};
+
+mod common;
+use common::Rng;
+
+/// Every rectangle in `rects` containing `query`, sorted so orders don't matter.
+fn brute_force(rects: &[BasicRectangle], query: &BasicRectangle) -> Vec<BasicRectangle> {
+    let mut found: Vec<BasicRectangle> = rects
+        .iter()
+        .filter(|rect| rect.contains_rectangle(query))
+        .copied()
+        .collect();
+    found.sort_by_key(|rect| (rect.left(), rect.right(), rect.top(), rect.bottom()));
+    found
+}
+
+fn containing(
+    index: &ContainmentIndex<BasicRectangle>,
+    query: &BasicRectangle,
+) -> Vec<BasicRectangle> {
+    let mut found: Vec<BasicRectangle> = index.containing(query).into_iter().copied().collect();
+    found.sort_by_key(|rect| (rect.left(), rect.right(), rect.top(), rect.bottom()));
+    found
+}
+
+#[test]
+fn test_containment_index_matches_brute_force() {
+    let mut rng = Rng(0x2545_f491_4f6c_dd1d);
+    let mut index = ContainmentIndex::new();
+    let mut stored = Vec::new();
+
+    // enough rectangles to split into many blocks, with removals mixed in
+    for step in 0..600 {
+        if step % 5 == 4 && !stored.is_empty() {
+            let rect: BasicRectangle = stored.swap_remove(rng.next(stored.len() as i32) as usize);
+            assert_eq!(index.remove(&rect), Some(rect));
+        } else {
+            let rect = rng.rect(100);
+            index.insert(rect);
+            stored.push(rect);
+        }
+        assert_eq!(index.len(), stored.len());
+
+        if step % 20 == 0 {
+            for _ in 0..20 {
+                let query = rng.rect(100);
+                assert_eq!(containing(&index, &query), brute_force(&stored, &query));
+            }
+        }
+    }
+}
+
+#[test]
+fn test_containment_index_point_queries() {
+    let index: ContainmentIndex<BasicRectangle> = [
+        BasicRectangle::new_from_sides(0, 9, 9, 0),
+        BasicRectangle::new_from_sides(9, 9, 9, 9),
+        BasicRectangle::new_from_sides(10, 20, 9, 0),
+    ]
+    .into_iter()
+    .collect();
+
+    // a point on a shared corner & one on a single rectangle's edge
+    let corner = BasicRectangle::new_from_sides(9, 9, 9, 9);
+    assert_eq!(index.containing(&corner).len(), 2);
+    let edge = BasicRectangle::new_from_sides(20, 20, 0, 0);
+    assert_eq!(
+        index.containing(&edge),
+        vec![&BasicRectangle::new_from_sides(10, 20, 9, 0)]
+    );
+    let outside = BasicRectangle::new_from_sides(21, 21, 0, 0);
+    assert!(index.containing(&outside).is_empty());
+}
+
+#[test]
+fn test_containment_index_remove_duplicates() {
+    let rect = BasicRectangle::new_from_sides(0, 4, 4, 0);
+    let mut index: ContainmentIndex<BasicRectangle> = vec![rect; 100].into_iter().collect();
+    assert_eq!(index.len(), 100);
+
+    for _ in 0..100 {
+        assert_eq!(index.remove(&rect), Some(rect));
+    }
+    assert!(index.is_empty());
+    assert_eq!(index.remove(&rect), None);
+    assert!(index.containing(&rect).is_empty());
+}
+
+#[test]
+fn test_containment_index_iteration_order() {
+    let rects = [
+        BasicRectangle::new_from_sides(5, 6, 6, 5),
+        BasicRectangle::new_from_sides(0, 9, 9, 0),
+        BasicRectangle::new_from_sides(5, 9, 2, 0),
+        BasicRectangle::new_from_sides(0, 2, 2, 0),
+    ];
+    let index: ContainmentIndex<BasicRectangle> = rects.into_iter().collect();
+
+    // by left, then insertion
+    let lefts: Vec<&BasicRectangle> = index.iter().collect();
+    assert_eq!(lefts, vec![&rects[1], &rects[3], &rects[0], &rects[2]]);
+    assert_eq!((&index).into_iter().count(), 4);
+
+    // the canonical order puts the highest rectangles first
+    let sorted: Vec<&BasicRectangle> = index.iter_sorted().collect();
+    assert_eq!(sorted, vec![&rects[1], &rects[0], &rects[3], &rects[2]]);
+
+    let owned: Vec<BasicRectangle> = index.into_iter().collect();
+    assert_eq!(owned, vec![rects[1], rects[3], rects[0], rects[2]]);
+}
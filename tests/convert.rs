@@ -0,0 +1,125 @@
+use std::collections::BTreeSet;
+
+use rect_lib::{convert, BasicRectangle, RectRegion, Rectangle};
+
+mod common;
+use common::Rng;
+
+fn points(region: &RectRegion<BasicRectangle>) -> BTreeSet<(i32, i32)> {
+    region
+        .iter()
+        .flat_map(|rect| {
+            (rect.left()..=rect.right())
+                .flat_map(move |x| (rect.bottom()..=rect.top()).map(move |y| (x, y)))
+        })
+        .collect()
+}
+
+#[test]
+fn test_polygons_round_trip() {
+    let mut rng = Rng(0x9e37_79b9_7f4a_7c15);
+    for _ in 0..100 {
+        let region = rng.rects(5, 12).into_iter().collect::<RectRegion<_>>();
+        let polygons = convert::region_to_polygons(&region);
+        assert_eq!(
+            convert::polygons_to_region::<BasicRectangle>(&polygons),
+            region
+        );
+
+        // only corners are kept, so every vertex turns
+        for polygon in &polygons {
+            assert!(polygon.len() >= 4);
+            for i in 0..polygon.len() {
+                let (a, b) = (polygon[i], polygon[(i + 1) % polygon.len()]);
+                assert!(a.0 == b.0 || a.1 == b.1);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_region_to_polygons_holes_and_corners() {
+    // a ring has an outer loop & a hole going the other way
+    let ring: RectRegion<_> = RectRegion::from(BasicRectangle::new_from_sides(0, 5, 5, 0))
+        .subtract(&RectRegion::from(BasicRectangle::new_from_sides(
+            2, 3, 3, 2,
+        )));
+    assert_eq!(
+        convert::region_to_polygons(&ring),
+        vec![
+            vec![(0, 6), (0, 0), (6, 0), (6, 6)],
+            vec![(2, 4), (4, 4), (4, 2), (2, 2)],
+        ]
+    );
+
+    // squares touching at a corner are separate loops
+    let diagonal: RectRegion<_> = [
+        BasicRectangle::new_from_sides(0, 1, 1, 0),
+        BasicRectangle::new_from_sides(2, 3, 3, 2),
+    ]
+    .into_iter()
+    .collect();
+    let polygons = convert::region_to_polygons(&diagonal);
+    assert_eq!(polygons.len(), 2);
+    assert_eq!(
+        convert::polygons_to_region::<BasicRectangle>(&polygons),
+        diagonal
+    );
+
+    assert!(convert::region_to_polygons(&RectRegion::<BasicRectangle>::new()).is_empty());
+}
+
+#[test]
+fn test_slanted_polygon_quantization() {
+    let triangle = vec![vec![(0, 0), (10, 0), (0, 7)]];
+    let region = convert::polygons_to_region::<BasicRectangle>(&triangle);
+
+    // exactly the points whose centers are inside the triangle, above & right of the axes & below x / 10 + y / 7 = 1
+    let inside = |x: i32, y: i32| {
+        let (cx, cy) = (x as f64 + 0.5, y as f64 + 0.5);
+        cx > 0.0 && cy > 0.0 && cx / 10.0 + cy / 7.0 < 1.0
+    };
+    let expected: BTreeSet<(i32, i32)> = (-2..12)
+        .flat_map(|x| (-2..10).map(move |y| (x, y)))
+        .filter(|&(x, y)| inside(x, y))
+        .collect();
+    assert_eq!(points(&region), expected);
+}
+
+#[test]
+fn test_grid_round_trip() {
+    let bounds = BasicRectangle::new_from_sides(0, 11, 11, 0);
+
+    // regions on the cell lines are exact
+    let aligned: RectRegion<_> = [
+        BasicRectangle::new_from_sides(0, 3, 11, 8),
+        BasicRectangle::new_from_sides(4, 11, 3, 0),
+    ]
+    .into_iter()
+    .collect();
+    let grid = convert::region_to_grid(&aligned, &bounds, 4, 4);
+    assert_eq!(grid.counts(), &[1, 0, 0, 0, 0, 0, 0, 1, 1]);
+    assert_eq!(convert::grid_to_region(&grid, &bounds, 4, 4), aligned);
+
+    // anything else grows by less than a cell
+    let mut rng = Rng(0x2545_f491_4f6c_dd1d);
+    for _ in 0..50 {
+        let region = rng.rects(3, 12).into_iter().collect::<RectRegion<_>>();
+        let grid = convert::region_to_grid(&region, &bounds, 3, 5);
+        let back = convert::grid_to_region(&grid, &bounds, 3, 5);
+
+        let (before, after) = (points(&region), points(&back));
+        let within: BTreeSet<_> = before
+            .iter()
+            .copied()
+            .filter(|&(x, y)| bounds.contains_point(x, y))
+            .collect();
+        assert!(after.is_superset(&within));
+        for &(x, y) in &after {
+            // in the same cell as some point of the region
+            assert!(within
+                .iter()
+                .any(|&(rx, ry)| rx / 3 == x / 3 && (11 - ry) / 5 == (11 - y) / 5));
+        }
+    }
+}
@@ -0,0 +1,216 @@
+mod common;
+
+use common::UnsignedRect;
+use rect_lib::{BasicRectangle, Rectangle};
+
+fn level() -> BasicRectangle {
+    BasicRectangle::new_from_sides(0, 20, 20, 0)
+}
+
+#[test]
+fn test_find_corridor_aligned_rooms() {
+    let a = BasicRectangle::new_from_sides(2, 6, 6, 2);
+    let b = BasicRectangle::new_from_sides(12, 16, 8, 3);
+    let no_obstructions: &[&BasicRectangle] = &[];
+
+    let corridor = level().find_corridor(&a, &b, no_obstructions, 1).unwrap();
+    assert_eq!(corridor, BasicRectangle::new_from_sides(6, 12, 6, 3));
+    assert!(corridor.overlaps(&a) && corridor.overlaps(&b));
+
+    // rooms stacked vertically get a vertical corridor
+    let c = BasicRectangle::new_from_sides(4, 8, 16, 12);
+    let corridor = level().find_corridor(&a, &c, no_obstructions, 1).unwrap();
+    assert_eq!(corridor, BasicRectangle::new_from_sides(4, 6, 12, 6));
+}
+
+#[test]
+fn test_find_corridor_blocked() {
+    let a = BasicRectangle::new_from_sides(2, 6, 6, 2);
+    let b = BasicRectangle::new_from_sides(12, 16, 8, 3);
+    let wall = BasicRectangle::new_from_sides(9, 9, 20, 0);
+    assert_eq!(level().find_corridor(&a, &b, &[&wall], 1), None);
+
+    // rooms that don't line up on either axis can't be joined by a straight corridor
+    let c = BasicRectangle::new_from_sides(12, 16, 16, 12);
+    let no_obstructions: &[&BasicRectangle] = &[];
+    assert_eq!(level().find_corridor(&a, &c, no_obstructions, 1), None);
+}
+
+#[test]
+fn test_find_corridor_thickness() {
+    let a = BasicRectangle::new_from_sides(2, 6, 6, 2);
+    let b = BasicRectangle::new_from_sides(12, 16, 8, 3);
+    // leaves a single row free at y = 3
+    let wall = BasicRectangle::new_from_sides(9, 9, 20, 4);
+
+    let corridor = level().find_corridor(&a, &b, &[&wall], 1);
+    assert_eq!(corridor, Some(BasicRectangle::new_from_sides(6, 12, 3, 3)));
+    assert_eq!(level().find_corridor(&a, &b, &[&wall], 2), None);
+}
+
+#[test]
+fn test_find_corridor_within_parent() {
+    // the obstruction pokes out of the parent, & the corridor must stay inside it
+    let parent = BasicRectangle::new_from_sides(0, 20, 5, 0);
+    let a = BasicRectangle::new_from_sides(0, 3, 8, 2);
+    let b = BasicRectangle::new_from_sides(10, 14, 8, 2);
+    let obstruction = BasicRectangle::new_from_sides(6, 7, 10, 4);
+
+    let corridor = parent.find_corridor(&a, &b, &[&obstruction], 1).unwrap();
+    assert_eq!(corridor, BasicRectangle::new_from_sides(3, 10, 3, 2));
+    assert!(parent.contains_rectangle(&corridor));
+}
+
+/// Asserts the legs form an unobstructed L joining `a` & `b`.
+fn assert_l_corridor(
+    (horizontal, vertical): (BasicRectangle, BasicRectangle),
+    a: &BasicRectangle,
+    b: &BasicRectangle,
+    obstructions: &[&BasicRectangle],
+    thickness: i32,
+) {
+    assert_eq!(horizontal.height() + 1, thickness);
+    assert_eq!(vertical.width() + 1, thickness);
+    assert!(horizontal.overlaps(&vertical));
+    assert!(horizontal.overlaps(a) || vertical.overlaps(a));
+    assert!(horizontal.overlaps(b) || vertical.overlaps(b));
+    for leg in [horizontal, vertical] {
+        assert!(level().contains_rectangle(&leg));
+        assert!(obstructions
+            .iter()
+            .all(|obstruction| !leg.overlaps(*obstruction)));
+    }
+}
+
+#[test]
+fn test_find_l_corridor_diagonal_rooms() {
+    let a = BasicRectangle::new_from_sides(1, 5, 18, 14);
+    let b = BasicRectangle::new_from_sides(12, 16, 6, 2);
+    let no_obstructions: &[&BasicRectangle] = &[];
+
+    let legs = level().find_l_corridor(&a, &b, no_obstructions, 2).unwrap();
+    assert_l_corridor(legs, &a, &b, no_obstructions, 2);
+    // leaves a horizontally & arrives at b vertically
+    assert!(legs.0.overlaps(&a) && legs.1.overlaps(&b));
+}
+
+#[test]
+fn test_find_l_corridor_other_elbow() {
+    let a = BasicRectangle::new_from_sides(1, 5, 18, 14);
+    let b = BasicRectangle::new_from_sides(12, 16, 6, 2);
+    // blocks the top right elbow, but not the bottom left one
+    let block = BasicRectangle::new_from_sides(10, 20, 20, 10);
+
+    let legs = level().find_l_corridor(&a, &b, &[&block], 1).unwrap();
+    assert_l_corridor(legs, &a, &b, &[&block], 1);
+    // leaves a vertically & arrives at b horizontally
+    assert!(legs.1.overlaps(&a) && legs.0.overlaps(&b));
+}
+
+#[test]
+fn test_find_l_corridor_blocked() {
+    let a = BasicRectangle::new_from_sides(1, 5, 18, 14);
+    let b = BasicRectangle::new_from_sides(12, 16, 6, 2);
+    // a wall between the rooms running the full height of the level
+    let wall = BasicRectangle::new_from_sides(8, 9, 20, 0);
+    assert_eq!(level().find_l_corridor(&a, &b, &[&wall], 1), None);
+}
+
+/// Tries every placement of the legs one unit at a time, leaving `a` horizontally & arriving at `b` vertically.
+fn brute_force_l(
+    a: &BasicRectangle,
+    b: &BasicRectangle,
+    obstructions: &[&BasicRectangle],
+    thickness: i32,
+) -> Option<(BasicRectangle, BasicRectangle)> {
+    let across = thickness.max(1) - 1;
+    let is_free = |leg: &BasicRectangle| {
+        level().contains_rectangle(leg) && obstructions.iter().all(|o| !leg.overlaps(*o))
+    };
+    for bottom in (a.bottom()..=a.top() - across).rev() {
+        let top = bottom + across;
+        for left in b.left()..=b.right() - across {
+            let right = left + across;
+            let horizontal = if left > a.right() {
+                Some(BasicRectangle::new_from_sides(
+                    a.right(),
+                    right,
+                    top,
+                    bottom,
+                ))
+            } else if right < a.left() {
+                Some(BasicRectangle::new_from_sides(left, a.left(), top, bottom))
+            } else {
+                None
+            };
+            let vertical = if top < b.bottom() {
+                Some(BasicRectangle::new_from_sides(
+                    left,
+                    right,
+                    b.bottom(),
+                    bottom,
+                ))
+            } else if bottom > b.top() {
+                Some(BasicRectangle::new_from_sides(left, right, top, b.top()))
+            } else {
+                None
+            };
+            if let (Some(horizontal), Some(vertical)) = (horizontal, vertical) {
+                if is_free(&horizontal) && is_free(&vertical) {
+                    return Some((horizontal, vertical));
+                }
+            }
+        }
+    }
+    None
+}
+
+#[test]
+fn test_find_l_corridor_matches_every_placement() {
+    let rooms = [
+        (
+            BasicRectangle::new_from_sides(1, 5, 18, 14),
+            BasicRectangle::new_from_sides(12, 16, 6, 2),
+        ),
+        (
+            BasicRectangle::new_from_sides(14, 19, 9, 3),
+            BasicRectangle::new_from_sides(2, 8, 19, 15),
+        ),
+    ];
+    // a block in every spot of a coarse grid, alone & next to a thin wall
+    let wall = BasicRectangle::new_from_sides(10, 10, 20, 6);
+    for (a, b) in rooms {
+        for x in (0..20).step_by(3) {
+            for y in (0..20).step_by(3) {
+                let block = BasicRectangle::new_from_sides(x, x + 2, y + 1, y);
+                for obstructions in [vec![&block], vec![&block, &wall]] {
+                    for thickness in 1..=3 {
+                        let expected = brute_force_l(&a, &b, &obstructions, thickness)
+                            .or_else(|| brute_force_l(&b, &a, &obstructions, thickness));
+                        assert_eq!(
+                            level().find_l_corridor(&a, &b, &obstructions, thickness),
+                            expected
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_find_l_corridor_unsigned_at_zero() {
+    let level = UnsignedRect::new_from_sides(0, 10, 10, 0);
+    let a = UnsignedRect::new_from_sides(0, 3, 3, 0);
+    let b = UnsignedRect::new_from_sides(7, 10, 10, 7);
+    let no_obstructions: &[&UnsignedRect] = &[];
+    let (horizontal, vertical) = level.find_l_corridor(&a, &b, no_obstructions, 1).unwrap();
+    assert_eq!(horizontal, UnsignedRect::new_from_sides(3, 7, 3, 3));
+    assert_eq!(vertical, UnsignedRect::new_from_sides(7, 7, 7, 3));
+
+    // nothing fits, so every row down to zero is tried
+    let wall = UnsignedRect::new_from_sides(5, 5, 10, 0);
+    assert_eq!(level.find_l_corridor(&a, &b, &[&wall], 1), None);
+    // legs thicker than either room
+    assert_eq!(level.find_l_corridor(&a, &b, no_obstructions, 5), None);
+}
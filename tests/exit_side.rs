@@ -0,0 +1,82 @@
+use rect_lib::{BasicRectangle, Rectangle, RectangleRead, Side};
+
+fn rect() -> BasicRectangle {
+    BasicRectangle::new_from_sides(0, 10, 10, 0)
+}
+
+#[test]
+fn test_exit_side_each_side() {
+    let rect = rect();
+    assert_eq!(rect.exit_side((5, 5), (-3, 6)), Some(Side::Left));
+    assert_eq!(rect.exit_side((5, 5), (14, 2)), Some(Side::Right));
+    assert_eq!(rect.exit_side((5, 5), (4, 11)), Some(Side::Top));
+    assert_eq!(rect.exit_side((5, 5), (7, -1)), Some(Side::Bottom));
+
+    // outside on both axes, the side reached first wins
+    assert_eq!(rect.exit_side((8, 5), (14, -20)), Some(Side::Bottom));
+    assert_eq!(rect.exit_side((8, 5), (20, -4)), Some(Side::Right));
+}
+
+#[test]
+fn test_exit_side_corner() {
+    let rect = rect();
+    // straight through the top right corner, overshooting both axes the same
+    assert_eq!(rect.exit_side((5, 5), (15, 15)), Some(Side::Right));
+    assert_eq!(rect.exit_side((5, 5), (-5, -5)), Some(Side::Left));
+    // through the top right corner, but going further up than right
+    assert_eq!(rect.exit_side((8, 6), (12, 14)), Some(Side::Top));
+    assert_eq!(rect.exit_side((6, 8), (14, 12)), Some(Side::Right));
+}
+
+#[test]
+fn test_exit_side_stays_inside_or_starts_outside() {
+    let rect = rect();
+    assert_eq!(rect.exit_side((5, 5), (10, 0)), None);
+    assert_eq!(rect.exit_side((0, 0), (10, 10)), None);
+    assert_eq!(rect.exit_side((-1, 5), (-20, 5)), None);
+    assert_eq!(rect.exit_side((-1, 5), (5, 5)), None);
+}
+
+#[test]
+fn test_exit_side_rect() {
+    let rect = rect();
+    let dragged = BasicRectangle::new_from_sides(2, 4, 4, 2);
+    assert_eq!(
+        rect.exit_side_rect(&dragged, &dragged.translate(7, 0)),
+        Some(Side::Right)
+    );
+    assert_eq!(
+        rect.exit_side_rect(&dragged, &dragged.translate(-3, 1)),
+        Some(Side::Left)
+    );
+    assert_eq!(
+        rect.exit_side_rect(&dragged, &dragged.translate(1, 7)),
+        Some(Side::Top)
+    );
+    // touching the edge is still inside
+    assert_eq!(
+        rect.exit_side_rect(&dragged, &dragged.translate(6, 6)),
+        None
+    );
+    // too big to ever be inside
+    let wide = BasicRectangle::new_from_sides(0, 12, 4, 2);
+    assert_eq!(rect.exit_side_rect(&wide, &wide.translate(0, 20)), None);
+}
+
+#[test]
+fn test_exit_side_large_distances() {
+    // the cross products are far past i32, but the answer is still exact
+    let rect = BasicRectangle::new_from_sides(0, 100_000, 100_000, 0);
+    assert_eq!(
+        rect.exit_side((50_000, 50_000), (200_000, 200_001)),
+        Some(Side::Top)
+    );
+    assert_eq!(
+        rect.exit_side((50_000, 50_000), (200_001, 200_000)),
+        Some(Side::Right)
+    );
+    assert_eq!(
+        rect.exit_side((0, 0), (i32::MAX, i32::MAX - 1)),
+        Some(Side::Right)
+    );
+}
@@ -0,0 +1,252 @@
+use rect_lib::{Easing, RectTrack, Rectangle, RectangleRead};
+
+/// A rectangle with float sides, `f32` is only `PartialOrd`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct FloatRect {
+    sides: [f32; 4],
+}
+
+impl RectangleRead for FloatRect {
+    type Unit = f32;
+
+    fn left(&self) -> f32 {
+        self.sides[0]
+    }
+
+    fn right(&self) -> f32 {
+        self.sides[1]
+    }
+
+    fn top(&self) -> f32 {
+        self.sides[2]
+    }
+
+    fn bottom(&self) -> f32 {
+        self.sides[3]
+    }
+}
+
+impl Rectangle for FloatRect {
+    fn new_from_sides(left: f32, right: f32, top: f32, bottom: f32) -> Self {
+        Self {
+            sides: [left, right, top, bottom],
+        }
+    }
+}
+
+impl FloatRect {
+    /// The exact center, as a pair of floats.
+    fn center_f32(&self) -> (f32, f32) {
+        (
+            (self.left() + self.right()) / 2.0,
+            (self.top() + self.bottom()) / 2.0,
+        )
+    }
+}
+
+#[test]
+fn test_partial_ord_units_cover_the_basics() {
+    let rect = FloatRect::new_from_sides(0.0, 4.0, 3.0, 0.0);
+    let other = FloatRect::new_from_sides(2.5, 6.0, 5.0, 1.5);
+
+    assert!(rect.contains_point(0.5, 2.5));
+    assert!(!rect.contains_point(4.5, 2.5));
+    assert!(rect.overlaps(&other));
+    assert!(rect.contains_rectangle(&FloatRect::new_from_sides(1.0, 2.0, 2.0, 1.0)));
+    assert_eq!(
+        rect.intersection(&other),
+        Some(FloatRect::new_from_sides(2.5, 4.0, 3.0, 1.5))
+    );
+    assert_eq!(
+        FloatRect::from_corners((4.0, 0.0), (0.0, 3.0)),
+        FloatRect::new_from_sides(0.0, 4.0, 3.0, 0.0)
+    );
+    assert_eq!(rect.area(), 12.0);
+}
+
+#[test]
+fn test_partial_ord_units_not_exactly_representable() {
+    // none of these are exact in binary, but sides are only compared & copied so nothing drifts
+    let rect = FloatRect::new_from_sides(0.1, 0.3, 0.7, 0.2);
+    let other = FloatRect::new_from_sides(0.3, 0.9, 0.6, 0.1);
+
+    assert!(rect.contains_point(0.1, 0.2));
+    assert!(rect.contains_point(0.3, 0.7));
+    // sides are inclusive, so sharing the side at 0.3 is enough to overlap
+    assert!(rect.overlaps(&other));
+    assert_eq!(
+        rect.intersection(&other),
+        Some(FloatRect::new_from_sides(0.3, 0.3, 0.6, 0.2))
+    );
+    assert_eq!(
+        FloatRect::from_corners((0.3, 0.2), (0.1, 0.7)),
+        FloatRect::new_from_sides(0.1, 0.3, 0.7, 0.2)
+    );
+
+    // comparisons are exact, in `f32` 0.1 + 0.6 lands just above 0.7
+    assert!(rect.contains_point(0.2, 0.7));
+    assert!(!rect.contains_point(0.2, 0.1 + 0.6));
+    assert!((rect.area() - 0.1).abs() < 1e-6);
+}
+
+#[test]
+fn test_float_camera_fit_is_exact() {
+    // 10 wide & 1 tall, so 4:1 grows it to 2.5 tall instead of rounding up
+    let targets = [FloatRect::new_from_sides(0.0, 10.0, 1.0, 0.0)];
+    let frame = FloatRect::camera_fit(&targets, 4.0, 1.0, 0.0).unwrap();
+    assert_eq!(frame, FloatRect::new_from_sides(0.0, 10.0, 1.75, -0.75));
+    assert_eq!((frame.width(), frame.height()), (10.0, 2.5));
+}
+
+#[test]
+fn test_float_lerp_keeps_fractions() {
+    let rect = FloatRect::new_from_sides(0.0, 1.0, 1.0, 0.0);
+    let target = FloatRect::new_from_sides(1.0, 2.0, 2.0, 1.0);
+    assert_eq!(
+        rect.lerp(&target, 0.25),
+        FloatRect::new_from_sides(0.25, 1.25, 1.25, 0.25)
+    );
+
+    // ease in squares t, so halfway in time is a quarter of the way
+    let target = FloatRect::new_from_sides(1.0, 2.0, 1.0, 0.0);
+    assert_eq!(rect.ease_to(&target, 0.5, Easing::EaseIn).left(), 0.25);
+
+    let mut track = RectTrack::new();
+    track.push(0, rect);
+    track.push(4, target);
+    assert_eq!(
+        track.at(1),
+        Some(FloatRect::new_from_sides(0.25, 1.25, 1.0, 0.0))
+    );
+}
+
+#[test]
+fn test_float_lerp_size_centered_keeps_fractions() {
+    let rect = FloatRect::new_from_sides(0.0, 2.0, 2.0, 0.0);
+    let target = FloatRect::new_from_sides(10.0, 13.0, 13.0, 10.0);
+    // 2.5 across, so each side moves out by a quarter
+    assert_eq!(
+        rect.lerp_size_centered(&target, 0.5),
+        FloatRect::new_from_sides(-0.25, 2.25, 2.25, -0.25)
+    );
+}
+
+#[test]
+fn test_float_transforms_keep_fractions() {
+    let rect = FloatRect::new_from_sides(0.0, 1.0, 0.5, 0.0);
+    // scaling by 1.5 isn't rounded outwards to whole units
+    assert_eq!(
+        rect.transformed_aabb([1.5, 0.0, 0.0, 1.5, 0.0, 0.0]),
+        FloatRect::new_from_sides(0.0, 1.5, 0.75, 0.0)
+    );
+
+    // 3 by 1.75 is nearest 16:9, & the height needs the smaller change
+    let rect = FloatRect::new_from_sides(0.0, 3.0, 1.75, 0.0);
+    let snapped = rect.snap_to_common_aspect();
+    assert_eq!((snapped.width(), snapped.height()), (3.0, 3.0 * 9.0 / 16.0));
+}
+
+#[test]
+fn test_nan_sides_never_intersect_circles() {
+    let rect = FloatRect::new_from_sides(f32::NAN, 4.0, 3.0, 0.0);
+    assert!(!rect.intersects_circle_f64((2.0, 1.0), 10.0));
+}
+
+#[test]
+fn test_float_area_without_overflow() {
+    // float products don't divide back exactly, but they can't wrap either
+    let tenth = FloatRect::new_from_sides(0.0, 0.1, 0.1, 0.0);
+    assert!((tenth.area() - 0.01).abs() < 1e-6);
+    assert!((tenth.perimeter() - 0.4).abs() < 1e-6);
+    let third = FloatRect::new_from_sides(0.0, 1.0 / 3.0, 0.7, 0.0);
+    assert!((third.area() - 0.7 / 3.0).abs() < 1e-6);
+}
+
+#[test]
+fn test_align_center_to_pixel_grid_even() {
+    // 4 by 2, so the center lands on a whole pixel
+    let rect = FloatRect::new_from_sides(0.6, 4.6, 2.875, 0.875);
+    let snapped = rect.align_center_to_pixel_grid();
+    assert_eq!(snapped.center_f32(), (3.0, 2.0));
+    assert_eq!((snapped.width(), snapped.height()), (4.0, 2.0));
+}
+
+#[test]
+fn test_align_center_to_pixel_grid_odd() {
+    // 3 by 5, so the center lands halfway between pixels
+    let rect = FloatRect::new_from_sides(1.25, 4.25, 5.0, 0.0);
+    let snapped = rect.align_center_to_pixel_grid();
+    assert_eq!(snapped.center_f32(), (2.5, 2.5));
+    assert_eq!(snapped, FloatRect::new_from_sides(1.0, 4.0, 5.0, 0.0));
+
+    // already aligned rectangles don't move
+    assert_eq!(snapped.align_center_to_pixel_grid(), snapped);
+}
+
+#[test]
+fn test_approx_eq() {
+    let rect = FloatRect::new_from_sides(0.1, 0.7, 0.3, 0.0);
+    // rounding makes the computed top a little off
+    let computed = FloatRect::new_from_sides(0.1, 0.7, 0.7 - 0.4, 0.0);
+    assert_ne!(rect, computed);
+    assert!(rect.approx_eq(&computed, 1e-6));
+
+    let nudged = FloatRect::new_from_sides(0.1, 0.7, 0.3, 0.001);
+    assert!(rect.approx_eq(&nudged, 0.01));
+    assert!(!rect.approx_eq(&nudged, 0.0001));
+    assert!(!nudged.approx_eq(&rect, 0.0001));
+}
+
+#[test]
+fn test_cell_coverage_f64() {
+    let rect = FloatRect::new_from_sides(0.5, 3.5, 2.0, 0.0);
+    let cell = |x: f32, y: f32| FloatRect::new_from_sides(x, x + 1.0, y + 1.0, y);
+
+    // fully inside
+    assert_eq!(rect.cell_coverage_f64(&cell(1.0, 0.0)), 1.0);
+    // the left edge cuts the cell in half
+    assert_eq!(rect.cell_coverage_f64(&cell(0.0, 0.0)), 0.5);
+    // a corner cell is covered a quarter
+    assert_eq!(
+        FloatRect::new_from_sides(0.5, 3.0, 1.5, 0.0).cell_coverage_f64(&cell(0.0, 1.0)),
+        0.25
+    );
+    // nowhere near
+    assert_eq!(rect.cell_coverage_f64(&cell(5.0, 5.0)), 0.0);
+    // only touching the edge covers nothing
+    assert_eq!(rect.cell_coverage_f64(&cell(3.5, 0.0)), 0.0);
+}
+
+#[test]
+fn test_quantize_floats() {
+    let rect = FloatRect::new_from_sides(0.3, 2.9, 1.25, -1.25);
+    assert_eq!(
+        rect.quantize(0.5),
+        FloatRect::new_from_sides(0.5, 3.0, 1.5, -1.0)
+    );
+}
+
+#[test]
+fn test_float_content_hash_exact() {
+    let rect = |left: f32| FloatRect::new_from_sides(left, 1.0, 1.0, 0.0);
+    // fractions aren't truncated away, & NaN isn't zero
+    assert_ne!(rect(0.1).content_hash(), rect(0.9).content_hash());
+    assert_ne!(rect(0.1).content_hash(), rect(0.0).content_hash());
+    assert_ne!(rect(f32::NAN).content_hash(), rect(0.0).content_hash());
+    assert_eq!(rect(0.1).content_hash(), rect(0.1).content_hash());
+}
+
+#[test]
+fn test_float_coverage_heatmap_fractional_cells() {
+    // cells of half a unit, which used to truncate to zero
+    let bounds = FloatRect::new_from_sides(0.0, 0.9, 0.9, 0.0);
+    let rect = FloatRect::new_from_sides(0.6, 0.9, 0.2, 0.0);
+    let heatmap = bounds.coverage_heatmap(&[&rect], 0.5, 0.5);
+    assert_eq!(heatmap.dimensions(), (2, 2));
+    assert_eq!(heatmap.counts(), &[0, 0, 0, 1]);
+
+    // straddling the middle touches every cell
+    let middle = FloatRect::new_from_sides(0.4, 0.6, 0.6, 0.3);
+    let heatmap = bounds.coverage_heatmap(&[&middle], 0.5, 0.5);
+    assert_eq!(heatmap.counts(), &[1, 1, 1, 1]);
+}
@@ -0,0 +1,69 @@
+use rect_lib::{generate, BasicRectangle, Rectangle, RectangleRead};
+
+fn assert_disjoint_inside(parent: &BasicRectangle, rects: &[BasicRectangle]) {
+    for (i, rect) in rects.iter().enumerate() {
+        assert!(parent.contains_rectangle(rect), "{rect:?} is outside");
+        assert!(rect.left() <= rect.right() && rect.bottom() <= rect.top());
+        assert!(!rect.overlaps_any(&rects[i + 1..]), "{rect:?} overlaps");
+    }
+}
+
+fn free_components(parent: &BasicRectangle, walls: &[BasicRectangle]) -> usize {
+    let walls: Vec<&BasicRectangle> = walls.iter().collect();
+    parent.free_components(&walls).len()
+}
+
+#[test]
+fn test_corridor_maze_is_connected() {
+    let parent = BasicRectangle::new_from_sides(-10, 40, 25, -5);
+    for seed in 0..20 {
+        for corridor_width in [1, 2, 3] {
+            let walls = generate::corridor_maze(seed, &parent, corridor_width);
+            assert_disjoint_inside(&parent, &walls);
+            assert_eq!(free_components(&parent, &walls), 1);
+        }
+    }
+
+    // the same seed always gives the same maze
+    assert_eq!(
+        generate::corridor_maze(3, &parent, 2),
+        generate::corridor_maze(3, &parent, 2)
+    );
+
+    // too small for a single cell
+    let tiny = BasicRectangle::new_from_sides(0, 1, 1, 0);
+    assert_eq!(generate::corridor_maze(1, &tiny, 2), vec![tiny]);
+}
+
+#[test]
+fn test_packed_grid_is_disjoint() {
+    let parent = BasicRectangle::new_from_sides(0, 49, 29, 0);
+    for seed in 0..20 {
+        for jitter in [0, 1, 3] {
+            let boxes = generate::packed_grid(seed, &parent, jitter);
+            assert_disjoint_inside(&parent, &boxes);
+            let slot = 4 + 2 * jitter;
+            assert_eq!(boxes.len(), (50 / slot * (30 / slot)) as usize);
+            assert!(boxes
+                .iter()
+                .all(|rect| (rect.width(), rect.height()) == (3, 3)));
+        }
+    }
+}
+
+#[test]
+fn test_nested_frames_are_connected() {
+    let parent = BasicRectangle::new_from_sides(0, 39, 29, 0);
+    for seed in 0..20 {
+        let walls = generate::nested_frames(seed, &parent, 4);
+        assert_disjoint_inside(&parent, &walls);
+        assert_eq!(free_components(&parent, &walls), 1);
+        // 4 frames of 4 or 5 walls
+        assert!((16..=20).contains(&walls.len()));
+    }
+
+    // the frames stop once they no longer fit
+    let walls = generate::nested_frames(1, &parent, 100);
+    assert_disjoint_inside(&parent, &walls);
+    assert_eq!(free_components(&parent, &walls), 1);
+}
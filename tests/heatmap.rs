@@ -0,0 +1,44 @@
+use rect_lib::{BasicRectangle, Rectangle};
+
+#[test]
+fn test_coverage_heatmap_exact_cells() {
+    let bounds = BasicRectangle::new_from_sides(0, 9, 9, 0);
+    // exactly the top left 2 by 2 cells of 5 by 5
+    let rect = BasicRectangle::new_from_sides(0, 9, 9, 0);
+    let heatmap = bounds.coverage_heatmap(&[&rect], 5, 5);
+    assert_eq!(heatmap.dimensions(), (2, 2));
+    assert_eq!(heatmap.counts(), &[1, 1, 1, 1]);
+
+    let heatmap = bounds.coverage_heatmap(&[&BasicRectangle::new_from_sides(0, 4, 9, 5)], 5, 5);
+    assert_eq!(heatmap.counts(), &[1, 0, 0, 0]);
+}
+
+#[test]
+fn test_coverage_heatmap_straddling_by_one() {
+    let bounds = BasicRectangle::new_from_sides(0, 9, 9, 0);
+    // one unit past the first column's right side, so it touches the second column too
+    let rect = BasicRectangle::new_from_sides(0, 5, 9, 5);
+    let heatmap = bounds.coverage_heatmap(&[&rect], 5, 5);
+    assert_eq!(heatmap.get(0, 0), Some(1));
+    assert_eq!(heatmap.get(1, 0), Some(1));
+    assert_eq!(heatmap.get(0, 1), Some(0));
+    assert_eq!(heatmap.get(2, 0), None);
+}
+
+#[test]
+fn test_coverage_heatmap_accumulates() {
+    // negative coordinates & a partial column on the right
+    let bounds = BasicRectangle::new_from_sides(-10, 0, -1, -6);
+    let rects = [
+        BasicRectangle::new_from_sides(-10, -6, -1, -3),
+        BasicRectangle::new_from_sides(-8, 0, -2, -6),
+        BasicRectangle::new_from_sides(-20, 20, 20, -20),
+        BasicRectangle::new_from_sides(5, 6, 5, 0),
+    ];
+    let refs: Vec<&BasicRectangle> = rects.iter().collect();
+    let heatmap = bounds.coverage_heatmap(&refs, 3, 3);
+    assert_eq!(heatmap.dimensions(), (4, 2));
+    assert_eq!(heatmap.counts(), &[3, 3, 2, 2, 2, 2, 2, 2]);
+    assert_eq!(heatmap.max(), 3);
+    assert_eq!(heatmap.iter().nth(5), Some((1, 1, 2)));
+}
@@ -0,0 +1,177 @@
+mod common;
+
+use common::UnsignedRect;
+use rect_lib::{
+    greedy_cover, merge_overlapping, normalize_obstructions, BasicRectangle, Error, FillOrder,
+    FrameOrder, RectError, RectRegion, Rectangle, RectangleRead, RegionDecodeError,
+};
+
+/// Rectangles with their sides the wrong way around, which hold no points.
+fn inverted() -> [BasicRectangle; 3] {
+    [
+        BasicRectangle::new_from_sides(5, 0, 5, 0),
+        BasicRectangle::new_from_sides(0, 5, 0, 5),
+        BasicRectangle::new_from_sides(3, 2, 1, 2),
+    ]
+}
+
+#[test]
+fn test_error_messages() {
+    let error = Error::from(RectError::Overflow);
+    assert_eq!(
+        error.to_string(),
+        "invalid rectangle: rectangle sides overflow the unit type"
+    );
+    assert!(std::error::Error::source(&error).is_some());
+
+    let error = Error::from(RegionDecodeError::UnsupportedVersion(9));
+    assert_eq!(
+        error.to_string(),
+        "invalid region data: unsupported region format version 9"
+    );
+}
+
+#[test]
+fn test_inverted_rectangles_dont_panic() {
+    let parent = BasicRectangle::new_from_sides(0, 9, 9, 0);
+    // the perimeter & area each inverted rectangle reports
+    let measures = [(0, -25), (0, -25), (-4, 1)];
+    for (rect, (perimeter, area)) in inverted().into_iter().zip(measures) {
+        assert!(parent.intersection(&rect).is_none());
+        assert!(!parent.overlaps(&rect));
+        assert!(RectRegion::from(rect).is_empty());
+        assert!(normalize_obstructions(&parent, [rect]).is_empty());
+
+        // an inverted obstruction blocks nothing
+        assert_eq!(parent.unobstructed_subrectangles(&[&rect]), vec![parent]);
+        // & an inverted parent has nothing to find
+        assert!(rect.unobstructed_subrectangles(&[&parent]).is_empty());
+
+        parent.carve_out(&rect);
+        parent.split_around(&rect, FrameOrder::VerticalFirst);
+        parent.wrap_pieces(&rect);
+        parent.translate_wrapping(3, 3, &rect);
+        parent.overlaps_wrapped(&rect, &rect);
+        rect.wrap_point(4, 4);
+        rect.partition_into_at_most(4);
+        rect.squarified_treemap(&[1.0, 2.0]);
+        rect.grid_cells_unobstructed(3, 3, &[&parent]);
+        assert!(!rect.intersects_circle_f64((2.0, 2.0), 100.0));
+
+        // a rectangle with no points has no perimeter or area to speak of, but still gives a number
+        assert_eq!(rect.perimeter(), perimeter);
+        assert_eq!(rect.area(), area);
+        assert!(rect.cells_along_line((0, 0), (9, 9)).is_empty());
+        assert_eq!(rect.shrink_to_avoid(&[&parent], true), Some(rect));
+        assert_eq!(rect.shrink_to_avoid(&[&parent], false), Some(rect));
+        assert_eq!(rect.exit_side((1, 1), (20, 3)), None);
+        assert_eq!(rect.exit_side_rect(&parent, &parent), None);
+        assert_eq!(parent.exit_side_rect(&rect, &parent), None);
+        assert_eq!(parent.find_l_corridor(&rect, &parent, &[&parent], 1), None);
+        assert_eq!(rect.find_l_corridor(&parent, &rect, &[&rect], 3), None);
+        rect.lerp_size_centered(&parent, 0.5);
+        parent.lerp_size_centered(&rect, 0.5);
+        assert_eq!(
+            parent.split_around(&rect, FrameOrder::HorizontalFirst),
+            None
+        );
+        assert_eq!(
+            rect.split_around(&parent, FrameOrder::HorizontalFirst),
+            None
+        );
+    }
+
+    let inverted = inverted();
+    assert!(merge_overlapping(&inverted).is_empty());
+    let region: RectRegion<BasicRectangle> = inverted.into_iter().collect();
+    assert!(greedy_cover(&region, Some(1)).is_empty());
+}
+
+#[test]
+fn test_unsigned_edges_dont_panic() {
+    // nothing here may step below zero or past the largest value while working things out
+    let low = UnsignedRect::new_from_sides(0, 9, 9, 0);
+    let corner = UnsignedRect::new_from_sides(0, 2, 2, 0);
+    let high = UnsignedRect::new_from_sides(u32::MAX - 3, u32::MAX, u32::MAX, u32::MAX - 3);
+    let no_obstructions: &[&UnsignedRect] = &[];
+
+    assert_eq!(high.perimeter(), 12);
+    assert_eq!(high.area(), 9);
+    assert_eq!(low.cells_along_line((0, 0), (u32::MAX, 3)).len(), 10);
+    assert!(low.shrink_to_avoid(&[&corner], true).is_some());
+    assert!(low.shrink_to_avoid(&[&corner], false).is_some());
+    assert!(low.exit_side((0, 0), (u32::MAX, 0)).is_some());
+    assert!(low.exit_side_rect(&corner, &high).is_some());
+    assert_eq!(low.find_l_corridor(&corner, &high, &[&corner], 50), None);
+    assert_eq!(high.find_l_corridor(&high, &high, no_obstructions, 2), None);
+    assert_eq!(
+        low.split_around(&corner, FrameOrder::VerticalFirst)
+            .map(|pieces| pieces.iter().flatten().count()),
+        Some(2)
+    );
+    assert_eq!(
+        high.split_around(&high, FrameOrder::VerticalFirst),
+        Some([None; 4])
+    );
+    assert_eq!(
+        low.lerp_size_centered(&corner, 1.0),
+        UnsignedRect::new_from_sides(3, 5, 6, 4)
+    );
+}
+
+#[test]
+fn test_camera_fit_degenerate_aspect() {
+    let targets = [BasicRectangle::new_from_sides(0, 4, 4, 0)];
+    // there is no frame with a zero or negative side ratio
+    assert_eq!(BasicRectangle::camera_fit(&targets, 0, 9, 1), None);
+    assert_eq!(BasicRectangle::camera_fit(&targets, 16, 0, 1), None);
+    assert_eq!(BasicRectangle::camera_fit(&targets, -16, 9, 1), None);
+    assert_eq!(BasicRectangle::camera_fit(&targets, -16, -9, 1), None);
+}
+
+#[test]
+#[should_panic(expected = "cells must have a positive size")]
+fn test_fit_to_cell_grid_negative_size() {
+    BasicRectangle::new_from_sides(0, 9, 9, 0).fit_to_cell_grid(3, -4);
+}
+
+#[test]
+#[should_panic(expected = "cells must have a positive size")]
+fn test_cells_in_order_negative_width() {
+    // stepping left by a negative width would never pass the right side
+    BasicRectangle::new_from_sides(0, 9, 9, 0).cells_in_order(-2, 2, FillOrder::RowMajor);
+}
+
+#[test]
+#[should_panic(expected = "cells must have a positive size")]
+fn test_cells_in_order_negative_height() {
+    BasicRectangle::new_from_sides(0, 9, 9, 0).cells_in_order(2, -2, FillOrder::Boustrophedon);
+}
+
+#[test]
+fn test_hostile_weights_dont_panic() {
+    let rect = BasicRectangle::new_from_sides(0, 9, 9, 0);
+    for weights in [
+        vec![f64::NAN, 1.0],
+        vec![f64::INFINITY, 1.0],
+        vec![-1.0, -2.0],
+        vec![0.0; 20],
+        vec![],
+    ] {
+        assert_eq!(rect.columns_weighted(&weights).len(), weights.len());
+        assert_eq!(rect.rows_weighted(&weights).len(), weights.len());
+        assert_eq!(rect.squarified_treemap(&weights).len(), weights.len());
+    }
+}
+
+#[test]
+fn test_empty_inputs_dont_panic() {
+    let parent = BasicRectangle::new_from_sides(0, 9, 9, 0);
+    let none: [&BasicRectangle; 0] = [];
+    assert_eq!(parent.unobstructed_subrectangles(&none), vec![parent]);
+    assert_eq!(parent.free_components(&none).len(), 1);
+    assert_eq!(parent.frame_all(&[] as &[BasicRectangle]), parent);
+    assert!(merge_overlapping::<BasicRectangle>(&[]).is_empty());
+    assert!(greedy_cover(&RectRegion::<BasicRectangle>::new(), None).is_empty());
+    assert!(BasicRectangle::spanning_points(Vec::<(i32, i32)>::new()).is_none());
+}
@@ -0,0 +1,105 @@
+use rect_lib::{BasicRectangle, Oriented, Rectangle, RectangleRead};
+
+/// A rectangle where y grows downwards, like most windowing systems.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Window {
+    x: i32,
+    y: i32,
+    width: i32,
+    height: i32,
+}
+
+impl RectangleRead for Window {
+    type Unit = i32;
+
+    fn left(&self) -> i32 {
+        self.x
+    }
+
+    fn right(&self) -> i32 {
+        self.x + self.width - 1
+    }
+
+    fn top(&self) -> i32 {
+        self.y
+    }
+
+    fn bottom(&self) -> i32 {
+        self.y + self.height - 1
+    }
+}
+
+impl Rectangle for Window {
+    fn new_from_sides(left: i32, right: i32, top: i32, bottom: i32) -> Self {
+        Self {
+            x: left,
+            y: top,
+            width: right - left + 1,
+            height: bottom - top + 1,
+        }
+    }
+}
+
+fn window(x: i32, y: i32, width: i32, height: i32) -> Oriented<Window, true> {
+    Oriented(Window {
+        x,
+        y,
+        width,
+        height,
+    })
+}
+
+/// Flips a y down window into y up coordinates by hand.
+fn flipped(window: &Oriented<Window, true>) -> BasicRectangle {
+    let inner = window.0;
+    BasicRectangle::new_from_sides(inner.left(), inner.right(), -inner.top(), -inner.bottom())
+}
+
+#[test]
+fn test_oriented_sweep_matches_flipped() {
+    let screen = window(0, 0, 40, 30);
+    let obstructions = [
+        window(0, 0, 40, 3),
+        window(5, 10, 8, 8),
+        window(20, 6, 4, 20),
+        window(30, 25, 10, 5),
+    ];
+
+    let oriented = screen.unobstructed_subrectangles(&obstructions.iter().collect::<Vec<_>>());
+
+    let flipped_obstructions: Vec<BasicRectangle> = obstructions.iter().map(flipped).collect();
+    let by_hand = flipped(&screen)
+        .unobstructed_subrectangles(&flipped_obstructions.iter().collect::<Vec<_>>());
+
+    // the results are ordinary y down windows
+    for free in &oriented {
+        assert!(free.0.top() <= free.0.bottom());
+    }
+
+    let mut oriented: Vec<BasicRectangle> = oriented.iter().map(flipped).collect();
+    let sort_key = |rect: &BasicRectangle| (rect.left(), rect.top(), rect.right(), rect.bottom());
+    oriented.sort_by_key(sort_key);
+    let mut by_hand = by_hand;
+    by_hand.sort_by_key(sort_key);
+    assert_eq!(oriented, by_hand);
+}
+
+#[test]
+fn test_oriented_accessors() {
+    let title_bar = window(0, 0, 10, 2);
+    // y down top 0 & bottom 1 become y up top 1 & bottom 0
+    assert_eq!((title_bar.top(), title_bar.bottom()), (1, 0));
+    assert_eq!(title_bar.height(), 1);
+    assert!(title_bar.contains_point(3, 1));
+
+    let rebuilt = Oriented::<Window, true>::new_from_sides(0, 9, 1, 0);
+    assert_eq!(rebuilt, title_bar);
+
+    // without Y_DOWN nothing changes
+    let plain = Oriented::<_, false>(BasicRectangle::new_from_sides(0, 1, 5, 2));
+    assert_eq!((plain.top(), plain.bottom()), (5, 2));
+    assert_eq!(
+        plain.into_inner(),
+        BasicRectangle::new_from_sides(0, 1, 5, 2)
+    );
+}
@@ -0,0 +1,132 @@
+use std::num::Wrapping;
+
+use rect_lib::{
+    coverage_fraction, union_area, BasicRectangle, RectRegion, Rectangle, RectangleRead,
+};
+
+/// A rectangle whose unit wraps silently instead of panicking on overflow.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct WrappingRectangle {
+    left: Wrapping<i32>,
+    right: Wrapping<i32>,
+    top: Wrapping<i32>,
+    bottom: Wrapping<i32>,
+}
+
+impl RectangleRead for WrappingRectangle {
+    type Unit = Wrapping<i32>;
+
+    fn left(&self) -> Wrapping<i32> {
+        self.left
+    }
+
+    fn right(&self) -> Wrapping<i32> {
+        self.right
+    }
+
+    fn top(&self) -> Wrapping<i32> {
+        self.top
+    }
+
+    fn bottom(&self) -> Wrapping<i32> {
+        self.bottom
+    }
+}
+
+impl Rectangle for WrappingRectangle {
+    fn new_from_sides(
+        left: Wrapping<i32>,
+        right: Wrapping<i32>,
+        top: Wrapping<i32>,
+        bottom: Wrapping<i32>,
+    ) -> Self {
+        Self {
+            left,
+            right,
+            top,
+            bottom,
+        }
+    }
+}
+
+fn wrapping_rect(left: i32, right: i32, top: i32, bottom: i32) -> WrappingRectangle {
+    WrappingRectangle::new_from_sides(
+        Wrapping(left),
+        Wrapping(right),
+        Wrapping(top),
+        Wrapping(bottom),
+    )
+}
+
+#[test]
+fn test_area_without_overflow() {
+    let rect = wrapping_rect(0, 1 << 15, 1 << 15, 0);
+    assert_eq!(rect.area(), Wrapping(1 << 30));
+    assert_eq!(rect.perimeter(), Wrapping(1 << 17));
+}
+
+#[test]
+fn test_area_inverted_without_overflow() {
+    // inverted rectangles have negative sizes, which aren't overflows
+    let inverted = BasicRectangle::new_from_sides(0, 5, 0, 5);
+    assert_eq!(inverted.perimeter(), 0);
+    assert_eq!(inverted.area(), -25);
+    let inverted = wrapping_rect(0, 5, 0, 5);
+    assert_eq!(inverted.perimeter(), Wrapping(0));
+    let inverted = wrapping_rect(5, 0, 3, 0);
+    assert_eq!(inverted.perimeter(), Wrapping(-4));
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "rectangle area overflowed its unit type")]
+fn test_area_overflow_asserts() {
+    let rect = wrapping_rect(0, 1 << 16, 1 << 16, 0);
+    rect.area();
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "rectangle perimeter overflowed its unit type")]
+fn test_perimeter_overflow_asserts() {
+    let rect = wrapping_rect(0, 1 << 30, 1 << 30, 0);
+    rect.perimeter();
+}
+
+#[test]
+fn test_widened_areas_past_i32() {
+    // a 2^20 square parent holds 2^40 points, far past i32::MAX
+    let parent = BasicRectangle::new_from_sides(0, (1 << 20) - 1, (1 << 20) - 1, 0);
+    let obstructions: Vec<BasicRectangle> = (0..1000)
+        .map(|i| {
+            let x = i * 1000;
+            BasicRectangle::new_from_sides(x, x + 499, (1 << 20) - 1, 1 << 19)
+        })
+        .collect();
+
+    let obstructed = 1000 * 500 * (1i128 << 19);
+    assert_eq!(union_area(&obstructions), obstructed);
+    assert_eq!(
+        parent.unobstructed_area(&obstructions.iter().collect::<Vec<_>>()),
+        (1i128 << 40) - obstructed
+    );
+    let fraction = coverage_fraction(&parent, &obstructions);
+    assert!((fraction - obstructed as f64 / (1u64 << 40) as f64).abs() < 1e-12);
+
+    let region: RectRegion<_> = obstructions.iter().copied().collect();
+    assert_eq!(region.area_wide(), obstructed);
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "region area overflowed its unit type")]
+fn test_region_area_overflow_asserts() {
+    // three apart squares of 2^30 points each
+    let region: RectRegion<_> = (0..3)
+        .map(|i| {
+            let x = i << 16;
+            wrapping_rect(x, x + (1 << 15) - 1, (1 << 15) - 1, 0)
+        })
+        .collect();
+    region.area();
+}
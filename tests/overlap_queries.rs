@@ -0,0 +1,140 @@
+use rect_lib::{BasicRectangle, Rectangle, RectangleRead};
+
+mod common;
+use common::{CountingRect, Rng};
+
+#[test]
+fn test_overlaps_any_stops_at_first_overlap() {
+    let rect = BasicRectangle::new_from_sides(0, 4, 4, 0);
+    let others = [
+        CountingRect::new_from_sides(10, 12, 12, 10),
+        CountingRect::new_from_sides(3, 6, 6, 3),
+        CountingRect::new_from_sides(1, 2, 2, 1),
+        CountingRect::new_from_sides(0, 9, 9, 0),
+    ];
+
+    assert!(rect.overlaps_any(&others));
+    assert!(others[0].reads() > 0);
+    assert!(others[1].reads() > 0);
+    // nothing after the first overlap is looked at
+    assert!(others[2..].iter().all(|other| other.reads() == 0));
+}
+
+#[test]
+fn test_first_overlapping_returns_earliest_index() {
+    let rect = BasicRectangle::new_from_sides(0, 4, 4, 0);
+    let others = [
+        BasicRectangle::new_from_sides(10, 12, 12, 10),
+        BasicRectangle::new_from_sides(5, 6, 6, 5),
+        BasicRectangle::new_from_sides(4, 6, 2, 1),
+        BasicRectangle::new_from_sides(1, 2, 2, 1),
+        BasicRectangle::new_from_sides(-3, 0, 0, -3),
+    ];
+
+    // several overlap, the earliest in the slice wins
+    assert_eq!(rect.first_overlapping(&others), Some((2, &others[2])));
+    assert_eq!(rect.first_overlapping(&others[3..]), Some((0, &others[3])));
+    assert_eq!(rect.first_overlapping(&others[..2]), None);
+}
+
+#[test]
+fn test_overlap_queries_match_naive_scans() {
+    let mut rng = Rng(0x9e37_79b9_7f4a_7c15);
+
+    for _ in 0..200 {
+        let count = rng.next(12) as usize;
+        let mut others: Vec<BasicRectangle> = (0..count).map(|_| rng.rect(30)).collect();
+        let rect = rng.rect(30);
+
+        let naive: Vec<usize> = (0..others.len())
+            .filter(|&i| rect.overlaps(&others[i]))
+            .collect();
+        assert_eq!(rect.overlaps_any(&others), !naive.is_empty());
+        assert_eq!(
+            rect.first_overlapping(&others).map(|(i, _)| i),
+            naive.first().copied()
+        );
+        assert_eq!(rect.count_overlapping(&others), naive.len());
+
+        // the sorted window must give the same answers
+        others.sort_by_key(|other| other.left());
+        let window = rect.overlap_window(&others);
+        let naive: Vec<usize> = (0..others.len())
+            .filter(|&i| rect.overlaps(&others[i]))
+            .collect();
+        assert_eq!(rect.overlaps_any(window), !naive.is_empty());
+        assert_eq!(
+            rect.first_overlapping(window).map(|(i, _)| i),
+            naive.first().copied()
+        );
+        assert_eq!(rect.count_overlapping(window), naive.len());
+    }
+}
+
+#[test]
+fn test_intersections_with_matches_intersection() {
+    let mut rng = Rng(0x9e37_79b9_7f4a_7c15);
+    for _ in 0..100 {
+        let rect = rng.rect(30);
+        let mut others: Vec<BasicRectangle> = (0..20).map(|_| rng.rect(30)).collect();
+
+        let expected: Vec<(usize, BasicRectangle)> = others
+            .iter()
+            .enumerate()
+            .filter_map(|(i, other)| Some((i, rect.intersection(other)?)))
+            .collect();
+        assert_eq!(rect.intersections_with(&others), expected);
+
+        let mut out = vec![(99, rect)];
+        rect.intersections_with_into(&others, &mut out);
+        assert_eq!(out, expected);
+
+        // sorted input gives the same intersections, by the sorted indices
+        others.sort_by_key(|other| other.left());
+        let expected: Vec<(usize, BasicRectangle)> = others
+            .iter()
+            .enumerate()
+            .filter_map(|(i, other)| Some((i, rect.intersection(other)?)))
+            .collect();
+        assert_eq!(rect.intersections_with_sorted(&others), expected);
+        rect.intersections_with_sorted_into(&others, &mut out);
+        assert_eq!(out, expected);
+    }
+}
+
+#[test]
+fn test_intersections_with_none_and_all() {
+    let rect = BasicRectangle::new_from_sides(0, 9, 9, 0);
+    let outside = [
+        BasicRectangle::new_from_sides(10, 12, 2, 0),
+        BasicRectangle::new_from_sides(0, 9, 20, 10),
+        BasicRectangle::new_from_sides(-5, -1, 9, 0),
+    ];
+    assert!(rect.intersections_with(&outside).is_empty());
+    assert!(rect.intersections_with_sorted(&outside[..0]).is_empty());
+
+    let inside = [
+        BasicRectangle::new_from_sides(1, 2, 2, 1),
+        BasicRectangle::new_from_sides(-5, 20, 20, -5),
+        BasicRectangle::new_from_sides(9, 9, 9, 9),
+    ];
+    assert_eq!(
+        rect.intersections_with(&inside),
+        vec![(0, inside[0]), (1, rect), (2, inside[2])]
+    );
+}
+
+#[test]
+fn test_intersections_with_reads_each_side_once() {
+    let rect = BasicRectangle::new_from_sides(0, 4, 4, 0);
+    let others = [
+        CountingRect::new_from_sides(10, 12, 12, 10),
+        CountingRect::new_from_sides(3, 6, 6, 3),
+    ];
+
+    assert_eq!(
+        rect.intersections_with(&others),
+        vec![(1, BasicRectangle::new_from_sides(3, 4, 4, 3))]
+    );
+    assert!(others.iter().all(|other| other.reads() == 4));
+}
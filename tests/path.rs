@@ -0,0 +1,109 @@
+use rect_lib::{sweep_along_path, BasicRectangle, Easing, Extent, Rectangle};
+
+fn covered(rects: &[BasicRectangle], x: i32, y: i32) -> bool {
+    rects.iter().any(|rect| rect.contains_point(x, y))
+}
+
+#[test]
+fn test_sweep_along_path_empty() {
+    assert!(sweep_along_path(Extent::new(2, 2), &[], 1).is_empty());
+
+    let single = sweep_along_path(Extent::new(2, 2), &[(3, 3)], 1);
+    assert_eq!(single, vec![BasicRectangle::new_from_sides(2, 4, 4, 2)]);
+}
+
+#[test]
+fn test_sweep_along_path_straight_line() {
+    let size = Extent::new(2, 2);
+    let rects = sweep_along_path(size, &[(0, 0), (10, 0)], 3);
+
+    // the region swept by a 2x2 rectangle moving from (0, 0) to (10, 0)
+    let swept = BasicRectangle::new_from_sides(-1, 11, 1, -1);
+    for x in -3..=13 {
+        for y in -3..=3 {
+            assert_eq!(
+                covered(&rects, x, y),
+                swept.contains_point(x, y),
+                "({x}, {y})"
+            );
+        }
+    }
+}
+
+#[test]
+fn test_sweep_along_path_right_angle() {
+    let rects = sweep_along_path(Extent::new(0, 0), &[(0, 0), (7, 0), (7, 7)], 3);
+
+    // the corner is always included
+    assert!(covered(&rects, 7, 0));
+    // both ends are included
+    assert!(covered(&rects, 0, 0));
+    assert!(covered(&rects, 7, 7));
+    // evenly spaced along each segment
+    assert!(covered(&rects, 3, 0) && covered(&rects, 6, 0));
+    assert!(covered(&rects, 7, 3) && covered(&rects, 7, 6));
+    assert_eq!(rects.len(), 7);
+}
+
+#[test]
+fn test_interpolate_to() {
+    let start = BasicRectangle::new_from_sides(0, 2, 2, 0);
+    let end = BasicRectangle::new_from_sides(8, 16, 8, 2);
+    let steps = start.interpolate_to(&end, 3);
+
+    assert_eq!(
+        steps,
+        vec![
+            BasicRectangle::new_from_sides(2, 6, 4, 1),
+            BasicRectangle::new_from_sides(4, 9, 5, 1),
+            BasicRectangle::new_from_sides(6, 13, 7, 2),
+        ]
+    );
+    assert!(start.interpolate_to(&end, 0).is_empty());
+}
+
+#[test]
+fn test_lerp_ends() {
+    let start = BasicRectangle::new_from_sides(0, 2, 2, 0);
+    let end = BasicRectangle::new_from_sides(8, 16, 8, 2);
+    assert_eq!(start.lerp(&end, 0.0), start);
+    assert_eq!(start.lerp(&end, 1.0), end);
+    // clamped
+    assert_eq!(start.lerp(&end, 2.0), end);
+}
+
+#[test]
+fn test_ease_in_out_midpoint() {
+    let start = BasicRectangle::new_from_sides(0, 10, 10, 0);
+    let end = BasicRectangle::new_from_sides(100, 140, 210, 50);
+
+    // halfway through it is halfway there, just like linear
+    assert_eq!(
+        start.ease_to(&end, 0.5, Easing::EaseInOut),
+        start.lerp(&end, 0.5)
+    );
+    assert_eq!(
+        start.ease_to(&end, 0.5, Easing::Linear),
+        start.lerp(&end, 0.5)
+    );
+
+    // but it lags behind before & runs ahead after
+    let linear = start.lerp(&end, 0.25);
+    let eased = start.ease_to(&end, 0.25, Easing::EaseInOut);
+    assert_ne!(eased, linear);
+    assert!(eased.left() < linear.left());
+    let linear = start.lerp(&end, 0.75);
+    let eased = start.ease_to(&end, 0.75, Easing::EaseInOut);
+    assert!(eased.left() > linear.left());
+
+    // every curve starts & ends in the same place
+    for easing in [
+        Easing::Linear,
+        Easing::EaseIn,
+        Easing::EaseOut,
+        Easing::EaseInOut,
+    ] {
+        assert_eq!(start.ease_to(&end, 0.0, easing), start);
+        assert_eq!(start.ease_to(&end, 1.0, easing), end);
+    }
+}
@@ -0,0 +1,89 @@
+use rect_lib::{rect, BasicRectangle, RectBuilder, RectError, Rectangle, RectangleRead};
+
+/// A rectangle storing its sides as `i64`, to check the builder isn't tied to `BasicRectangle`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Sides {
+    left: i64,
+    right: i64,
+    top: i64,
+    bottom: i64,
+}
+
+impl RectangleRead for Sides {
+    type Unit = i64;
+
+    fn left(&self) -> i64 {
+        self.left
+    }
+
+    fn right(&self) -> i64 {
+        self.right
+    }
+
+    fn top(&self) -> i64 {
+        self.top
+    }
+
+    fn bottom(&self) -> i64 {
+        self.bottom
+    }
+}
+
+impl Rectangle for Sides {
+    fn new_from_sides(left: i64, right: i64, top: i64, bottom: i64) -> Self {
+        Self {
+            left,
+            right,
+            top,
+            bottom,
+        }
+    }
+}
+
+#[test]
+fn test_rect_builder_build() {
+    // the order the sides are set in doesn't matter
+    let rect = RectBuilder::new()
+        .bottom(-2)
+        .top(4)
+        .right(3)
+        .left(1)
+        .build::<BasicRectangle>();
+    assert_eq!(rect, Ok(BasicRectangle::new_from_sides(1, 3, 4, -2)));
+
+    let point: Result<BasicRectangle, _> = rect!(left: 1, right: 1, top: 1, bottom: 1);
+    assert_eq!(point, Ok(BasicRectangle::new_from_sides(1, 1, 1, 1)));
+}
+
+#[test]
+fn test_rect_builder_missing_side() {
+    let rect = RectBuilder::new()
+        .left(0)
+        .right(5)
+        .top(5)
+        .build::<BasicRectangle>();
+    assert_eq!(rect, Err(RectError::MissingSide));
+
+    let rect: Result<BasicRectangle, _> = rect!();
+    assert_eq!(rect, Err(RectError::MissingSide));
+}
+
+#[test]
+fn test_rect_builder_inverted_side() {
+    let rect: Result<BasicRectangle, _> = rect!(left: 0, right: 5, top: 0, bottom: 5);
+    assert_eq!(rect, Err(RectError::NegativeSize));
+}
+
+#[test]
+fn test_rect_builder_other_impl() {
+    let rect: Result<Sides, _> = rect!(top: 9_000_000_000, bottom: 0, left: -1, right: 1);
+    assert_eq!(
+        rect,
+        Ok(Sides {
+            left: -1,
+            right: 1,
+            top: 9_000_000_000,
+            bottom: 0
+        })
+    );
+}
@@ -0,0 +1,56 @@
+use rect_lib::{BasicRectangle, RectFingerprintSet, Rectangle};
+
+/// Every rectangle collides with every other.
+fn colliding(_: &BasicRectangle) -> u64 {
+    7
+}
+
+#[test]
+fn test_fingerprint_stable() {
+    let rect = BasicRectangle::new_from_sides(-3, 5, 8, 1);
+    let fingerprint = RectFingerprintSet::fingerprint(&rect);
+    assert_eq!(fingerprint, RectFingerprintSet::fingerprint(&rect));
+    assert_ne!(
+        fingerprint,
+        RectFingerprintSet::fingerprint(&BasicRectangle::new_from_sides(-3, 5, 8, 2))
+    );
+}
+
+#[test]
+fn test_fingerprint_set_exact_collisions() {
+    let rects: Vec<_> = (0..20)
+        .map(|i| BasicRectangle::new_from_sides(i, i + 1, i, i - 1))
+        .collect();
+
+    let mut exact = RectFingerprintSet::exact().with_key(colliding);
+    assert!(rects.iter().all(|rect| exact.insert_if_new(rect)));
+    assert!(rects.iter().all(|rect| !exact.insert_if_new(rect)));
+    assert_eq!(exact.len(), 20);
+
+    // without the side table every collision looks like a duplicate
+    let mut approximate = RectFingerprintSet::new().with_key(colliding);
+    assert!(approximate.insert_if_new(&rects[0]));
+    assert!(rects[1..]
+        .iter()
+        .all(|rect| !approximate.insert_if_new(rect)));
+    assert_eq!(approximate.len(), 1);
+}
+
+#[test]
+fn test_fingerprint_set_approximate_memory() {
+    let mut seen = RectFingerprintSet::new();
+    for i in 0..1000 {
+        let rect = BasicRectangle::new_from_sides(i, i + 2, 2 * i, i);
+        assert!(seen.insert_if_new(&rect));
+        assert!(!seen.insert_if_new(&rect));
+    }
+
+    // only the 8 byte fingerprints are stored
+    assert_eq!(seen.len(), 1000);
+    assert!(seen.capacity() >= 1000);
+    assert_eq!(seen.stored_rects(), 0);
+
+    let mut exact = RectFingerprintSet::exact();
+    exact.insert_if_new(&BasicRectangle::new_from_sides(0, 1, 1, 0));
+    assert_eq!(exact.stored_rects(), 1);
+}
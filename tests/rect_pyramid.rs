@@ -0,0 +1,162 @@
+use rect_lib::{BasicRectangle, Occupancy, RectPyramid, Rectangle};
+
+mod common;
+use common::Rng;
+
+/// Checks the occupancy of a region one point at a time.
+fn brute_force(region: &BasicRectangle, rects: &[BasicRectangle]) -> Occupancy {
+    let mut covered = 0;
+    let mut total = 0;
+    for x in region.left()..=region.right() {
+        for y in region.bottom()..=region.top() {
+            total += 1;
+            if rects.iter().any(|rect| rect.contains_point(x, y)) {
+                covered += 1;
+            }
+        }
+    }
+
+    match covered {
+        0 => Occupancy::Empty,
+        _ if covered == total => Occupancy::Full,
+        _ => Occupancy::Partial,
+    }
+}
+
+fn scene() -> (BasicRectangle, Vec<BasicRectangle>) {
+    let bounds = BasicRectangle::new_from_sides(0, 15, 15, 0);
+    let rects = vec![
+        BasicRectangle::new_from_sides(0, 5, 15, 10),
+        BasicRectangle::new_from_sides(4, 9, 12, 7),
+        BasicRectangle::new_from_sides(12, 15, 3, 0),
+        BasicRectangle::new_from_sides(13, 13, 9, 5),
+    ];
+    (bounds, rects)
+}
+
+#[test]
+fn test_rect_pyramid_empty() {
+    let (bounds, rects) = scene();
+    let pyramid = RectPyramid::new(bounds, &rects, 4);
+    assert_eq!(
+        pyramid.query(&BasicRectangle::new_from_sides(0, 3, 6, 0)),
+        Occupancy::Empty
+    );
+    // outside of the bounds
+    assert_eq!(
+        pyramid.query(&BasicRectangle::new_from_sides(20, 30, 30, 20)),
+        Occupancy::Empty
+    );
+}
+
+#[test]
+fn test_rect_pyramid_full() {
+    let (bounds, rects) = scene();
+    let pyramid = RectPyramid::new(bounds, &rects, 4);
+    // covered by two rectangles together
+    assert_eq!(
+        pyramid.query(&BasicRectangle::new_from_sides(1, 8, 11, 10)),
+        Occupancy::Full
+    );
+    assert_eq!(
+        pyramid.query(&BasicRectangle::new_from_sides(12, 15, 3, 0)),
+        Occupancy::Full
+    );
+}
+
+#[test]
+fn test_rect_pyramid_partial() {
+    let (bounds, rects) = scene();
+    let pyramid = RectPyramid::new(bounds, &rects, 4);
+    // straddles the edge of an obstruction
+    assert_eq!(
+        pyramid.query(&BasicRectangle::new_from_sides(9, 10, 8, 7)),
+        Occupancy::Partial
+    );
+    assert_eq!(pyramid.query(&bounds), Occupancy::Partial);
+}
+
+#[test]
+fn test_rect_pyramid_matches_brute_force() {
+    let (bounds, rects) = scene();
+    for depth in 0..5 {
+        let pyramid = RectPyramid::new(bounds, &rects, depth);
+        for left in (0..16).step_by(3) {
+            for bottom in (0..16).step_by(2) {
+                for size in [0, 1, 4, 7] {
+                    let region = BasicRectangle::new_from_sides(
+                        left,
+                        (left + size).min(15),
+                        (bottom + size).min(15),
+                        bottom,
+                    );
+                    assert_eq!(
+                        pyramid.query(&region),
+                        brute_force(&region, &rects),
+                        "depth {depth} region {region:?}"
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_rect_pyramid_random_matches_brute_force() {
+    let mut rng = Rng(0x9e37_79b9_7f4a_7c15);
+    for _ in 0..40 {
+        // odd sizes away from the origin, so the grids don't line up with the bounds
+        let (left, bottom) = (rng.next(10) - 5, rng.next(10) - 5);
+        let bounds = BasicRectangle::new_from_sides(
+            left,
+            left + 4 + rng.next(14),
+            bottom + 4 + rng.next(14),
+            bottom,
+        );
+        let rects: Vec<BasicRectangle> = (0..rng.next(6))
+            .map(|_| rng.rect(24).translate(-7, -7))
+            .collect();
+
+        for depth in 0..6 {
+            let pyramid = RectPyramid::new(bounds, &rects, depth);
+            for _ in 0..20 {
+                let region = rng.rect(24).translate(-7, -7);
+                let expected = match bounds.intersection(&region) {
+                    Some(clipped) => brute_force(&clipped, &rects),
+                    None => Occupancy::Empty,
+                };
+                assert_eq!(
+                    pyramid.query(&region),
+                    expected,
+                    "depth {depth} bounds {bounds:?} region {region:?}"
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn test_iteration_order_is_stable() {
+    let bounds = BasicRectangle::new_from_sides(0, 15, 15, 0);
+    let rects = [
+        BasicRectangle::new_from_sides(8, 9, 3, 0),
+        BasicRectangle::new_from_sides(0, 3, 15, 12),
+        BasicRectangle::new_from_sides(5, 12, 15, 10),
+        BasicRectangle::new_from_sides(20, 21, 20, 20),
+    ];
+
+    // deeper pyramids split far more cells, but iterate the same
+    for depth in 0..5 {
+        let pyramid = RectPyramid::new(bounds, &rects, depth);
+        assert_eq!(pyramid.len(), rects.len());
+        assert_eq!(pyramid.iter().copied().collect::<Vec<_>>(), rects);
+        assert_eq!((&pyramid).into_iter().copied().collect::<Vec<_>>(), rects);
+        // the one outside of the bounds is kept too, & is topmost
+        assert_eq!(
+            pyramid.iter_sorted().copied().collect::<Vec<_>>(),
+            vec![rects[3], rects[1], rects[2], rects[0]]
+        );
+        assert_eq!(pyramid.into_iter().collect::<Vec<_>>(), rects);
+    }
+    assert!(RectPyramid::new(bounds, &[], 2).is_empty());
+}
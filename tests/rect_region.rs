@@ -0,0 +1,389 @@
+use std::collections::BTreeSet;
+
+use rect_lib::{
+    boolean_op, drop_slivers, greedy_cover, BasicRectangle, BoolOp, RectRegion, Rectangle,
+};
+
+mod common;
+use common::Rng;
+
+type Points = BTreeSet<(i32, i32)>;
+
+fn rasterize<'a>(rects: impl IntoIterator<Item = &'a BasicRectangle>) -> Points {
+    rects
+        .into_iter()
+        .flat_map(|rect| {
+            (rect.left()..=rect.right())
+                .flat_map(move |x| (rect.bottom()..=rect.top()).map(move |y| (x, y)))
+        })
+        .collect()
+}
+
+fn assert_disjoint(region: &RectRegion<BasicRectangle>) {
+    let rects: Vec<_> = region.iter().collect();
+    for (i, a) in rects.iter().enumerate() {
+        for b in &rects[i + 1..] {
+            assert!(!a.overlaps(*b), "{a:?} overlaps {b:?}");
+        }
+    }
+}
+
+#[test]
+fn test_rect_region_from_overlapping() {
+    let region: RectRegion<_> = [
+        BasicRectangle::new_from_sides(0, 4, 4, 0),
+        BasicRectangle::new_from_sides(2, 6, 6, 2),
+        BasicRectangle::new_from_sides(1, 1, 1, 1),
+    ]
+    .into_iter()
+    .collect();
+
+    assert_disjoint(&region);
+    assert_eq!(region.area(), 25 + 25 - 9);
+    assert_eq!(
+        region.bounding_box(),
+        Some(BasicRectangle::new_from_sides(0, 6, 6, 0))
+    );
+}
+
+#[test]
+fn test_rect_region_empty() {
+    let region: RectRegion<BasicRectangle> = RectRegion::new();
+    assert!(region.is_empty());
+    assert_eq!(region.area(), 0);
+    assert_eq!(region.bounding_box(), None);
+    assert!(!region.contains_point(0, 0));
+}
+
+#[test]
+fn test_rect_region_canonical() {
+    // the same points described two different ways
+    let a: RectRegion<_> = [
+        BasicRectangle::new_from_sides(0, 5, 2, 0),
+        BasicRectangle::new_from_sides(0, 2, 5, 3),
+    ]
+    .into_iter()
+    .collect();
+    let b: RectRegion<_> = [
+        BasicRectangle::new_from_sides(0, 2, 5, 0),
+        BasicRectangle::new_from_sides(3, 5, 2, 0),
+    ]
+    .into_iter()
+    .collect();
+    assert_eq!(a, b);
+}
+
+#[test]
+fn test_rect_region_matches_raster() {
+    let mut rng = Rng(0x9e37_79b9_7f4a_7c15);
+    for _ in 0..200 {
+        let (count_a, count_b) = (rng.next(4) as usize, rng.next(4) as usize);
+        let rects_a = rng.rects(count_a, 10);
+        let rects_b = rng.rects(count_b, 10);
+        let (points_a, points_b) = (rasterize(&rects_a), rasterize(&rects_b));
+
+        let a: RectRegion<_> = rects_a.iter().copied().collect();
+        let b: RectRegion<_> = rects_b.iter().copied().collect();
+
+        let cases = [
+            (
+                a.union(&b),
+                points_a.union(&points_b).copied().collect::<Points>(),
+            ),
+            (
+                a.intersect(&b),
+                points_a.intersection(&points_b).copied().collect(),
+            ),
+            (
+                a.subtract(&b),
+                points_a.difference(&points_b).copied().collect(),
+            ),
+            (
+                a.xor(&b),
+                points_a.symmetric_difference(&points_b).copied().collect(),
+            ),
+        ];
+        for (region, expected) in cases {
+            assert_disjoint(&region);
+            assert_eq!(rasterize(region.iter()), expected);
+            assert_eq!(region.area() as usize, expected.len());
+            for &(x, y) in &expected {
+                assert!(region.contains_point(x, y));
+            }
+        }
+    }
+}
+
+#[test]
+fn test_rect_region_laws() {
+    let mut rng = Rng(0x2545_f491_4f6c_dd1d);
+    for _ in 0..200 {
+        let (count_a, count_b) = (rng.next(4) as usize, rng.next(4) as usize);
+        let a: RectRegion<_> = rng.rects(count_a, 10).into_iter().collect();
+        let b: RectRegion<_> = rng.rects(count_b, 10).into_iter().collect();
+
+        assert_eq!(a.union(&b).subtract(&b), a.subtract(&b));
+        assert_eq!(a.union(&b), b.union(&a));
+        assert_eq!(a.intersect(&b), a.subtract(&a.subtract(&b)));
+        assert_eq!(a.xor(&b), a.subtract(&b).union(&b.subtract(&a)));
+        assert_eq!(a.union(&a), a);
+        assert!(a.subtract(&a).is_empty());
+    }
+}
+
+#[test]
+fn test_boolean_op() {
+    let bounds = BasicRectangle::new_from_sides(0, 7, 7, 0);
+    let a = [
+        BasicRectangle::new_from_sides(0, 3, 3, 0),
+        BasicRectangle::new_from_sides(2, 5, 5, 2),
+    ];
+    let b = [BasicRectangle::new_from_sides(3, 9, 4, 1)];
+
+    let (in_a, in_b, in_bounds) = (rasterize(&a), rasterize(&b), rasterize([&bounds]));
+    let expected: [(BoolOp, Points); 4] = [
+        (BoolOp::Union, in_a.union(&in_b).copied().collect()),
+        (
+            BoolOp::Intersection,
+            in_a.intersection(&in_b).copied().collect(),
+        ),
+        (
+            BoolOp::Difference,
+            in_a.difference(&in_b).copied().collect(),
+        ),
+        (
+            BoolOp::Xor,
+            in_a.symmetric_difference(&in_b).copied().collect(),
+        ),
+    ];
+
+    for (op, points) in expected {
+        let rects = boolean_op(&bounds, &a, &b, op);
+        let region: RectRegion<_> = rects.iter().copied().collect();
+        assert_eq!(region.iter().copied().collect::<Vec<_>>(), rects, "{op:?}");
+        assert_disjoint(&region);
+        // b pokes out of the bounds, so the result is clipped
+        let clipped: Points = points.intersection(&in_bounds).copied().collect();
+        assert_eq!(rasterize(&rects), clipped, "{op:?}");
+    }
+}
+
+#[test]
+fn test_free_components_dividing_wall() {
+    let parent = BasicRectangle::new_from_sides(0, 9, 9, 0);
+    let wall = BasicRectangle::new_from_sides(3, 3, 9, 0);
+
+    let rooms = parent.free_components(&[&wall]);
+    assert_eq!(rooms.len(), 2);
+    assert_eq!(rooms[0].area(), 30);
+    assert_eq!(rooms[1].area(), 60);
+}
+
+#[test]
+fn test_free_components_wall_with_gap() {
+    let parent = BasicRectangle::new_from_sides(0, 9, 9, 0);
+    let walls = [
+        BasicRectangle::new_from_sides(3, 3, 9, 5),
+        BasicRectangle::new_from_sides(3, 3, 3, 0),
+    ];
+
+    let rooms = parent.free_components(&walls.iter().collect::<Vec<_>>());
+    assert_eq!(rooms.len(), 1);
+    assert_eq!(rooms[0].area(), 100 - 9);
+}
+
+#[test]
+fn test_free_components_nested_enclosure() {
+    let parent = BasicRectangle::new_from_sides(0, 9, 9, 0);
+    // a closed ring from 2 to 7, leaving 3..=6 inside
+    let ring = [
+        BasicRectangle::new_from_sides(2, 7, 7, 7),
+        BasicRectangle::new_from_sides(2, 7, 2, 2),
+        BasicRectangle::new_from_sides(2, 2, 6, 3),
+        BasicRectangle::new_from_sides(7, 7, 6, 3),
+    ];
+
+    let rooms = parent.free_components(&ring.iter().collect::<Vec<_>>());
+    assert_eq!(rooms.len(), 2);
+    let mut areas: Vec<i32> = rooms.iter().map(|room| room.area()).collect();
+    areas.sort_unstable();
+    assert_eq!(areas, vec![16, 100 - 36]);
+
+    // the inner room is inside the ring & the outer one wraps around it
+    let inner = rooms.iter().find(|room| room.area() == 16).unwrap();
+    assert_eq!(
+        inner.bounding_box(),
+        Some(BasicRectangle::new_from_sides(3, 6, 6, 3))
+    );
+    assert_disjoint(inner);
+}
+
+#[test]
+fn test_rect_region_components_diagonal() {
+    // touching only at a corner isn't connected
+    let region: RectRegion<_> = [
+        BasicRectangle::new_from_sides(0, 1, 1, 0),
+        BasicRectangle::new_from_sides(2, 3, 3, 2),
+    ]
+    .into_iter()
+    .collect();
+    assert_eq!(region.components().len(), 2);
+}
+
+#[test]
+fn test_rect_region_ops_into() {
+    let mut rng = Rng(0x5851_f42d_4c95_7f2d);
+    let mut out = RectRegion::new();
+    for _ in 0..50 {
+        let a: RectRegion<_> = rng.rects(4, 10).into_iter().collect();
+        let b: RectRegion<_> = rng.rects(4, 10).into_iter().collect();
+
+        a.union_into(&b, &mut out);
+        assert_eq!(out, a.union(&b));
+        a.intersect_into(&b, &mut out);
+        assert_eq!(out, a.intersect(&b));
+        a.subtract_into(&b, &mut out);
+        assert_eq!(out, a.subtract(&b));
+        a.xor_into(&b, &mut out);
+        assert_eq!(out, a.xor(&b));
+    }
+}
+
+#[test]
+fn test_greedy_cover_l_shape() {
+    // a plain L is always 2 strips, so the foot reaches a column past the stem to make it 3,
+    // greedy still covers it with the 2 overlapping bars
+    let l: RectRegion<_> = [
+        BasicRectangle::new_from_sides(1, 3, 9, 0),
+        BasicRectangle::new_from_sides(0, 9, 2, 0),
+    ]
+    .into_iter()
+    .collect();
+    assert_eq!(l.iter().count(), 3);
+    let cover = greedy_cover(&l, None);
+    assert_eq!(
+        cover,
+        vec![
+            BasicRectangle::new_from_sides(1, 3, 9, 0),
+            BasicRectangle::new_from_sides(0, 9, 2, 0),
+        ]
+    );
+
+    // a T is 3 strips, but still 2 overlapping bars
+    let t: RectRegion<_> = [
+        BasicRectangle::new_from_sides(0, 9, 9, 7),
+        BasicRectangle::new_from_sides(4, 5, 9, 0),
+    ]
+    .into_iter()
+    .collect();
+    assert_eq!(t.iter().count(), 3);
+    let cover = greedy_cover(&t, None);
+    assert_eq!(cover.len(), 2);
+    assert_eq!(cover.iter().copied().collect::<RectRegion<_>>(), t);
+}
+
+#[test]
+fn test_greedy_cover_matches_region() {
+    let mut rng = Rng(0x853c_49e6_748f_ea9b);
+    for _ in 0..50 {
+        let region: RectRegion<_> = rng.rects(5, 10).into_iter().collect();
+        let cover = greedy_cover(&region, None);
+        // exactly the region, in no more rectangles than its partition
+        assert_eq!(cover.iter().copied().collect::<RectRegion<_>>(), region);
+        assert!(cover.len() <= region.iter().count());
+    }
+    assert!(greedy_cover(&RectRegion::<BasicRectangle>::new(), None).is_empty());
+}
+
+#[test]
+fn test_greedy_cover_max_rects() {
+    let region: RectRegion<_> = [
+        BasicRectangle::new_from_sides(0, 2, 9, 0),
+        BasicRectangle::new_from_sides(0, 9, 2, 0),
+    ]
+    .into_iter()
+    .collect();
+
+    // a single rectangle falls back to the bounding box
+    let bounds = BasicRectangle::new_from_sides(0, 9, 9, 0);
+    assert_eq!(greedy_cover(&region, Some(1)), vec![bounds]);
+    assert_eq!(greedy_cover(&region, Some(0)), vec![bounds]);
+
+    // with enough room the last bounding box is just the rest of the region
+    let cover = greedy_cover(&region, Some(2));
+    assert_eq!(cover.len(), 2);
+    assert_eq!(cover.iter().copied().collect::<RectRegion<_>>(), region);
+}
+
+#[test]
+fn test_drop_slivers() {
+    let block = BasicRectangle::new_from_sides(0, 5, 5, 0);
+    let region: RectRegion<_> = [
+        block,
+        // a column one point wide & a row one point tall
+        BasicRectangle::new_from_sides(8, 8, 5, 0),
+        BasicRectangle::new_from_sides(0, 5, 8, 8),
+        // 2 by 2 survives
+        BasicRectangle::new_from_sides(10, 11, 1, 0),
+    ]
+    .into_iter()
+    .collect();
+
+    let (kept, dropped) = drop_slivers(region.clone(), 2, 2, 0);
+    assert_disjoint(&kept);
+    assert_eq!(
+        kept,
+        [block, BasicRectangle::new_from_sides(10, 11, 1, 0)]
+            .into_iter()
+            .collect()
+    );
+    assert_eq!(dropped, 12);
+    assert_eq!(kept.area() + dropped, region.area());
+
+    // an area threshold alone catches the slivers & the small square
+    let (kept, dropped) = drop_slivers(region, 0, 0, 7);
+    assert_eq!(kept, RectRegion::from(block));
+    assert_eq!(dropped, 16);
+}
+
+#[test]
+fn test_drop_slivers_everything() {
+    let region: RectRegion<_> = [
+        BasicRectangle::new_from_sides(0, 0, 9, 0),
+        BasicRectangle::new_from_sides(3, 9, 4, 4),
+    ]
+    .into_iter()
+    .collect();
+
+    let (kept, dropped) = drop_slivers(region.clone(), 2, 2, 0);
+    assert!(kept.is_empty());
+    assert_eq!(dropped, region.area());
+}
+
+#[test]
+fn test_iteration_order_is_stable() {
+    let mut rng = Rng(0x2545_f491_4f6c_dd1d);
+    for _ in 0..50 {
+        let rects = rng.rects(6, 10);
+
+        // every union splits & merges the strips again, in a different order each way round
+        let forwards = rects.iter().fold(RectRegion::new(), |region, rect| {
+            region.union(&RectRegion::from(*rect))
+        });
+        let backwards = rects.iter().rev().fold(RectRegion::new(), |region, rect| {
+            region.union(&RectRegion::from(*rect))
+        });
+
+        let order: Vec<BasicRectangle> = forwards.iter().copied().collect();
+        assert_eq!(backwards.iter().copied().collect::<Vec<_>>(), order);
+        assert_eq!(forwards.iter_sorted().copied().collect::<Vec<_>>(), order);
+        assert_eq!((&forwards).into_iter().copied().collect::<Vec<_>>(), order);
+        assert_eq!(forwards.len(), order.len());
+        assert_eq!(forwards.into_iter().collect::<Vec<_>>(), order);
+
+        // reading order, top to bottom then left to right
+        for pair in order.windows(2) {
+            assert!((-pair[0].top(), pair[0].left()) < (-pair[1].top(), pair[1].left()));
+        }
+    }
+}
@@ -0,0 +1,311 @@
+use std::collections::HashSet;
+
+use rect_lib::{
+    clip_all, clip_all_into, clip_all_sorted, clip_all_sorted_into, cluster_overlapping,
+    merge_overlapping, merge_plan, merge_rects, overlap_adjacency, remove_contained,
+    remove_contained_indices, retain_clipped, separate_rects, BasicRectangle, MergeStep, Rectangle,
+    RectangleRead,
+};
+
+mod common;
+use common::Rng;
+
+#[test]
+fn test_remove_contained_indices() {
+    let rects = [
+        BasicRectangle::new_from_sides(0, 4, 4, 0),
+        BasicRectangle::new_from_sides(1, 2, 2, 1),
+        BasicRectangle::new_from_sides(3, 6, 3, 1),
+        // identical to the first, only the first is kept
+        BasicRectangle::new_from_sides(0, 4, 4, 0),
+    ];
+    assert_eq!(remove_contained_indices(&rects), vec![0, 2]);
+    assert_eq!(remove_contained(&rects), vec![rects[0], rects[2]]);
+}
+
+#[test]
+fn test_cluster_overlapping() {
+    let rects = [
+        BasicRectangle::new_from_sides(0, 1, 1, 0),
+        BasicRectangle::new_from_sides(10, 11, 11, 10),
+        BasicRectangle::new_from_sides(4, 5, 5, 4),
+        // bridges the first & third
+        BasicRectangle::new_from_sides(1, 4, 4, 1),
+    ];
+    assert_eq!(cluster_overlapping(&rects), vec![vec![0, 2, 3], vec![1]]);
+    assert!(cluster_overlapping::<BasicRectangle>(&[]).is_empty());
+}
+
+/// Every point of a rectangle, for checking merges without trusting how they were found.
+fn points(rect: &BasicRectangle) -> HashSet<(i32, i32)> {
+    (rect.left()..=rect.right())
+        .flat_map(|x| (rect.bottom()..=rect.top()).map(move |y| (x, y)))
+        .collect()
+}
+
+/// Checks a merge plan by its points: each input is used once, each result covers exactly the points of its sources,
+/// & no two results could still merge, meaning their bounding box holds a point neither covers.
+fn assert_merge_result(rects: &[BasicRectangle], plan: &[MergeStep], merged: &[BasicRectangle]) {
+    let mut used: Vec<usize> = plan.iter().flat_map(|step| step.sources.clone()).collect();
+    used.sort_unstable();
+    assert_eq!(used, (0..rects.len()).collect::<Vec<_>>());
+
+    assert_eq!(plan.len(), merged.len());
+    for (step, rect) in plan.iter().zip(merged) {
+        let sources: HashSet<_> = step
+            .sources
+            .iter()
+            .flat_map(|&i| points(&rects[i]))
+            .collect();
+        assert_eq!(points(rect), sources);
+    }
+
+    for (i, a) in merged.iter().enumerate() {
+        for b in &merged[i + 1..] {
+            let union: HashSet<_> = points(a).union(&points(b)).copied().collect();
+            let hull = BasicRectangle::new_from_sides(
+                a.left().min(b.left()),
+                a.right().max(b.right()),
+                a.top().max(b.top()),
+                a.bottom().min(b.bottom()),
+            );
+            assert_ne!(points(&hull), union);
+        }
+    }
+}
+
+#[test]
+fn test_merge_plan_replays_owned_result() {
+    let rects = [
+        BasicRectangle::new_from_sides(0, 1, 1, 0),
+        BasicRectangle::new_from_sides(2, 3, 1, 0),
+        BasicRectangle::new_from_sides(9, 9, 9, 9),
+        BasicRectangle::new_from_sides(0, 3, 3, 2),
+        // overlapping & lined up
+        BasicRectangle::new_from_sides(9, 9, 10, 8),
+    ];
+    let plan = merge_plan(&rects);
+
+    // replay the plan on separate storage, like an ECS would
+    let replayed: Vec<BasicRectangle> = plan.iter().map(|step| step.apply(&rects)).collect();
+    assert_merge_result(&rects, &plan, &replayed);
+
+    assert_eq!(plan.len(), 2);
+    assert_eq!(plan[0].sources, vec![0, 1, 3]);
+    assert_eq!(plan[1].sources, vec![2, 4]);
+    assert_eq!(replayed[0], BasicRectangle::new_from_sides(0, 3, 3, 0));
+    assert_eq!(replayed[1], BasicRectangle::new_from_sides(9, 9, 10, 8));
+}
+
+#[test]
+fn test_merge_rects_random_points() {
+    let mut rng = Rng(0x853c_49e6_748f_ea9b);
+    for _ in 0..100 {
+        // a few rectangles on a coarse grid, so they line up often enough to merge
+        let rects: Vec<BasicRectangle> = (0..6)
+            .map(|_| {
+                let (left, bottom) = (rng.next(4) * 3, rng.next(4) * 3);
+                BasicRectangle::new_from_sides(left, left + 2 + rng.next(2) * 3, bottom + 2, bottom)
+            })
+            .collect();
+        let plan = merge_plan(&rects);
+        assert_merge_result(&rects, &plan, &merge_rects(&rects));
+    }
+}
+
+#[test]
+fn test_merge_rects_not_lined_up() {
+    let rects = [
+        BasicRectangle::new_from_sides(0, 1, 1, 0),
+        BasicRectangle::new_from_sides(2, 3, 2, 0),
+    ];
+    assert_eq!(merge_rects(&rects), rects.to_vec());
+}
+
+#[test]
+fn test_separate_rects_smaller_penetration_axis() {
+    // overlapping by 2 columns but 5 rows, so they split sideways
+    let mut rects = [
+        BasicRectangle::new_from_sides(0, 4, 4, 0),
+        BasicRectangle::new_from_sides(3, 7, 4, 0),
+    ];
+    assert!(separate_rects(&mut rects, 0, None, 10));
+    assert_eq!(rects[0], BasicRectangle::new_from_sides(-1, 3, 4, 0));
+    assert_eq!(rects[1], BasicRectangle::new_from_sides(4, 8, 4, 0));
+
+    // same thing with padding keeps a gap of 1 between them
+    let mut rects = [
+        BasicRectangle::new_from_sides(0, 4, 4, 0),
+        BasicRectangle::new_from_sides(0, 4, 7, 3),
+    ];
+    assert!(separate_rects(&mut rects, 1, None, 10));
+    assert_eq!(rects[1].bottom() - rects[0].top(), 2);
+    assert_eq!(rects[0].left(), rects[1].left());
+}
+
+#[test]
+fn test_separate_rects_dense_cluster_in_bounds() {
+    let bounds = BasicRectangle::new_from_sides(0, 9, 9, 0);
+    let start: Vec<_> = (0..4)
+        .map(|i| BasicRectangle::new_from_sides(i, i + 3, 4 + i, i + 1))
+        .collect();
+
+    let mut rects = start.clone();
+    assert!(separate_rects(&mut rects, 0, Some(&bounds), 50));
+    for (i, rect) in rects.iter().enumerate() {
+        assert!(bounds.contains_rectangle(rect));
+        assert!(rects[i + 1..].iter().all(|other| !rect.overlaps(other)));
+    }
+
+    // determinism
+    let mut again = start.clone();
+    separate_rects(&mut again, 0, Some(&bounds), 50);
+    assert_eq!(rects, again);
+
+    // far too tight to ever fit
+    let tight = BasicRectangle::new_from_sides(0, 4, 4, 0);
+    let mut rects = start.clone();
+    assert!(!separate_rects(&mut rects, 0, Some(&tight), 50));
+    assert!(rects.iter().all(|rect| tight.contains_rectangle(rect)));
+}
+
+#[test]
+fn test_separate_rects_disjoint_untouched() {
+    let start = [
+        BasicRectangle::new_from_sides(0, 1, 1, 0),
+        BasicRectangle::new_from_sides(3, 4, 1, 0),
+        BasicRectangle::new_from_sides(0, 1, 5, 3),
+    ];
+    let mut rects = start;
+    assert!(separate_rects(&mut rects, 1, None, 0));
+    assert!(separate_rects(&mut rects, 1, None, 10));
+    assert_eq!(rects, start);
+}
+
+#[test]
+fn test_clip_all_sorted_matches_naive() {
+    let clip = BasicRectangle::new_from_sides(5, 14, 14, 5);
+    let mut rng = Rng(0x2545_f491_4f6c_dd1d);
+
+    for _ in 0..50 {
+        let mut rects: Vec<_> = (0..200).map(|_| rng.rect(60).translate(-20, -20)).collect();
+        // some exactly on the clip boundary
+        rects.push(BasicRectangle::new_from_sides(14, 20, 14, 14));
+        rects.push(BasicRectangle::new_from_sides(0, 5, 5, 0));
+        rects.push(BasicRectangle::new_from_sides(15, 20, 14, 5));
+        rects.sort_by_key(|rect| rect.left());
+
+        let naive = clip_all(&rects, &clip);
+        assert_eq!(clip_all_sorted(&rects, &clip), naive);
+        assert!(naive.iter().all(|rect| clip.contains_rectangle(rect)));
+
+        let mut in_place = rects.clone();
+        retain_clipped(&mut in_place, &clip);
+        assert_eq!(in_place, naive);
+    }
+}
+
+#[test]
+fn test_clip_all_boundary() {
+    let clip = BasicRectangle::new_from_sides(0, 9, 9, 0);
+    let rects = [
+        BasicRectangle::new_from_sides(9, 12, 9, 9),
+        BasicRectangle::new_from_sides(10, 12, 9, 0),
+    ];
+    assert_eq!(
+        clip_all(&rects, &clip),
+        vec![BasicRectangle::new_from_sides(9, 9, 9, 9)]
+    );
+}
+
+#[test]
+fn test_clip_all_into_reuses_buffer() {
+    let mut rng = Rng(0x9e37_79b9_7f4a_7c15);
+    let clip = BasicRectangle::new_from_sides(0, 19, 19, 0);
+
+    let mut out = Vec::new();
+    let mut capacity = 0;
+    for _ in 0..20 {
+        let mut rects: Vec<BasicRectangle> =
+            (0..20).map(|_| rng.rect(60).translate(-20, -20)).collect();
+        clip_all_into(&rects, &clip, &mut out);
+        assert_eq!(out, clip_all(&rects, &clip));
+        assert!(out.capacity() >= capacity);
+        capacity = out.capacity();
+
+        rects.sort_by_key(|rect| rect.left());
+        clip_all_sorted_into(&rects, &clip, &mut out);
+        assert_eq!(out, clip_all_sorted(&rects, &clip));
+        assert!(out.capacity() >= capacity);
+        capacity = out.capacity();
+    }
+}
+
+#[test]
+fn test_merge_overlapping() {
+    // three overlapping rectangles whose union is a single rectangle
+    let rects = [
+        BasicRectangle::new_from_sides(0, 4, 4, 0),
+        BasicRectangle::new_from_sides(3, 8, 4, 0),
+        BasicRectangle::new_from_sides(1, 6, 4, 2),
+    ];
+    assert_eq!(
+        merge_overlapping(&rects),
+        vec![BasicRectangle::new_from_sides(0, 8, 4, 0)]
+    );
+
+    // an L made of three overlapping pieces becomes two disjoint ones
+    let rects = [
+        BasicRectangle::new_from_sides(0, 2, 9, 0),
+        BasicRectangle::new_from_sides(0, 9, 2, 0),
+        BasicRectangle::new_from_sides(1, 5, 2, 1),
+    ];
+    let merged = merge_overlapping(&rects);
+    assert_eq!(
+        merged,
+        vec![
+            BasicRectangle::new_from_sides(0, 2, 9, 0),
+            BasicRectangle::new_from_sides(3, 9, 2, 0),
+        ]
+    );
+}
+
+#[test]
+fn test_merge_overlapping_random() {
+    let mut rng = Rng(0x2545_f491_4f6c_dd1d);
+    for _ in 0..50 {
+        let rects: Vec<BasicRectangle> = (0..6).map(|_| rng.rect(60).translate(-20, -20)).collect();
+        let merged = merge_overlapping(&rects);
+        for (i, rect) in merged.iter().enumerate() {
+            assert!(!rect.overlaps_any(&merged[i + 1..]));
+        }
+        // every point is covered by the result exactly when it is by the input
+        for x in -20..40 {
+            for y in -20..40 {
+                assert_eq!(
+                    merged.iter().any(|rect| rect.contains_point(x, y)),
+                    rects.iter().any(|rect| rect.contains_point(x, y))
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn test_overlap_adjacency_weights() {
+    let rects = [
+        BasicRectangle::new_from_sides(0, 9, 9, 0),
+        // a 3 by 3 corner of the first
+        BasicRectangle::new_from_sides(7, 12, 12, 7),
+        // a column sharing 10 points with the first & 6 with the second
+        BasicRectangle::new_from_sides(8, 8, 20, 0),
+        // only sharing a single point with the first
+        BasicRectangle::new_from_sides(-5, 0, 0, -5),
+    ];
+
+    assert_eq!(
+        overlap_adjacency(&rects),
+        vec![(0, 1, 9), (0, 2, 10), (0, 3, 1), (1, 2, 6)]
+    );
+    assert!(overlap_adjacency(&rects[3..]).is_empty());
+}
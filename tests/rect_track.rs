@@ -0,0 +1,90 @@
+use rect_lib::{BasicRectangle, RectTrack, Rectangle};
+
+/// A track moving a 2x2 rectangle along the x axis, sampled every `step` frames until `last`.
+fn sliding(step: u32, last: u32, speed: i32) -> RectTrack<BasicRectangle> {
+    let mut track = RectTrack::new();
+    for frame in (0..=last).step_by(step as usize) {
+        let x = frame as i32 * speed;
+        track.push(frame, BasicRectangle::new_from_sides(x, x + 1, 1, 0));
+    }
+    track
+}
+
+#[test]
+fn test_rect_track_interpolation() {
+    let track = sliding(5, 20, 1);
+    for frame in 0..=20 {
+        let x = frame as i32;
+        assert_eq!(
+            track.at(frame),
+            Some(BasicRectangle::new_from_sides(x, x + 1, 1, 0))
+        );
+    }
+    assert_eq!(track.at(21), None);
+
+    // samples pushed out of order end up sorted
+    let mut track = RectTrack::new();
+    track.push(10, BasicRectangle::new_from_sides(10, 11, 1, 0));
+    track.push(0, BasicRectangle::new_from_sides(0, 1, 1, 0));
+    assert_eq!(track.frames(), Some((0, 10)));
+    assert_eq!(
+        track.at(3),
+        Some(BasicRectangle::new_from_sides(3, 4, 1, 0))
+    );
+}
+
+#[test]
+fn test_rect_track_hull_and_union() {
+    let mut track = RectTrack::new();
+    track.push(0, BasicRectangle::new_from_sides(0, 1, 1, 0));
+    track.push(4, BasicRectangle::new_from_sides(0, 1, 9, 8));
+    track.push(8, BasicRectangle::new_from_sides(8, 9, 9, 8));
+
+    // the hull includes the corner sample in the middle of the range
+    assert_eq!(
+        track.hull_between(2, 6),
+        Some(BasicRectangle::new_from_sides(0, 5, 9, 4))
+    );
+    assert_eq!(track.hull_between(9, 12), None);
+
+    // the union is only the points actually covered, which is far less than the hull
+    let union = track.union_between(0, 8);
+    assert!(union.contains_point(0, 0) && union.contains_point(9, 9));
+    assert!(!union.contains_point(8, 0));
+    assert_eq!(union.bounding_box(), track.hull_between(0, 8));
+}
+
+#[test]
+fn test_rect_track_overlap_frames() {
+    // moves right one unit a frame, sampled every frame
+    let fast = sliding(1, 30, 1);
+
+    // sits still at x = 10 then jumps to x = 20, sampled far less often
+    let mut other = RectTrack::new();
+    other.push(0, BasicRectangle::new_from_sides(10, 11, 1, 0));
+    other.push(12, BasicRectangle::new_from_sides(10, 11, 1, 0));
+    other.push(13, BasicRectangle::new_from_sides(20, 21, 1, 0));
+    other.push(40, BasicRectangle::new_from_sides(20, 21, 1, 0));
+
+    // overlaps while passing x = 10, then again while passing x = 20
+    assert_eq!(fast.overlap_frames(&other, 0, 40), vec![9..=11, 19..=21]);
+    assert_eq!(other.overlap_frames(&fast, 40, 0), vec![9..=11, 19..=21]);
+
+    // the range cuts the runs off at its ends
+    assert_eq!(fast.overlap_frames(&other, 10, 20), vec![10..=11, 19..=20]);
+    assert_eq!(fast.overlap_frames(&other, 12, 18), vec![]);
+
+    // tracks that never share a frame never overlap
+    let mut late = RectTrack::new();
+    late.push(31, BasicRectangle::new_from_sides(0, 30, 1, 0));
+    assert!(fast.overlap_frames(&late, 0, 40).is_empty());
+
+    // a run can end on the very last frame
+    let mut end = RectTrack::new();
+    end.push(u32::MAX - 1, BasicRectangle::new_from_sides(0, 1, 1, 0));
+    end.push(u32::MAX, BasicRectangle::new_from_sides(0, 1, 1, 0));
+    assert_eq!(
+        end.overlap_frames(&end, 0, u32::MAX),
+        vec![u32::MAX - 1..=u32::MAX]
+    );
+}
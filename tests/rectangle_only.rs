@@ -0,0 +1,34 @@
+// only the combined trait is imported, like code written before `RectangleRead` was split out
+use rect_lib::{BasicRectangle, Rectangle};
+
+#[test]
+fn test_basic_rectangle_methods_with_only_rectangle() {
+    let rect = BasicRectangle::new_from_sides(0, 3, 2, 0);
+    let other = BasicRectangle::new_from_sides(2, 5, 4, 1);
+
+    assert_eq!(
+        (rect.left(), rect.right(), rect.top(), rect.bottom()),
+        (0, 3, 2, 0)
+    );
+    assert_eq!((rect.width(), rect.height()), (3, 2));
+    assert_eq!((rect.perimeter(), rect.area()), (10, 6));
+    assert!(rect.contains_point(1, 1));
+    assert!(!rect.contains_rectangle(&other));
+    assert!(rect.overlaps(&other));
+    assert_eq!(
+        rect.intersection(&other),
+        Some(BasicRectangle::new_from_sides(2, 3, 2, 1))
+    );
+    assert_eq!(rect.translate(1, 1).left(), 1);
+    assert_eq!(rect.unobstructed_subrectangles(&[&other]).len(), 2);
+}
+
+/// Generic code sees the accessors through the supertrait bound.
+fn right_of<R: Rectangle>(rect: &R) -> R::Unit {
+    rect.right()
+}
+
+#[test]
+fn test_generic_rectangle_bound_sees_accessors() {
+    assert_eq!(right_of(&BasicRectangle::new_from_sides(0, 3, 2, 0)), 3);
+}
@@ -0,0 +1,107 @@
+use rect_lib::{BasicRectangle, Rectangle, RectangleRead};
+
+/// A rectangle we can only read, like one handed over through FFI.
+/// It isn't `Copy` & can't be constructed, so it only implements `RectangleRead`.
+struct ReadOnlyRect {
+    sides: Vec<i64>,
+}
+
+impl RectangleRead for ReadOnlyRect {
+    type Unit = i64;
+
+    fn left(&self) -> i64 {
+        self.sides[0]
+    }
+
+    fn right(&self) -> i64 {
+        self.sides[1]
+    }
+
+    fn top(&self) -> i64 {
+        self.sides[2]
+    }
+
+    fn bottom(&self) -> i64 {
+        self.sides[3]
+    }
+}
+
+/// A constructible rectangle with the same unit, to sweep with.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Rect64 {
+    left: i64,
+    right: i64,
+    top: i64,
+    bottom: i64,
+}
+
+impl RectangleRead for Rect64 {
+    type Unit = i64;
+
+    fn left(&self) -> i64 {
+        self.left
+    }
+
+    fn right(&self) -> i64 {
+        self.right
+    }
+
+    fn top(&self) -> i64 {
+        self.top
+    }
+
+    fn bottom(&self) -> i64 {
+        self.bottom
+    }
+}
+
+impl Rectangle for Rect64 {
+    fn new_from_sides(left: i64, right: i64, top: i64, bottom: i64) -> Self {
+        Self {
+            left,
+            right,
+            top,
+            bottom,
+        }
+    }
+}
+
+#[test]
+fn test_rectangle_read_methods() {
+    let read_only = ReadOnlyRect {
+        sides: vec![0, 3, 2, 0],
+    };
+    assert_eq!(read_only.width(), 3);
+    assert_eq!(read_only.area(), 6);
+    assert!(read_only.contains_point(3, 2));
+    assert!(read_only.overlaps(&Rect64::new_from_sides(3, 5, 5, 2)));
+}
+
+#[test]
+fn test_rectangle_read_as_obstruction() {
+    let parent = Rect64::new_from_sides(0, 5, 5, 0);
+    let obstruction = ReadOnlyRect {
+        sides: vec![0, 2, 5, 1],
+    };
+
+    let subrects = parent.unobstructed_subrectangles(&[&obstruction]);
+    assert_eq!(subrects.len(), 2);
+    assert!(subrects.contains(&Rect64::new_from_sides(0, 5, 0, 0)));
+    assert!(subrects.contains(&Rect64::new_from_sides(3, 5, 5, 0)));
+
+    // a constructible rectangle still works as an obstruction through the supertrait
+    let same = parent.unobstructed_subrectangles(&[&Rect64::new_from_sides(0, 2, 5, 1)]);
+    assert_eq!(subrects, same);
+
+    // & generic code bounded by `Rectangle` can still call the read-only methods
+    fn widest<R: Rectangle>(rects: &[R]) -> Option<R>
+    where
+        R::Unit: Ord,
+    {
+        rects.iter().copied().max_by_key(|rect| rect.width())
+    }
+    assert_eq!(
+        widest(&[BasicRectangle::new_from_sides(0, 1, 1, 0)]),
+        Some(BasicRectangle::new_from_sides(0, 1, 1, 0))
+    );
+}
@@ -0,0 +1,107 @@
+use rect_lib::{BasicRectangle, RectRegion, Rectangle, RegionDecodeError};
+
+mod common;
+use common::Rng;
+
+fn encode(region: &RectRegion<BasicRectangle>) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    region.write_to(&mut bytes).unwrap();
+    bytes
+}
+
+#[test]
+fn test_region_io_round_trip() {
+    let mut rng = Rng(0x2545_f491_4f6c_dd1d);
+
+    for _ in 0..100 {
+        let count = rng.next(8) as usize;
+        let region: RectRegion<_> = (0..count)
+            .map(|_| rng.rect(200).translate(-100, -100))
+            .collect();
+
+        let bytes = encode(&region);
+        assert_eq!(RectRegion::read_from(bytes.as_slice()).unwrap(), region);
+        assert_eq!(
+            RectRegion::read_from_checked(bytes.as_slice(), true).unwrap(),
+            region
+        );
+    }
+
+    let empty = RectRegion::<BasicRectangle>::new();
+    assert_eq!(
+        RectRegion::read_from(encode(&empty).as_slice()).unwrap(),
+        empty
+    );
+}
+
+#[test]
+fn test_region_io_corrupted_header() {
+    let region = RectRegion::from(BasicRectangle::new_from_sides(0, 3, 3, 0));
+    let mut bytes = encode(&region);
+
+    bytes[0] = b'X';
+    let result = RectRegion::<BasicRectangle>::read_from(bytes.as_slice());
+    assert!(matches!(result, Err(RegionDecodeError::BadMagic)));
+
+    bytes[0] = b'R';
+    bytes[4] = 200;
+    let result = RectRegion::<BasicRectangle>::read_from(bytes.as_slice());
+    assert!(matches!(
+        result,
+        Err(RegionDecodeError::UnsupportedVersion(200))
+    ));
+
+    // cut off part way through a rectangle
+    let bytes = encode(&region);
+    let result = RectRegion::<BasicRectangle>::read_from(&bytes[..bytes.len() - 1]);
+    assert!(matches!(result, Err(RegionDecodeError::Io(_))));
+}
+
+#[test]
+fn test_region_io_reads_version_1() {
+    // written by version 1 of the format, this must keep decoding as the format evolves
+    let fixture: &[u8] = &[
+        b'R', b'R', b'G', b'N', // magic
+        1,    // version
+        2,    // rectangles
+        6, 0, 3, 3, // top +3, left +0, width 3, height 3
+        3, 10, 1, 1, // top -2, left +5, width 1, height 1
+    ];
+
+    let region = RectRegion::<BasicRectangle>::read_from(fixture).unwrap();
+    assert_eq!(
+        region.iter().copied().collect::<Vec<_>>(),
+        vec![
+            BasicRectangle::new_from_sides(0, 3, 3, 0),
+            BasicRectangle::new_from_sides(5, 6, 1, 0),
+        ]
+    );
+    assert_eq!(encode(&region), fixture);
+}
+
+#[test]
+fn test_region_io_validation() {
+    // the second rectangle sits inside the first
+    let overlapping: &[u8] = &[b'R', b'R', b'G', b'N', 1, 2, 6, 0, 3, 3, 1, 2, 1, 1];
+    assert!(RectRegion::<BasicRectangle>::read_from(overlapping).is_ok());
+    let result = RectRegion::<BasicRectangle>::read_from_checked(overlapping, true);
+    assert!(matches!(result, Err(RegionDecodeError::Overlapping)));
+
+    // the second rectangle is above the first
+    let unordered: &[u8] = &[b'R', b'R', b'G', b'N', 1, 2, 6, 0, 1, 1, 4, 0, 1, 1];
+    let result = RectRegion::<BasicRectangle>::read_from(unordered);
+    assert!(matches!(result, Err(RegionDecodeError::Unordered)));
+
+    // two touching rectangles of the same height, which the region keeps as one
+    let unmerged: &[u8] = &[b'R', b'R', b'G', b'N', 1, 2, 2, 0, 1, 1, 0, 4, 1, 1];
+    assert!(RectRegion::<BasicRectangle>::read_from(unmerged).is_ok());
+    let result = RectRegion::<BasicRectangle>::read_from_checked(unmerged, true);
+    assert!(matches!(result, Err(RegionDecodeError::NotCanonical)));
+
+    // a side past i32::MAX
+    let overflowing: &[u8] = &[
+        b'R', b'R', b'G', b'N', 1, 1, 0, 0, 0xff, 0xff, 0xff, 0xff, 0x0f, 0,
+    ];
+    let result = RectRegion::<BasicRectangle>::read_from(overflowing);
+    assert!(matches!(result, Err(RegionDecodeError::Overflow)));
+}
@@ -0,0 +1,44 @@
+use rect_lib::{render_ascii, BasicRectangle, Rectangle, MAX_ASCII_POINTS};
+
+#[test]
+fn test_render_ascii_two_obstructions() {
+    let parent = BasicRectangle::new_from_sides(0, 7, 4, 0);
+    let pillar = BasicRectangle::new_from_sides(1, 2, 3, 1);
+    let shelf = BasicRectangle::new_from_sides(2, 9, 1, 0);
+
+    let expected = "\
+........
+.##.....
+.##.....
+.#######
+..######
+";
+    assert_eq!(
+        render_ascii(&parent, &[(&pillar, '#'), (&shelf, '#')]).unwrap(),
+        expected
+    );
+
+    // later rectangles are drawn on top
+    let expected = "\
+........
+.AA.....
+.AA.....
+.ABBBBBB
+..BBBBBB
+";
+    assert_eq!(
+        render_ascii(&parent, &[(&pillar, 'A'), (&shelf, 'B')]).unwrap(),
+        expected
+    );
+}
+
+#[test]
+fn test_render_ascii_too_large() {
+    let huge = BasicRectangle::new_from_sides(0, 1000, 1000, 0);
+    let none: [(&BasicRectangle, char); 0] = [];
+    assert_eq!(render_ascii(&huge, &none), None);
+
+    let side = (MAX_ASCII_POINTS as f64).sqrt() as i32;
+    let largest = BasicRectangle::new_from_sides(0, side - 1, side - 1, 0);
+    assert!(render_ascii(&largest, &none).is_some());
+}
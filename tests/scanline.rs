@@ -0,0 +1,135 @@
+use rect_lib::{
+    occupancy_grid, projection_profile_x, projection_profile_y, scanline_spans, vertical_gaps_at,
+    x_event_lines, BasicRectangle, Rectangle,
+};
+
+#[test]
+fn test_x_event_lines_no_rects() {
+    let bounds = BasicRectangle::new_from_sides(3, 9, 9, 0);
+    assert_eq!(x_event_lines(&bounds, &[] as &[BasicRectangle]), vec![3]);
+}
+
+#[test]
+fn test_x_event_lines_known_layout() {
+    // each rectangle adds a line at its left & one past its right, if they fall within the bounds
+    let bounds = BasicRectangle::new_from_sides(0, 9, 9, 0);
+    let rects = [
+        // flush with the left edge, so 0 is shared with the bounds, & 3 is one past its right
+        BasicRectangle::new_from_sides(0, 2, 9, 6),
+        // starts just after the first ends, 3 is shared with the line past the first, then 6
+        BasicRectangle::new_from_sides(3, 5, 4, 2),
+        // the same left edge as the one before, only adds 5
+        BasicRectangle::new_from_sides(3, 4, 9, 8),
+        // extends past the right edge, only adds 8
+        BasicRectangle::new_from_sides(8, 15, 1, 0),
+        // entirely left of the bounds, adds nothing
+        BasicRectangle::new_from_sides(-5, -2, 9, 0),
+    ];
+    assert_eq!(x_event_lines(&bounds, &rects), vec![0, 3, 5, 6, 8]);
+}
+
+#[test]
+fn test_vertical_gaps_at_inside_obstructions() {
+    let bounds = BasicRectangle::new_from_sides(0, 9, 9, 0);
+    let rects = [
+        BasicRectangle::new_from_sides(0, 4, 9, 7),
+        BasicRectangle::new_from_sides(2, 6, 5, 4),
+        // overlaps the one above, shouldn't create a fake gap
+        BasicRectangle::new_from_sides(3, 3, 5, 5),
+        // hangs off the bottom of the bounds
+        BasicRectangle::new_from_sides(3, 8, 1, -3),
+    ];
+
+    assert_eq!(vertical_gaps_at(&bounds, &rects, 3), vec![(6, 6), (3, 2)]);
+    assert_eq!(vertical_gaps_at(&bounds, &rects, 1), vec![(6, 0)]);
+    assert_eq!(vertical_gaps_at(&bounds, &rects, 8), vec![(9, 2)]);
+}
+
+#[test]
+fn test_vertical_gaps_at_outside_obstructions() {
+    let bounds = BasicRectangle::new_from_sides(0, 9, 9, 0);
+    let rects = [BasicRectangle::new_from_sides(0, 4, 9, 0)];
+
+    // fully covered
+    assert_eq!(vertical_gaps_at(&bounds, &rects, 2), Vec::new());
+    // no obstructions on this line
+    assert_eq!(vertical_gaps_at(&bounds, &rects, 5), vec![(9, 0)]);
+    // outside of the bounds
+    assert_eq!(vertical_gaps_at(&bounds, &rects, 10), Vec::new());
+}
+
+#[test]
+fn test_scanline_spans_merge_overlaps() {
+    let bounds = BasicRectangle::new_from_sides(0, 9, 3, 0);
+    let rects = [
+        BasicRectangle::new_from_sides(1, 4, 3, 1),
+        BasicRectangle::new_from_sides(3, 7, 2, 0),
+        // touches the second rectangle, so it merges too
+        BasicRectangle::new_from_sides(8, 12, 1, 1),
+    ];
+
+    let rows: Vec<_> = scanline_spans(&bounds, &rects).collect();
+    assert_eq!(
+        rows,
+        vec![
+            (3, vec![(1, 4)]),
+            (2, vec![(1, 7)]),
+            (1, vec![(1, 9)]),
+            (0, vec![(3, 7)]),
+        ]
+    );
+}
+
+#[test]
+fn test_occupancy_grid_one_obstacle() {
+    let bounds = BasicRectangle::new_from_sides(-1, 3, 2, 0);
+    // pokes out of the right of the bounds
+    let obstacle = BasicRectangle::new_from_sides(1, 5, 2, 1);
+
+    let grid = occupancy_grid(&bounds, &[obstacle]);
+    assert_eq!(
+        grid,
+        vec![
+            vec![false, false, true, true, true],
+            vec![false, false, true, true, true],
+            vec![false, false, false, false, false],
+        ]
+    );
+
+    // every cell agrees with the obstacle at its point
+    for (row, cells) in grid.iter().enumerate() {
+        for (column, &covered) in cells.iter().enumerate() {
+            let (x, y) = (bounds.left() + column as i32, bounds.top() - row as i32);
+            assert_eq!(covered, obstacle.contains_point(x, y));
+        }
+    }
+}
+
+#[test]
+fn test_projection_profile_gap() {
+    let rects = [
+        BasicRectangle::new_from_sides(0, 2, 5, 0),
+        BasicRectangle::new_from_sides(6, 9, 3, 1),
+    ];
+    assert_eq!(
+        projection_profile_x(&rects),
+        vec![(0, 2, 1), (3, 5, 0), (6, 9, 1)]
+    );
+    assert_eq!(
+        projection_profile_y(&rects),
+        vec![(0, 0, 1), (1, 3, 2), (4, 5, 1)]
+    );
+}
+
+#[test]
+fn test_projection_profile_nested() {
+    let rects = [
+        BasicRectangle::new_from_sides(0, 9, 9, 0),
+        BasicRectangle::new_from_sides(3, 5, 5, 3),
+    ];
+    assert_eq!(
+        projection_profile_x(&rects),
+        vec![(0, 2, 1), (3, 5, 2), (6, 9, 1)]
+    );
+    assert!(projection_profile_x::<BasicRectangle>(&[]).is_empty());
+}
@@ -0,0 +1,57 @@
+use rect_lib::{scroll_region, BasicRectangle, RectRegion, Rectangle};
+
+#[test]
+fn test_scroll_region_moves_dirty_strip() {
+    let view = BasicRectangle::new_from_sides(0, 9, 9, 0);
+    let strip = BasicRectangle::new_from_sides(0, 9, 5, 5);
+    let outside = BasicRectangle::new_from_sides(20, 25, 5, 0);
+    let mut dirty: RectRegion<_> = [strip, outside].into_iter().collect();
+
+    // scroll down by 3 rows
+    let plan = scroll_region(&view, (0, -3), &mut dirty);
+    assert_eq!(
+        plan.blits,
+        vec![(
+            BasicRectangle::new_from_sides(0, 9, 9, 3),
+            BasicRectangle::new_from_sides(0, 9, 6, 0)
+        )]
+    );
+    let exposed = RectRegion::from(BasicRectangle::new_from_sides(0, 9, 9, 7));
+    assert_eq!(plan.exposed, exposed);
+
+    // the strip's stale content moved with it, the old spot was overwritten by clean content
+    let expected: RectRegion<_> = [
+        BasicRectangle::new_from_sides(0, 9, 2, 2),
+        BasicRectangle::new_from_sides(0, 9, 9, 7),
+        outside,
+    ]
+    .into_iter()
+    .collect();
+    assert_eq!(dirty, expected);
+    assert!(!dirty.contains_point(4, 5));
+}
+
+#[test]
+fn test_scroll_region_dirty_scrolled_out() {
+    let view = BasicRectangle::new_from_sides(0, 9, 9, 0);
+    let mut dirty = RectRegion::from(BasicRectangle::new_from_sides(0, 1, 9, 0));
+
+    // the dirty column scrolls off the left edge
+    let plan = scroll_region(&view, (-4, 0), &mut dirty);
+    assert_eq!(plan.blits.len(), 1);
+    assert_eq!(
+        dirty,
+        RectRegion::from(BasicRectangle::new_from_sides(6, 9, 9, 0))
+    );
+}
+
+#[test]
+fn test_scroll_region_past_whole_region() {
+    let view = BasicRectangle::new_from_sides(0, 9, 9, 0);
+    let mut dirty = RectRegion::new();
+
+    let plan = scroll_region(&view, (0, 10), &mut dirty);
+    assert!(plan.blits.is_empty());
+    assert_eq!(plan.exposed, RectRegion::from(view));
+    assert_eq!(dirty, RectRegion::from(view));
+}
@@ -0,0 +1,51 @@
+use rect_lib::{BasicRectangle, Rectangle, RectangleRead, Segment};
+
+#[test]
+fn test_clip_segment() {
+    let rect = BasicRectangle::new_from_sides(0, 10, 10, 0);
+
+    // inside is untouched
+    let inside = Segment::new((1, 1), (9, 9));
+    assert_eq!(rect.clip_segment(&inside), Some(inside));
+
+    // crossing diagonally through two edges
+    let crossing = Segment::new((-5, 5), (5, 15));
+    assert_eq!(
+        rect.clip_segment(&crossing),
+        Some(Segment::new((0, 10), (0, 10)))
+    );
+    let through = Segment::new((-10, -10), (20, 20));
+    assert_eq!(
+        rect.clip_segment(&through),
+        Some(Segment::new((0, 0), (10, 10)))
+    );
+
+    // parallel to an edge & outside of it
+    assert_eq!(rect.clip_segment(&Segment::new((-1, 0), (-1, 10))), None);
+    assert_eq!(rect.clip_segment(&Segment::new((20, 20), (30, 5))), None);
+}
+
+#[test]
+fn test_clip_polyline_zigzag() {
+    let rect = BasicRectangle::new_from_sides(0, 20, 10, 0);
+    // starts inside, zigs out through the top, comes back in, then out the bottom & back once more
+    let zigzag = [(0, 5), (4, 5), (8, 15), (12, 5), (16, -5), (20, 5)];
+
+    let runs = rect.clip_polyline(&zigzag);
+    assert_eq!(
+        runs,
+        vec![
+            vec![(0, 5), (4, 5), (6, 10)],
+            vec![(10, 10), (12, 5), (14, 0)],
+            vec![(18, 0), (20, 5)],
+        ]
+    );
+
+    for run in &runs {
+        assert!(run.iter().all(|&(x, y)| rect.contains_point(x, y)));
+    }
+
+    // a polyline entirely outside has no runs
+    assert!(rect.clip_polyline(&[(30, 30), (40, 40)]).is_empty());
+    assert_eq!(rect.clip_polyline(&[(1, 1)]), vec![vec![(1, 1)]]);
+}
@@ -0,0 +1,72 @@
+use rect_lib::{BasicRectangle, Extent, Rectangle};
+
+/// Checks the placements cover the parent exactly with the given sizes.
+fn assert_exact_cover(
+    parent: &BasicRectangle,
+    sizes: &[Extent<i32>],
+    tiling: &[(usize, BasicRectangle)],
+) {
+    assert_eq!(tiling.len(), sizes.len());
+    for (i, (index, rect)) in tiling.iter().enumerate() {
+        assert_eq!(*index, i);
+        let size = sizes[i];
+        assert!(
+            (rect.width(), rect.height()) == (size.width, size.height)
+                || (rect.width(), rect.height()) == (size.height, size.width)
+        );
+        assert!(parent.contains_rectangle(rect));
+        assert!(tiling[i + 1..]
+            .iter()
+            .all(|(_, other)| !rect.overlaps(other)));
+    }
+}
+
+#[test]
+fn test_tile_with_sizes_quarters() {
+    let parent = BasicRectangle::new_from_sides(0, 3, 3, 0);
+    let sizes = [Extent::new(1, 1); 4];
+
+    let tiling = parent.tile_with_sizes(&sizes, false, 1000).unwrap();
+    assert_exact_cover(&parent, &sizes, &tiling);
+    assert_eq!(tiling[0].1, BasicRectangle::new_from_sides(0, 1, 1, 0));
+}
+
+#[test]
+fn test_tile_with_sizes_impossible() {
+    let parent = BasicRectangle::new_from_sides(0, 3, 3, 0);
+
+    // the areas don't add up, rejected without searching
+    assert_eq!(
+        parent.tile_with_sizes(&[Extent::new(1, 1); 3], true, 0),
+        None
+    );
+
+    // the areas add up but a 1 wide strip always leaves a 3 wide gap for the 2x2s
+    let sizes = [
+        Extent::new(0, 3),
+        Extent::new(1, 1),
+        Extent::new(1, 1),
+        Extent::new(1, 1),
+    ];
+    assert_eq!(parent.tile_with_sizes(&sizes, true, 100_000), None);
+}
+
+#[test]
+fn test_tile_with_sizes_rotation() {
+    // two 2 tall columns can only fill a 4 wide, 2 tall alcove lying down
+    let parent = BasicRectangle::new_from_sides(0, 3, 1, 0);
+    let sizes = [Extent::new(0, 3), Extent::new(0, 3)];
+
+    assert_eq!(parent.tile_with_sizes(&sizes, false, 1000), None);
+    let tiling = parent.tile_with_sizes(&sizes, true, 1000).unwrap();
+    assert_exact_cover(&parent, &sizes, &tiling);
+}
+
+#[test]
+fn test_tile_with_sizes_budget() {
+    let parent = BasicRectangle::new_from_sides(0, 5, 5, 0);
+    let sizes = [Extent::new(0, 0); 36];
+
+    assert!(parent.tile_with_sizes(&sizes, false, 36).is_some());
+    assert_eq!(parent.tile_with_sizes(&sizes, false, 35), None);
+}
@@ -0,0 +1,79 @@
+#![cfg(feature = "trace")]
+
+use rect_lib::{BasicRectangle, RecordingObserver, Rectangle, SweepEvent};
+
+mod common;
+use common::Rng;
+
+#[test]
+fn test_doc_example_golden_log() {
+    let rect = BasicRectangle::new_from_sides(0, 5, 5, 0);
+    let obstruction = BasicRectangle::new_from_sides(0, 2, 5, 1);
+    let mut recording = RecordingObserver::new();
+    let found = rect.unobstructed_subrectangles_traced(&[&obstruction], &mut recording);
+
+    assert_eq!(
+        recording.events(),
+        &[
+            SweepEvent::Line { x: 0, opens: false },
+            SweepEvent::Gap { top: 0, bottom: 0 },
+            SweepEvent::Open {
+                left: 0,
+                top: 0,
+                bottom: 0
+            },
+            SweepEvent::Line { x: 3, opens: true },
+            SweepEvent::Gap { top: 5, bottom: 0 },
+            SweepEvent::Open {
+                left: 3,
+                top: 5,
+                bottom: 0
+            },
+            SweepEvent::Close {
+                left: 0,
+                right: 5,
+                top: 0,
+                bottom: 0
+            },
+            SweepEvent::Close {
+                left: 3,
+                right: 5,
+                top: 5,
+                bottom: 0
+            },
+        ]
+    );
+
+    // every closed rectangle is one of the results, in the same order
+    let closed: Vec<BasicRectangle> = recording
+        .events()
+        .iter()
+        .filter_map(|event| match *event {
+            SweepEvent::Close {
+                left,
+                right,
+                top,
+                bottom,
+            } => Some(BasicRectangle::new_from_sides(left, right, top, bottom)),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(closed, found);
+}
+
+#[test]
+fn test_traced_matches_untraced() {
+    let mut rng = Rng(0x853c_49e6_748f_ea9b);
+    let parent = BasicRectangle::new_from_sides(0, 19, 19, 0);
+    for _ in 0..100 {
+        let obstructions: Vec<BasicRectangle> = (0..6).map(|_| rng.rect(20)).collect();
+        let refs: Vec<&BasicRectangle> = obstructions.iter().collect();
+
+        let mut recording = RecordingObserver::new();
+        assert_eq!(
+            parent.unobstructed_subrectangles_traced(&refs, &mut recording),
+            parent.unobstructed_subrectangles(&refs)
+        );
+        assert!(!recording.events().is_empty());
+    }
+}
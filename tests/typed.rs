@@ -0,0 +1,51 @@
+use rect_lib::{BasicRectangle, Height, Rectangle, TypedRectangle, Width, XCoord, YCoord};
+
+#[test]
+fn test_typed_accessors() {
+    let rect = BasicRectangle::new_from_sides(-2, 4, 7, 1);
+    let typed = rect.typed();
+
+    assert_eq!(typed.left(), XCoord(-2));
+    assert_eq!(typed.right(), XCoord(4));
+    assert_eq!(typed.top(), YCoord(7));
+    assert_eq!(typed.bottom(), YCoord(1));
+    assert_eq!(typed.width(), Width(6));
+    assert_eq!(typed.height(), Height(6));
+    assert_eq!(typed.area().into_inner(), rect.area());
+
+    assert!(typed.contains_point(XCoord(0), YCoord(7)));
+    assert!(!typed.contains_point(XCoord(7), YCoord(0)));
+}
+
+#[test]
+fn test_typed_arithmetic() {
+    let x = XCoord(3);
+    let y = YCoord(10);
+
+    assert_eq!(x + Width(4), XCoord(7));
+    assert_eq!(x - Width(4), XCoord(-1));
+    assert_eq!(XCoord(7) - x, Width(4));
+    assert_eq!(y - Height(2), YCoord(8));
+    assert_eq!(YCoord(12) - y, Height(2));
+    assert_eq!((Width(3) * Height(4)).into_inner(), 12);
+    assert_eq!(Height(4) * Width(3), Width(3) * Height(4));
+}
+
+#[test]
+fn test_typed_escape_hatches() {
+    let rect = BasicRectangle::new_from_sides(0, 3, 3, 0);
+    let typed = rect.typed();
+
+    // the untyped methods are still there through deref
+    assert!(typed.overlaps(&rect));
+    assert_eq!(typed.into_inner(), &rect);
+    assert_eq!(*XCoord(5), 5);
+    assert_eq!(Width(2).into_inner(), 2);
+}
+
+#[test]
+fn test_typed_mixups_dont_compile() {
+    // the expected errors are next to each case in tests/ui
+    let cases = trybuild::TestCases::new();
+    cases.compile_fail("tests/ui/typed_*.rs");
+}
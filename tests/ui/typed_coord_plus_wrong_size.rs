@@ -0,0 +1,7 @@
+use rect_lib::{BasicRectangle, Rectangle, TypedRectangle};
+
+fn main() {
+    let rect = BasicRectangle::new_from_sides(0, 3, 5, 2);
+    let typed = rect.typed();
+    let _ = typed.left() + typed.height();
+}
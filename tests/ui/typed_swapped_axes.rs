@@ -0,0 +1,6 @@
+use rect_lib::{BasicRectangle, Rectangle, TypedRectangle, XCoord, YCoord};
+
+fn main() {
+    let rect = BasicRectangle::new_from_sides(0, 3, 5, 2);
+    rect.typed().contains_point(YCoord(4), XCoord(1));
+}
@@ -0,0 +1,6 @@
+use rect_lib::{BasicRectangle, Height, Rectangle, TypedRectangle};
+
+fn main() {
+    let rect = BasicRectangle::new_from_sides(0, 3, 5, 2);
+    let _: Height<i32> = rect.typed().width();
+}
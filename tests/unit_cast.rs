@@ -0,0 +1,123 @@
+use rect_lib::{intersection_cast, union_hull_cast, BasicRectangle, Rectangle, RectangleRead};
+
+/// World coordinates, wider than the `i32` tiles of `BasicRectangle`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct WorldRect {
+    sides: [i64; 4],
+}
+
+impl RectangleRead for WorldRect {
+    type Unit = i64;
+
+    fn left(&self) -> i64 {
+        self.sides[0]
+    }
+
+    fn right(&self) -> i64 {
+        self.sides[1]
+    }
+
+    fn top(&self) -> i64 {
+        self.sides[2]
+    }
+
+    fn bottom(&self) -> i64 {
+        self.sides[3]
+    }
+}
+
+impl Rectangle for WorldRect {
+    fn new_from_sides(left: i64, right: i64, top: i64, bottom: i64) -> Self {
+        Self {
+            sides: [left, right, top, bottom],
+        }
+    }
+}
+
+#[test]
+fn test_union_hull_cast_exact() {
+    let tile = BasicRectangle::new_from_sides(-5, 5, 5, -5);
+    let world = WorldRect::new_from_sides(0, 1_000_000_000, 10, -1_000_000_000);
+    assert_eq!(
+        union_hull_cast(&tile, &world),
+        Some(BasicRectangle::new_from_sides(
+            -5,
+            1_000_000_000,
+            10,
+            -1_000_000_000
+        ))
+    );
+
+    // widening the other way always fits
+    assert_eq!(
+        union_hull_cast(&world, &tile),
+        Some(WorldRect::new_from_sides(
+            -5,
+            1_000_000_000,
+            10,
+            -1_000_000_000
+        ))
+    );
+}
+
+#[test]
+fn test_union_hull_cast_overflow() {
+    let tile = BasicRectangle::new_from_sides(0, 1, 1, 0);
+    // one past the largest i32 would have wrapped with an `as` cast
+    let world = WorldRect::new_from_sides(0, i32::MAX as i64 + 1, 1, 0);
+    assert_eq!(union_hull_cast(&tile, &world), None);
+
+    let world = WorldRect::new_from_sides(0, 1, 1, i64::MIN);
+    assert_eq!(union_hull_cast(&tile, &world), None);
+}
+
+#[test]
+fn test_intersection_cast() {
+    let tile = BasicRectangle::new_from_sides(0, 9, 9, 0);
+
+    let world = WorldRect::new_from_sides(5, 20, 7, -3);
+    assert_eq!(
+        intersection_cast(&tile, &world),
+        Some(BasicRectangle::new_from_sides(5, 9, 7, 0))
+    );
+
+    // disjoint gives nothing
+    assert_eq!(
+        intersection_cast(&tile, &WorldRect::new_from_sides(10, 20, 7, 3)),
+        None
+    );
+}
+
+#[test]
+fn test_intersection_cast_overflow() {
+    let tile = BasicRectangle::new_from_sides(0, 9, 9, 0);
+
+    // sides too big for i32 get clipped to the tile instead of failing the cast
+    assert_eq!(
+        intersection_cast(&tile, &WorldRect::new_from_sides(5, i64::MAX, 7, 3)),
+        Some(BasicRectangle::new_from_sides(5, 9, 7, 3))
+    );
+    assert_eq!(
+        intersection_cast(
+            &tile,
+            &WorldRect::new_from_sides(i64::MIN, i64::MAX, i64::MAX, i64::MIN)
+        ),
+        Some(tile)
+    );
+
+    // but a side past the far edge of the tile still misses it
+    assert_eq!(
+        intersection_cast(
+            &tile,
+            &WorldRect::new_from_sides(i64::MAX - 1, i64::MAX, 7, 3)
+        ),
+        None
+    );
+    assert_eq!(
+        intersection_cast(
+            &tile,
+            &WorldRect::new_from_sides(0, 9, i64::MIN + 1, i64::MIN)
+        ),
+        None
+    );
+}
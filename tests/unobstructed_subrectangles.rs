@@ -1,4 +1,10 @@
-use rect_lib::{BasicRectangle, Rectangle};
+use rect_lib::{
+    normalize_obstructions, BasicRectangle, BudgetStrategy, Decomposition, RectRegion, Rectangle,
+    Side, SweepState, UnobstructedOptions,
+};
+
+mod common;
+use common::{Rng, UnsignedRect};
 
 #[test]
 fn test_unobstructed_subrectangles_no_obstructions() {
@@ -21,6 +27,7 @@ fn test_unobstructed_subrectangles_fully_obstructed() {
 }
 
 #[test]
+#[allow(clippy::useless_vec)]
 fn test_unobstructed_subrectangles_part_obstructed() {
     let rect = BasicRectangle::new_from_sides(0, 5, 5, 0);
     let obstruction = BasicRectangle::new_from_sides(0, 2, 5, 1);
@@ -31,3 +38,536 @@ fn test_unobstructed_subrectangles_part_obstructed() {
     // & one at the end
     assert!(subrects.contains(&BasicRectangle::new_from_sides(3, 5, 5, 0)));
 }
+
+/// Asserts every sub-rectangle is well formed, within the parent, & clear of every obstruction.
+fn assert_unobstructed(parent: &BasicRectangle, obstructions: &[&BasicRectangle]) {
+    let subrects = parent.unobstructed_subrectangles(obstructions);
+    assert!(!subrects.is_empty());
+    for subrect in subrects {
+        assert!(subrect.left() <= subrect.right() && subrect.bottom() <= subrect.top());
+        assert!(parent.contains_rectangle(&subrect));
+        for obstruction in obstructions {
+            assert!(
+                !subrect.overlaps(*obstruction),
+                "{subrect:?} overlaps {obstruction:?}"
+            );
+        }
+    }
+}
+
+#[test]
+fn test_unobstructed_subrectangles_flush_with_top() {
+    let rect = BasicRectangle::new_from_sides(0, 5, 5, 0);
+    assert_unobstructed(&rect, &[&BasicRectangle::new_from_sides(2, 3, 5, 4)]);
+}
+
+#[test]
+fn test_unobstructed_subrectangles_flush_with_bottom() {
+    let rect = BasicRectangle::new_from_sides(0, 5, 5, 0);
+    assert_unobstructed(&rect, &[&BasicRectangle::new_from_sides(2, 3, 1, 0)]);
+}
+
+#[test]
+fn test_unobstructed_subrectangles_flush_with_left() {
+    let rect = BasicRectangle::new_from_sides(0, 5, 5, 0);
+    assert_unobstructed(&rect, &[&BasicRectangle::new_from_sides(0, 1, 3, 2)]);
+}
+
+#[test]
+fn test_unobstructed_subrectangles_flush_with_right() {
+    let rect = BasicRectangle::new_from_sides(0, 5, 5, 0);
+    assert_unobstructed(&rect, &[&BasicRectangle::new_from_sides(4, 5, 3, 2)]);
+}
+
+#[test]
+fn test_unobstructed_subrectangles_full_height() {
+    let rect = BasicRectangle::new_from_sides(0, 5, 5, 0);
+    let obstruction = BasicRectangle::new_from_sides(2, 3, 5, 0);
+    assert_unobstructed(&rect, &[&obstruction]);
+
+    let subrects = rect.unobstructed_subrectangles(&[&obstruction]);
+    assert_eq!(subrects.len(), 2);
+    assert!(subrects.contains(&BasicRectangle::new_from_sides(0, 1, 5, 0)));
+    assert!(subrects.contains(&BasicRectangle::new_from_sides(4, 5, 5, 0)));
+}
+
+#[test]
+fn test_unobstructed_subrectangles_full_width() {
+    let rect = BasicRectangle::new_from_sides(0, 5, 5, 0);
+    let obstruction = BasicRectangle::new_from_sides(0, 5, 3, 2);
+    assert_unobstructed(&rect, &[&obstruction]);
+
+    let subrects = rect.unobstructed_subrectangles(&[&obstruction]);
+    assert_eq!(subrects.len(), 2);
+    assert!(subrects.contains(&BasicRectangle::new_from_sides(0, 5, 5, 4)));
+    assert!(subrects.contains(&BasicRectangle::new_from_sides(0, 5, 1, 0)));
+}
+
+#[test]
+fn test_unobstructed_subrectangles_shared_lines() {
+    // the second obstruction starts right where the first ends
+    let rect = BasicRectangle::new_from_sides(0, 5, 5, 0);
+    let first = BasicRectangle::new_from_sides(0, 1, 5, 3);
+    let second = BasicRectangle::new_from_sides(2, 3, 2, 0);
+    let subrects = rect.unobstructed_subrectangles(&[&first, &second]);
+
+    for subrect in &subrects {
+        assert!(!subrect.overlaps(&first) && !subrect.overlaps(&second));
+    }
+    // every free point is covered
+    for x in 0..=5 {
+        for y in 0..=5 {
+            if !first.contains_point(x, y) && !second.contains_point(x, y) {
+                assert!(subrects.iter().any(|subrect| subrect.contains_point(x, y)));
+            }
+        }
+    }
+}
+
+#[test]
+fn test_unobstructed_subrectangles_staggered() {
+    let rect = BasicRectangle::new_from_sides(0, 7, 7, 0);
+    let obstructions = [
+        BasicRectangle::new_from_sides(0, 4, 3, 2),
+        BasicRectangle::new_from_sides(3, 5, 5, 1),
+        BasicRectangle::new_from_sides(1, 7, 6, 4),
+    ];
+    assert_unobstructed(&rect, &obstructions.iter().collect::<Vec<_>>());
+}
+
+#[test]
+fn test_unobstructed_subrectangles_budgeted_generous() {
+    let rect = BasicRectangle::new_from_sides(0, 7, 7, 0);
+    let obstructions = [
+        &BasicRectangle::new_from_sides(0, 4, 3, 2),
+        &BasicRectangle::new_from_sides(3, 5, 5, 1),
+    ];
+    let exact = rect.unobstructed_subrectangles(&obstructions);
+
+    for strategy in [BudgetStrategy::LargestArea, BudgetStrategy::FirstFound] {
+        let (subrects, truncated) =
+            rect.unobstructed_subrectangles_budgeted(&obstructions, exact.len(), strategy);
+        assert!(!truncated);
+        assert_eq!(subrects.len(), exact.len());
+        assert!(subrects.iter().all(|subrect| exact.contains(subrect)));
+    }
+}
+
+#[test]
+fn test_unobstructed_subrectangles_budgeted_largest_area() {
+    let rect = BasicRectangle::new_from_sides(0, 7, 7, 0);
+    let obstructions = [
+        &BasicRectangle::new_from_sides(0, 4, 3, 2),
+        &BasicRectangle::new_from_sides(3, 5, 5, 1),
+    ];
+    let points = |r: &BasicRectangle| (r.width() + 1) * (r.height() + 1);
+
+    let mut exact = rect.unobstructed_subrectangles(&obstructions);
+    exact.sort_by_key(|subrect| core::cmp::Reverse(points(subrect)));
+
+    let (subrects, truncated) =
+        rect.unobstructed_subrectangles_budgeted(&obstructions, 3, BudgetStrategy::LargestArea);
+    assert!(truncated);
+    assert_eq!(subrects.len(), 3);
+    // largest first, & just as large as the best of the exact result
+    let sizes: Vec<i32> = subrects.iter().map(points).collect();
+    assert_eq!(sizes, exact[..3].iter().map(points).collect::<Vec<_>>());
+}
+
+#[test]
+fn test_unobstructed_subrectangles_budgeted_first_found() {
+    let rect = BasicRectangle::new_from_sides(0, 7, 7, 0);
+    let obstruction = BasicRectangle::new_from_sides(3, 4, 4, 3);
+    let exact = rect.unobstructed_subrectangles(&[&obstruction]);
+
+    let (subrects, truncated) =
+        rect.unobstructed_subrectangles_budgeted(&[&obstruction], 2, BudgetStrategy::FirstFound);
+    assert!(truncated);
+    assert_eq!(subrects.len(), 2);
+    assert!(subrects.iter().all(|subrect| exact.contains(subrect)));
+
+    let (subrects, truncated) =
+        rect.unobstructed_subrectangles_budgeted(&[&obstruction], 0, BudgetStrategy::FirstFound);
+    assert!(truncated && subrects.is_empty());
+}
+
+#[test]
+fn test_normalize_obstructions_messy_input() {
+    let parent = BasicRectangle::new_from_sides(0, 9, 9, 0);
+    let messy = [
+        BasicRectangle::new_from_sides(4, 5, 3, 2),
+        // reaches outside the parent on every side
+        BasicRectangle::new_from_sides(-5, 2, 20, 7),
+        // entirely outside
+        BasicRectangle::new_from_sides(12, 15, 4, 1),
+        BasicRectangle::new_from_sides(4, 5, 3, 2),
+        // clips to a duplicate of the second
+        BasicRectangle::new_from_sides(-3, 2, 12, 7),
+        BasicRectangle::new_from_sides(7, 12, 3, -1),
+    ];
+
+    let normalized = normalize_obstructions(&parent, messy);
+    assert_eq!(
+        normalized,
+        vec![
+            BasicRectangle::new_from_sides(0, 2, 9, 7),
+            BasicRectangle::new_from_sides(4, 5, 3, 2),
+            BasicRectangle::new_from_sides(7, 9, 3, 0),
+        ]
+    );
+    // normalizing is idempotent
+    assert_eq!(
+        normalize_obstructions(&parent, normalized.clone()),
+        normalized
+    );
+
+    // the sweep gives the same answer either way
+    let mut from_messy = parent.unobstructed_subrectangles(&messy.iter().collect::<Vec<_>>());
+    let mut from_clean = parent.unobstructed_subrectangles(&normalized.iter().collect::<Vec<_>>());
+    from_messy.sort_by_key(|rect| (rect.left(), rect.top(), rect.right(), rect.bottom()));
+    from_clean.sort_by_key(|rect| (rect.left(), rect.top(), rect.right(), rect.bottom()));
+    assert_eq!(from_messy, from_clean);
+    assert_unobstructed(&parent, &messy.iter().collect::<Vec<_>>());
+}
+
+#[test]
+fn test_sweep_state_chunked_matches_one_shot() {
+    let parent = BasicRectangle::new_from_sides(0, 29, 19, 0);
+    let mut rng = Rng(0x9e37_79b9_7f4a_7c15);
+
+    for _ in 0..30 {
+        let mut obstructions: Vec<BasicRectangle> = (0..8)
+            .map(|_| {
+                let (left, bottom) = (rng.next(30), rng.next(20));
+                BasicRectangle::new_from_sides(
+                    left,
+                    left + rng.next(8),
+                    bottom + rng.next(6),
+                    bottom,
+                )
+            })
+            .collect();
+        obstructions.sort_by_key(|rect| rect.left());
+
+        let mut expected =
+            parent.unobstructed_subrectangles(&obstructions.iter().collect::<Vec<_>>());
+
+        // three slices, each obstruction given in the slice its left side falls in
+        let mut state = SweepState::new(parent);
+        let mut found = Vec::new();
+        for boundary in [10, 20] {
+            let slice: Vec<BasicRectangle> = obstructions
+                .iter()
+                .filter(|rect| boundary - 10 <= rect.left() && rect.left() < boundary)
+                .copied()
+                .collect();
+            state.advance_to(boundary, &slice, |rect| found.push(rect));
+        }
+        let last: Vec<BasicRectangle> = obstructions
+            .iter()
+            .filter(|rect| rect.left() >= 20)
+            .copied()
+            .collect();
+        state.advance_to(30, &last, |rect| found.push(rect));
+        found.extend(state.finish());
+
+        let key = |rect: &BasicRectangle| (rect.left(), rect.right(), rect.top(), rect.bottom());
+        expected.sort_by_key(key);
+        found.sort_by_key(key);
+        assert_eq!(found, expected);
+    }
+}
+
+#[test]
+fn test_unobstructed_subrectangles_into_reuses_buffer() {
+    let parent = BasicRectangle::new_from_sides(0, 29, 19, 0);
+    let mut rng = Rng(0x2545_f491_4f6c_dd1d);
+
+    let mut out = Vec::new();
+    let mut capacity = 0;
+    for _ in 0..30 {
+        let obstructions: Vec<BasicRectangle> = (0..6)
+            .map(|_| {
+                let (left, bottom) = (rng.next(30), rng.next(20));
+                BasicRectangle::new_from_sides(
+                    left,
+                    left + rng.next(8),
+                    bottom + rng.next(6),
+                    bottom,
+                )
+            })
+            .collect();
+        let obstructions: Vec<&BasicRectangle> = obstructions.iter().collect();
+
+        parent.unobstructed_subrectangles_into(&obstructions, &mut out);
+        assert_eq!(out, parent.unobstructed_subrectangles(&obstructions));
+
+        // the buffer is cleared, never shrunk
+        assert!(out.capacity() >= capacity);
+        capacity = out.capacity();
+    }
+}
+
+#[test]
+fn test_unobstructed_subrectangles_near_parent_bottom() {
+    let parent = BasicRectangle::new_from_sides(0, 5, 5, 0);
+    let unsigned_parent = UnsignedRect::new_from_sides(0, 5, 5, 0);
+
+    for (bottom, strip) in [(0, None), (1, Some(0)), (2, Some(1))] {
+        let obstruction = BasicRectangle::new_from_sides(2, 3, 3, bottom);
+        let subrects = parent.unobstructed_subrectangles(&[&obstruction]);
+        assert_unobstructed(&parent, &[&obstruction]);
+
+        // a gap below the obstruction spans the whole width, one unit tall when it ends one above the parent's bottom
+        let below = BasicRectangle::new_from_sides(0, 5, strip.unwrap_or(0), 0);
+        assert_eq!(subrects.contains(&below), strip.is_some());
+
+        // unsigned units give the same rectangles without underflowing at zero
+        let unsigned_obstruction = UnsignedRect::new_from_sides(2, 3, 3, bottom as u32);
+        let unsigned: Vec<[i32; 4]> = unsigned_parent
+            .unobstructed_subrectangles(&[&unsigned_obstruction])
+            .iter()
+            .map(|rect| rect.sides.map(|side| side as i32))
+            .collect();
+        let signed: Vec<[i32; 4]> = subrects
+            .iter()
+            .map(|rect| [rect.left(), rect.right(), rect.top(), rect.bottom()])
+            .collect();
+        assert_eq!(unsigned, signed);
+    }
+}
+
+#[test]
+fn test_unobstructed_subrectangles_unsigned_at_zero() {
+    // obstructions on the left & bottom edges of a parent at the origin
+    let parent = UnsignedRect::new_from_sides(0, 5, 5, 0);
+    let left = UnsignedRect::new_from_sides(0, 1, 5, 3);
+    let bottom = UnsignedRect::new_from_sides(3, 5, 1, 0);
+    let subrects = parent.unobstructed_subrectangles(&[&left, &bottom]);
+    assert!(subrects.contains(&UnsignedRect::new_from_sides(2, 5, 5, 2)));
+    assert!(subrects.contains(&UnsignedRect::new_from_sides(0, 2, 2, 0)));
+}
+
+#[test]
+fn test_subtract_all_two_holes() {
+    let rect = BasicRectangle::new_from_sides(0, 9, 9, 0);
+    let holes = [
+        BasicRectangle::new_from_sides(2, 4, 7, 5),
+        BasicRectangle::new_from_sides(6, 12, 3, -2),
+    ];
+    let refs = [&holes[0], &holes[1]];
+    let pieces = rect.subtract_all(&refs);
+
+    // disjoint, inside the rectangle, & clear of both holes
+    for (i, a) in pieces.iter().enumerate() {
+        assert!(rect.contains_rectangle(a));
+        assert!(!a.overlaps(&holes[0]) && !a.overlaps(&holes[1]));
+        assert!(pieces[i + 1..].iter().all(|b| !a.overlaps(b)));
+    }
+
+    // the same points as the maximal rectangles, 100 - 9 - 16
+    let area: i32 = pieces
+        .iter()
+        .map(|piece| (piece.width() + 1) * (piece.height() + 1))
+        .sum();
+    assert_eq!(area, 75);
+    let maximal: RectRegion<_> = rect.unobstructed_subrectangles(&refs).into_iter().collect();
+    assert_eq!(pieces.iter().copied().collect::<RectRegion<_>>(), maximal);
+
+    // reusing a buffer that already holds something
+    let mut out = vec![rect; 3];
+    rect.subtract_all_into(&refs, &mut out);
+    assert_eq!(out, pieces);
+}
+
+#[test]
+fn test_subtract_all_many_holes() {
+    // 40000 holes, one on every other point, which only finishes quickly with a sweep
+    let rect = BasicRectangle::new_from_sides(0, 399, 399, 0);
+    let holes: Vec<BasicRectangle> = (0..200)
+        .flat_map(|x| {
+            (0..200).map(move |y| BasicRectangle::new_from_sides(x * 2, x * 2, y * 2, y * 2))
+        })
+        .collect();
+    let refs: Vec<&BasicRectangle> = holes.iter().collect();
+    let pieces = rect.subtract_all(&refs);
+    let area: i32 = pieces
+        .iter()
+        .map(|piece| (piece.width() + 1) * (piece.height() + 1))
+        .sum();
+    assert_eq!(area, 400 * 400 - 200 * 200);
+}
+
+/// A rectangle with a thin strip free left of its obstruction, a short band above, & tall ones right & below.
+fn options_scene() -> (BasicRectangle, BasicRectangle) {
+    (
+        BasicRectangle::new_from_sides(0, 9, 9, 0),
+        BasicRectangle::new_from_sides(2, 3, 6, 5),
+    )
+}
+
+fn sorted(mut rects: Vec<BasicRectangle>) -> Vec<BasicRectangle> {
+    rects.sort_by_key(|rect| (rect.left(), rect.right(), rect.top(), rect.bottom()));
+    rects
+}
+
+#[test]
+fn test_unobstructed_options_default() {
+    let (rect, obstruction) = options_scene();
+    let (found, truncated) =
+        rect.unobstructed_subrectangles_with(&[&obstruction], &UnobstructedOptions::default());
+    assert_eq!(found, rect.unobstructed_subrectangles(&[&obstruction]));
+    assert_eq!(found.len(), 4);
+    assert!(!truncated);
+}
+
+#[test]
+fn test_unobstructed_options_min_size() {
+    let (rect, obstruction) = options_scene();
+    let options = UnobstructedOptions::default().with_min_size(3, 3);
+    let (found, _) = rect.unobstructed_subrectangles_with(&[&obstruction], &options);
+    assert_eq!(
+        sorted(found),
+        vec![
+            BasicRectangle::new_from_sides(0, 9, 4, 0),
+            BasicRectangle::new_from_sides(0, 9, 9, 7),
+            BasicRectangle::new_from_sides(4, 9, 9, 0),
+        ]
+    );
+
+    // too big for anything
+    let options = UnobstructedOptions::default().with_min_size(11, 1);
+    let (found, _) = rect.unobstructed_subrectangles_with(&[&obstruction], &options);
+    assert!(found.is_empty());
+}
+
+#[test]
+fn test_unobstructed_options_touching() {
+    let (rect, obstruction) = options_scene();
+    let options = UnobstructedOptions::default().with_touching(Side::Bottom);
+    let (found, _) = rect.unobstructed_subrectangles_with(&[&obstruction], &options);
+    assert_eq!(
+        sorted(found),
+        vec![
+            BasicRectangle::new_from_sides(0, 1, 9, 0),
+            BasicRectangle::new_from_sides(0, 9, 4, 0),
+            BasicRectangle::new_from_sides(4, 9, 9, 0),
+        ]
+    );
+}
+
+#[test]
+fn test_unobstructed_options_partition() {
+    let (rect, obstruction) = options_scene();
+    let options = UnobstructedOptions::default().with_decomposition(Decomposition::Partition);
+    let (found, truncated) = rect.unobstructed_subrectangles_with(&[&obstruction], &options);
+    assert_eq!(found, rect.subtract_all(&[&obstruction]));
+    assert!(!truncated);
+}
+
+#[test]
+fn test_unobstructed_options_sorted() {
+    let (rect, obstruction) = options_scene();
+    let options = UnobstructedOptions::default().with_sorted(true);
+    let (found, _) = rect.unobstructed_subrectangles_with(&[&obstruction], &options);
+    assert_eq!(
+        found,
+        vec![
+            BasicRectangle::new_from_sides(0, 1, 9, 0),
+            BasicRectangle::new_from_sides(0, 9, 9, 7),
+            BasicRectangle::new_from_sides(4, 9, 9, 0),
+            BasicRectangle::new_from_sides(0, 9, 4, 0),
+        ]
+    );
+}
+
+#[test]
+fn test_unobstructed_options_budget() {
+    let (rect, obstruction) = options_scene();
+    let options = UnobstructedOptions::default().with_budget(2, BudgetStrategy::LargestArea);
+    let found = rect.unobstructed_subrectangles_with(&[&obstruction], &options);
+    assert_eq!(
+        found,
+        rect.unobstructed_subrectangles_budgeted(&[&obstruction], 2, BudgetStrategy::LargestArea)
+    );
+    assert_eq!(
+        found,
+        (
+            vec![
+                BasicRectangle::new_from_sides(4, 9, 9, 0),
+                BasicRectangle::new_from_sides(0, 9, 4, 0),
+            ],
+            true
+        )
+    );
+}
+
+#[test]
+fn test_unobstructed_options_budget_then_sorted() {
+    let (rect, obstruction) = options_scene();
+
+    // sorting replaces the largest first order, but keeps the same rectangles
+    let budget = UnobstructedOptions::default().with_budget(3, BudgetStrategy::LargestArea);
+    let (largest_first, truncated) = rect.unobstructed_subrectangles_with(&[&obstruction], &budget);
+    assert!(truncated);
+    assert_eq!(
+        largest_first,
+        vec![
+            BasicRectangle::new_from_sides(4, 9, 9, 0),
+            BasicRectangle::new_from_sides(0, 9, 4, 0),
+            BasicRectangle::new_from_sides(0, 9, 9, 7),
+        ]
+    );
+    let (reading_order, truncated) =
+        rect.unobstructed_subrectangles_with(&[&obstruction], &budget.with_sorted(true));
+    assert!(truncated);
+    assert_eq!(
+        reading_order,
+        vec![
+            BasicRectangle::new_from_sides(0, 9, 9, 7),
+            BasicRectangle::new_from_sides(4, 9, 9, 0),
+            BasicRectangle::new_from_sides(0, 9, 4, 0),
+        ]
+    );
+
+    // the first found are kept before sorting, not the first in reading order
+    let budget = UnobstructedOptions::default().with_budget(2, BudgetStrategy::FirstFound);
+    let (first, _) = rect.unobstructed_subrectangles_with(&[&obstruction], &budget);
+    let (first_sorted, _) =
+        rect.unobstructed_subrectangles_with(&[&obstruction], &budget.with_sorted(true));
+    assert_eq!(sorted(first), sorted(first_sorted));
+}
+
+#[test]
+fn test_unobstructed_options_filters_before_budget() {
+    let (rect, obstruction) = options_scene();
+
+    // the thin strip is dropped before it can use up the budget
+    let options = UnobstructedOptions::default()
+        .with_min_size(3, 3)
+        .with_budget(3, BudgetStrategy::FirstFound);
+    let (found, truncated) = rect.unobstructed_subrectangles_with(&[&obstruction], &options);
+    assert_eq!(found.len(), 3);
+    assert!(!truncated);
+    assert!(found.iter().all(|rect| rect.width() >= 2));
+
+    let options = options.with_budget(2, BudgetStrategy::FirstFound);
+    let (found, truncated) = rect.unobstructed_subrectangles_with(&[&obstruction], &options);
+    assert_eq!(found.len(), 2);
+    assert!(truncated);
+}
+
+#[test]
+fn test_unobstructed_options_partition_touching() {
+    let (rect, obstruction) = options_scene();
+    let options = UnobstructedOptions::default()
+        .with_decomposition(Decomposition::Partition)
+        .with_touching(Side::Left);
+    let (found, _) = rect.unobstructed_subrectangles_with(&[&obstruction], &options);
+
+    let mut expected = rect.subtract_all(&[&obstruction]);
+    expected.retain(|piece| piece.left() == 0);
+    assert_eq!(found, expected);
+    // the partition is split into columns, so only the first lies along the left
+    assert_eq!(found, vec![BasicRectangle::new_from_sides(0, 1, 9, 0)]);
+}
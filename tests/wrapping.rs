@@ -0,0 +1,215 @@
+use rect_lib::{BasicRectangle, Rectangle, RectangleRead};
+
+fn world() -> BasicRectangle {
+    BasicRectangle::new_from_sides(0, 9, 9, 0)
+}
+
+#[test]
+fn test_overlaps_wrapped_across_x_seam() {
+    let rect = BasicRectangle::new_from_sides(8, 10, 5, 3);
+    let other = BasicRectangle::new_from_sides(0, 1, 4, 4);
+    assert!(!rect.overlaps(&other));
+    assert!(rect.overlaps_wrapped(&other, &world()));
+    // it's symmetric
+    assert!(other.overlaps_wrapped(&rect, &world()));
+}
+
+#[test]
+fn test_overlaps_wrapped_across_y_seam() {
+    let rect = BasicRectangle::new_from_sides(3, 5, 0, -2);
+    let other = BasicRectangle::new_from_sides(4, 4, 9, 8);
+    assert!(!rect.overlaps(&other));
+    assert!(rect.overlaps_wrapped(&other, &world()));
+    assert!(other.overlaps_wrapped(&rect, &world()));
+}
+
+#[test]
+fn test_overlaps_wrapped_across_corner() {
+    let rect = BasicRectangle::new_from_sides(9, 10, 10, 9);
+    let other = BasicRectangle::new_from_sides(0, 0, 0, 0);
+    assert!(rect.overlaps_wrapped(&other, &world()));
+}
+
+#[test]
+fn test_overlaps_wrapped_near_miss() {
+    // wraps to x = 0 but the other starts at x = 1
+    let rect = BasicRectangle::new_from_sides(8, 10, 5, 3);
+    let other = BasicRectangle::new_from_sides(1, 2, 4, 4);
+    assert!(!rect.overlaps_wrapped(&other, &world()));
+
+    // overlaps in x across the seam but not in y
+    let other = BasicRectangle::new_from_sides(0, 0, 8, 6);
+    assert!(!rect.overlaps_wrapped(&other, &world()));
+}
+
+#[test]
+fn test_overlaps_wrapped_without_wrapping() {
+    let rect = BasicRectangle::new_from_sides(2, 4, 4, 2);
+    assert!(rect.overlaps_wrapped(&BasicRectangle::new_from_sides(3, 5, 5, 3), &world()));
+    assert!(!rect.overlaps_wrapped(&BasicRectangle::new_from_sides(6, 7, 7, 6), &world()));
+}
+
+#[test]
+fn test_wrap_pieces_inside() {
+    let rect = BasicRectangle::new_from_sides(2, 4, 4, 2);
+    assert_eq!(rect.wrap_pieces(&world()), vec![rect]);
+}
+
+#[test]
+fn test_wrap_pieces_corner() {
+    // straddles the top right corner
+    let rect = BasicRectangle::new_from_sides(8, 11, 10, 7);
+    let pieces = rect.wrap_pieces(&world());
+    assert_eq!(pieces.len(), 4);
+    for piece in [
+        BasicRectangle::new_from_sides(8, 9, 9, 7),
+        BasicRectangle::new_from_sides(0, 1, 9, 7),
+        BasicRectangle::new_from_sides(8, 9, 0, 0),
+        BasicRectangle::new_from_sides(0, 1, 0, 0),
+    ] {
+        assert!(pieces.contains(&piece), "missing {piece:?}");
+    }
+
+    // the pieces cover as many points as the original
+    let points: i32 = pieces
+        .iter()
+        .map(|piece| (piece.width() + 1) * (piece.height() + 1))
+        .sum();
+    assert_eq!(points, (rect.width() + 1) * (rect.height() + 1));
+}
+
+#[test]
+fn test_wrap_pieces_before_start() {
+    let rect = BasicRectangle::new_from_sides(-2, 1, 4, 4);
+    let pieces = rect.wrap_pieces(&world());
+    assert_eq!(pieces.len(), 2);
+    assert!(pieces.contains(&BasicRectangle::new_from_sides(0, 1, 4, 4)));
+    assert!(pieces.contains(&BasicRectangle::new_from_sides(8, 9, 4, 4)));
+}
+
+#[test]
+fn test_wrap_pieces_outside() {
+    // entirely past the right edge
+    let rect = BasicRectangle::new_from_sides(12, 13, 4, 4);
+    assert_eq!(
+        rect.wrap_pieces(&world()),
+        vec![BasicRectangle::new_from_sides(2, 3, 4, 4)]
+    );
+
+    // wider than the world
+    let rect = BasicRectangle::new_from_sides(-3, 12, 4, 4);
+    assert_eq!(
+        rect.wrap_pieces(&world()),
+        vec![BasicRectangle::new_from_sides(0, 9, 4, 4)]
+    );
+}
+
+#[test]
+fn test_translate_wrapping_no_wrap() {
+    let rect = BasicRectangle::new_from_sides(1, 3, 3, 1);
+    assert_eq!(
+        rect.translate_wrapping(2, 2, &world()),
+        (BasicRectangle::new_from_sides(3, 5, 5, 3), None, None, None)
+    );
+
+    // whole worlds away lands in the same place
+    assert_eq!(
+        rect.translate_wrapping(-18, 32, &world()),
+        rect.translate_wrapping(2, 2, &world())
+    );
+}
+
+#[test]
+fn test_translate_wrapping_x_only() {
+    let rect = BasicRectangle::new_from_sides(5, 8, 3, 1);
+    let (main, x, y, both) = rect.translate_wrapping(3, 0, &world());
+    assert_eq!(main, BasicRectangle::new_from_sides(8, 9, 3, 1));
+    assert_eq!(x, Some(BasicRectangle::new_from_sides(0, 1, 3, 1)));
+    assert_eq!((y, both), (None, None));
+
+    // moving left past the start wraps the same way
+    let (main, x, _, _) = rect.translate_wrapping(-7, 0, &world());
+    assert_eq!(main, BasicRectangle::new_from_sides(8, 9, 3, 1));
+    assert_eq!(x, Some(BasicRectangle::new_from_sides(0, 1, 3, 1)));
+}
+
+#[test]
+fn test_translate_wrapping_both_axes() {
+    let rect = BasicRectangle::new_from_sides(0, 2, 2, 0);
+    assert_eq!(
+        rect.translate_wrapping(-1, -1, &world()),
+        (
+            BasicRectangle::new_from_sides(9, 9, 9, 9),
+            Some(BasicRectangle::new_from_sides(0, 1, 9, 9)),
+            Some(BasicRectangle::new_from_sides(9, 9, 1, 0)),
+            Some(BasicRectangle::new_from_sides(0, 1, 1, 0)),
+        )
+    );
+}
+
+#[test]
+fn test_translate_wrapping_larger_than_world() {
+    // wider than the world is clamped to it, the height still wraps
+    let rect = BasicRectangle::new_from_sides(0, 14, 3, 0);
+    assert_eq!(
+        rect.translate_wrapping(4, 8, &world()),
+        (
+            BasicRectangle::new_from_sides(0, 9, 9, 8),
+            None,
+            Some(BasicRectangle::new_from_sides(0, 9, 1, 0)),
+            None,
+        )
+    );
+}
+
+#[test]
+fn test_translate_wrapping_matches_wrap_pieces() {
+    // both cut along the same seams, so the pieces of a move are the pieces of the moved rectangle wrapped back
+    let rect = BasicRectangle::new_from_sides(0, 3, 2, 0);
+    for dx in -25..25 {
+        for dy in -25..25 {
+            let (main, wrapped_x, wrapped_y, wrapped_both) =
+                rect.translate_wrapping(dx, dy, &world());
+            let mut pieces: Vec<BasicRectangle> = [Some(main), wrapped_x, wrapped_y, wrapped_both]
+                .into_iter()
+                .flatten()
+                .collect();
+
+            let (x, y) = world().wrap_point(dx, dy);
+            let mut expected = rect.translate(x, y).wrap_pieces(&world());
+            let key = |r: &BasicRectangle| (r.left(), r.bottom());
+            pieces.sort_by_key(key);
+            expected.sort_by_key(key);
+            assert_eq!(pieces, expected);
+        }
+    }
+}
+
+#[test]
+fn test_wrap_point() {
+    assert_eq!(world().wrap_point(3, 4), (3, 4));
+    assert_eq!(world().wrap_point(10, -10), (0, 0));
+    assert_eq!(world().wrap_point(-1, 109), (9, 9));
+}
+
+#[test]
+fn test_contains_rectangle_wrapped_across_seams() {
+    // both wrap across the corner of the world
+    let rect = BasicRectangle::new_from_sides(7, 12, 12, 7);
+    let other = BasicRectangle::new_from_sides(8, 11, 11, 8);
+    assert!(rect.contains_rectangle_wrapped(&other, &world()));
+
+    // the same rectangle given a world to the left is still inside
+    let shifted = BasicRectangle::new_from_sides(-2, 1, 11, 8);
+    assert!(rect.contains_rectangle_wrapped(&shifted, &world()));
+
+    // poking out past the wrapped right side isn't
+    let poking = BasicRectangle::new_from_sides(8, 13, 11, 8);
+    assert!(!rect.contains_rectangle_wrapped(&poking, &world()));
+
+    // a rectangle covering the whole width contains anything in its rows
+    let band = BasicRectangle::new_from_sides(0, 9, 3, 1);
+    let wrapped = BasicRectangle::new_from_sides(8, 12, 2, 2);
+    assert!(band.contains_rectangle_wrapped(&wrapped, &world()));
+    assert!(!wrapped.contains_rectangle_wrapped(&band, &world()));
+}